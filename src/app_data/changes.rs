@@ -0,0 +1,334 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use bollard::models::{ChangeType, FilesystemChange};
+
+use super::{ContainerId, ScrollDirection, StatefulList};
+
+/// The kind of a change in the writable layer of a container, as returned by `docker diff`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChangeKind {
+    Added,
+    Changed,
+    Deleted,
+}
+
+impl From<ChangeType> for ChangeKind {
+    fn from(value: ChangeType) -> Self {
+        match value {
+            ChangeType::_0 => Self::Changed,
+            ChangeType::_1 => Self::Added,
+            ChangeType::_2 => Self::Deleted,
+        }
+    }
+}
+
+/// Single char, in the same style as `docker diff`
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Added => "A",
+            Self::Changed => "C",
+            Self::Deleted => "D",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl ChangeKind {
+    /// Cycle through the filters, None is used to show every change
+    const fn next_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Added),
+            Some(Self::Added) => Some(Self::Changed),
+            Some(Self::Changed) => Some(Self::Deleted),
+            Some(Self::Deleted) => None,
+        }
+    }
+
+    const fn previous_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Deleted),
+            Some(Self::Deleted) => Some(Self::Changed),
+            Some(Self::Changed) => Some(Self::Added),
+            Some(Self::Added) => None,
+        }
+    }
+}
+
+/// A single visible row of the changes tree
+/// Kind is None for parent directories that are only shown so that a filtered change has context
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeRow {
+    pub collapsed: bool,
+    pub depth: usize,
+    pub has_children: bool,
+    pub kind: Option<ChangeKind>,
+    pub path: String,
+}
+
+impl ChangeRow {
+    /// The final component of the path
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or_default()
+    }
+}
+
+/// The filesystem changes of a single container, displayed as a collapsible tree
+#[derive(Debug, Clone)]
+pub struct ContainerChanges {
+    changes: Vec<(String, ChangeKind)>,
+    collapsed: HashSet<String>,
+    pub filter: Option<ChangeKind>,
+    pub id: ContainerId,
+    pub name: String,
+    pub rows: StatefulList<ChangeRow>,
+}
+
+/// Split a path into its components, used to sort paths so that children always directly follow their parent
+fn components(path: &str) -> Vec<&str> {
+    path.split('/').filter(|i| !i.is_empty()).collect()
+}
+
+impl ContainerChanges {
+    pub fn new(id: ContainerId, name: String, changes: Vec<FilesystemChange>) -> Self {
+        let mut changes = changes
+            .into_iter()
+            .map(|i| (i.path, ChangeKind::from(i.kind)))
+            .collect::<Vec<_>>();
+        changes.sort_by(|a, b| components(&a.0).cmp(&components(&b.0)));
+        let mut output = Self {
+            changes,
+            collapsed: HashSet::new(),
+            filter: None,
+            id,
+            name,
+            rows: StatefulList::new(vec![]),
+        };
+        output.build_rows();
+        output.rows.start();
+        output
+    }
+
+    /// The total number of changes of the given kind
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|(_, k)| *k == kind).count()
+    }
+
+    /// Every change that matches the current filter, in the `docker diff` format, used when saving to a file
+    pub fn as_text(&self) -> String {
+        self.changes
+            .iter()
+            .filter(|(_, kind)| self.filter.is_none_or(|f| f == *kind))
+            .map(|(path, kind)| format!("{kind} {path}\n"))
+            .collect()
+    }
+
+    /// Rebuild the visible rows, from the current filter & collapsed directories
+    /// The ancestors of every matching change are included, and the rows of collapsed directories are hidden
+    fn build_rows(&mut self) {
+        let matching = self
+            .changes
+            .iter()
+            .filter(|(_, kind)| self.filter.is_none_or(|f| f == *kind))
+            .map(|(path, kind)| (path.as_str(), *kind))
+            .collect::<HashMap<_, _>>();
+
+        let mut paths = matching
+            .keys()
+            .flat_map(|path| {
+                let parts = components(path);
+                (1..=parts.len())
+                    .map(move |i| format!("/{}", parts[..i].join("/")))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        paths.sort_by(|a, b| components(a).cmp(&components(b)));
+        paths.dedup();
+
+        let mut rows = vec![];
+        for (index, path) in paths.iter().enumerate() {
+            if path
+                .match_indices('/')
+                .skip(1)
+                .any(|(i, _)| self.collapsed.contains(&path[..i]))
+            {
+                continue;
+            }
+            let has_children = paths.get(index + 1).is_some_and(|i| {
+                i.strip_prefix(path.as_str())
+                    .is_some_and(|rest| rest.starts_with('/'))
+            });
+            rows.push(ChangeRow {
+                collapsed: self.collapsed.contains(path),
+                depth: components(path).len().saturating_sub(1),
+                has_children,
+                kind: matching.get(path.as_str()).copied(),
+                path: path.clone(),
+            });
+        }
+
+        let selected = self.selected().map(|i| i.path);
+        self.rows.items = rows;
+        let index = selected
+            .and_then(|s| self.rows.items.iter().position(|i| i.path == s))
+            .unwrap_or_default();
+        self.rows.state.select(Some(index));
+    }
+
+    pub fn selected(&self) -> Option<ChangeRow> {
+        self.rows
+            .state
+            .selected()
+            .and_then(|i| self.rows.items.get(i))
+            .cloned()
+    }
+
+    /// The directory to open in the file browser for the selected row, the row itself if it has children, else its parent
+    pub fn selected_dir(&self) -> String {
+        self.selected().map_or_else(
+            || "/".to_owned(),
+            |row| {
+                if row.has_children {
+                    row.path
+                } else {
+                    match row.path.rsplit_once('/') {
+                        Some((parent, _)) if !parent.is_empty() => parent.to_owned(),
+                        _ => "/".to_owned(),
+                    }
+                }
+            },
+        )
+    }
+
+    pub fn scroll(&mut self, sd: &ScrollDirection) {
+        self.rows.scroll(sd);
+    }
+
+    /// Collapse or expand the selected directory, collapse is Some(true) to only collapse, Some(false) to only expand, None to toggle
+    pub fn collapse(&mut self, collapse: Option<bool>) {
+        if let Some(row) = self.selected()
+            && row.has_children
+        {
+            let collapse = collapse.unwrap_or(!row.collapsed);
+            if collapse {
+                self.collapsed.insert(row.path);
+            } else {
+                self.collapsed.remove(&row.path);
+            }
+            self.build_rows();
+        }
+    }
+
+    /// Change the change type filter, forward to go from all, to added, changed, & deleted
+    pub fn cycle_filter(&mut self, forward: bool) {
+        self.filter = if forward {
+            ChangeKind::next_filter(self.filter)
+        } else {
+            ChangeKind::previous_filter(self.filter)
+        };
+        self.build_rows();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::models::{ChangeType, FilesystemChange};
+
+    use super::{ChangeKind, ContainerChanges};
+    use crate::app_data::{ContainerId, ScrollDirection};
+
+    fn gen_changes() -> ContainerChanges {
+        let change = |path: &str, kind: ChangeType| FilesystemChange {
+            path: path.to_owned(),
+            kind,
+        };
+        ContainerChanges::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            vec![
+                change("/var/log/app.log", ChangeType::_1),
+                change("/etc", ChangeType::_0),
+                change("/etc/hosts", ChangeType::_0),
+                change("/var", ChangeType::_0),
+                change("/tmp-old", ChangeType::_2),
+                change("/var/log", ChangeType::_0),
+            ],
+        )
+    }
+
+    fn paths(changes: &ContainerChanges) -> Vec<(String, usize, Option<ChangeKind>)> {
+        changes
+            .rows
+            .items
+            .iter()
+            .map(|i| (i.path.clone(), i.depth, i.kind))
+            .collect()
+    }
+
+    #[test]
+    /// Paths are sorted into a tree, with children directly after their parent
+    fn test_changes_tree() {
+        let changes = gen_changes();
+        assert_eq!(
+            paths(&changes),
+            vec![
+                ("/etc".to_owned(), 0, Some(ChangeKind::Changed)),
+                ("/etc/hosts".to_owned(), 1, Some(ChangeKind::Changed)),
+                ("/tmp-old".to_owned(), 0, Some(ChangeKind::Deleted)),
+                ("/var".to_owned(), 0, Some(ChangeKind::Changed)),
+                ("/var/log".to_owned(), 1, Some(ChangeKind::Changed)),
+                ("/var/log/app.log".to_owned(), 2, Some(ChangeKind::Added)),
+            ]
+        );
+        assert!(changes.rows.items[0].has_children);
+        assert!(!changes.rows.items[2].has_children);
+        assert_eq!(changes.count(ChangeKind::Changed), 4);
+        assert_eq!(changes.selected_dir(), "/etc");
+    }
+
+    #[test]
+    /// Collapsed directories hide all descendants, and the selected row is kept
+    fn test_changes_collapse() {
+        let mut changes = gen_changes();
+        changes.scroll(&ScrollDirection::Down);
+        changes.scroll(&ScrollDirection::Down);
+        changes.scroll(&ScrollDirection::Down);
+        changes.collapse(None);
+        assert_eq!(changes.rows.items.len(), 4);
+        assert!(changes.rows.items[3].collapsed);
+        assert_eq!(changes.selected().unwrap().path, "/var");
+
+        changes.collapse(Some(false));
+        assert_eq!(changes.rows.items.len(), 6);
+
+        changes.scroll(&ScrollDirection::Down);
+        changes.scroll(&ScrollDirection::Down);
+        assert_eq!(changes.selected_dir(), "/var/log");
+    }
+
+    #[test]
+    /// Filtering shows only matching changes, with their parent directories for context
+    fn test_changes_filter() {
+        let mut changes = gen_changes();
+        changes.cycle_filter(true);
+        assert_eq!(changes.filter, Some(ChangeKind::Added));
+        assert_eq!(
+            paths(&changes),
+            vec![
+                ("/var".to_owned(), 0, None),
+                ("/var/log".to_owned(), 1, None),
+                ("/var/log/app.log".to_owned(), 2, Some(ChangeKind::Added)),
+            ]
+        );
+        assert_eq!(changes.as_text(), "A /var/log/app.log\n");
+
+        changes.cycle_filter(false);
+        changes.cycle_filter(false);
+        assert_eq!(changes.filter, Some(ChangeKind::Deleted));
+        assert_eq!(paths(&changes).len(), 1);
+    }
+}
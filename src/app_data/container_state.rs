@@ -1,27 +1,38 @@
 use std::{
     cmp::Ordering,
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
     fmt,
     net::IpAddr,
 };
 
 use bollard::models::{ContainerSummaryHealthStatusEnum, PortSummary};
-use jiff::{Timestamp, tz::TimeZone};
+use jiff::{SignedDuration, Timestamp, tz::TimeZone};
 use ratatui::{
     layout::Size,
-    style::Color,
-    text::{Line, Text},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
     widgets::ListState,
 };
 
-use crate::config::AppColors;
+use crate::config::{AppColors, Config};
 
-use super::Header;
+use super::{
+    FieldFilter, Header, JsonLog, LogLevel, LogLine, LogStream, LogTimeColumn, SaveLines, SaveScope,
+};
 
 const ONE_KB: f64 = 1000.0;
 const ONE_MB: f64 = ONE_KB * 1000.0;
 const ONE_GB: f64 = ONE_MB * 1000.0;
 
+/// Drawn at the start of each continuation row of a wrapped log line
+const WRAP_INDICATOR: &str = "↪ ";
+
+/// The number of columns of the logs panel that can't be used by a log line, the two borders & the highlight symbol
+const LOGS_PANEL_PADDING: usize = 4;
+
+/// A delta, between a line & the line before it, of at least this many seconds is highlighted in the time column
+const STALL_SECONDS: i64 = 1;
+
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum ScrollDirection {
     // Next,
@@ -419,6 +430,65 @@ impl fmt::Display for State {
     }
 }
 
+/// The toggleable options of the delete confirm dialog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteOption {
+    Volumes,
+    Force,
+    Link,
+}
+
+impl DeleteOption {
+    pub const ALL: [Self; 3] = [Self::Volumes, Self::Force, Self::Link];
+}
+
+impl fmt::Display for DeleteOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Volumes => "also remove anonymous volumes",
+            Self::Force => "force (kill if running)",
+            Self::Link => "remove linked",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The options used when deleting a container, the defaults are taken from the config
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteOptions {
+    pub force: bool,
+    pub link: bool,
+    pub volumes: bool,
+}
+
+impl From<&Config> for DeleteOptions {
+    fn from(config: &Config) -> Self {
+        Self {
+            force: config.delete_force,
+            link: config.delete_link,
+            volumes: config.delete_volumes,
+        }
+    }
+}
+
+impl DeleteOptions {
+    pub const fn get(self, option: DeleteOption) -> bool {
+        match option {
+            DeleteOption::Volumes => self.volumes,
+            DeleteOption::Force => self.force,
+            DeleteOption::Link => self.link,
+        }
+    }
+
+    pub const fn toggle(&mut self, option: DeleteOption) {
+        match option {
+            DeleteOption::Volumes => self.volumes = !self.volumes,
+            DeleteOption::Force => self.force = !self.force,
+            DeleteOption::Link => self.link = !self.link,
+        }
+    }
+}
+
 /// Items for the container control list
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DockerCommand {
@@ -427,6 +497,10 @@ pub enum DockerCommand {
     Start,
     Stop,
     Resume,
+    Kill,
+    Recreate,
+    Rename,
+    Commit,
     Delete,
 }
 
@@ -439,16 +513,58 @@ impl DockerCommand {
             Self::Stop => colors.commands.stop,
             Self::Delete => colors.commands.delete,
             Self::Resume => colors.commands.resume,
+            Self::Kill => colors.commands.kill,
+            Self::Recreate => colors.commands.recreate,
+            Self::Rename => colors.commands.rename,
+            Self::Commit => colors.commands.commit,
         }
     }
 
+    /// Stop & restart wait for the container to exit, so can be given a timeout
+    pub const fn has_timeout(self) -> bool {
+        matches!(self, Self::Restart | Self::Stop)
+    }
+
     /// Docker commands available depending on the containers state
     pub fn gen_vec(state: State) -> Vec<Self> {
         match state {
-            State::Dead | State::Exited => vec![Self::Start, Self::Restart, Self::Delete],
-            State::Paused => vec![Self::Resume, Self::Stop, Self::Delete],
-            State::Restarting => vec![Self::Stop, Self::Delete],
-            State::Running(_) => vec![Self::Pause, Self::Restart, Self::Stop, Self::Delete],
+            State::Dead | State::Exited => {
+                vec![
+                    Self::Start,
+                    Self::Restart,
+                    Self::Recreate,
+                    Self::Rename,
+                    Self::Commit,
+                    Self::Delete,
+                ]
+            }
+            State::Paused => vec![
+                Self::Resume,
+                Self::Stop,
+                Self::Kill,
+                Self::Recreate,
+                Self::Rename,
+                Self::Commit,
+                Self::Delete,
+            ],
+            State::Restarting => vec![
+                Self::Stop,
+                Self::Kill,
+                Self::Recreate,
+                Self::Rename,
+                Self::Commit,
+                Self::Delete,
+            ],
+            State::Running(_) => vec![
+                Self::Pause,
+                Self::Restart,
+                Self::Stop,
+                Self::Kill,
+                Self::Recreate,
+                Self::Rename,
+                Self::Commit,
+                Self::Delete,
+            ],
             _ => vec![Self::Delete],
         }
     }
@@ -463,6 +579,10 @@ impl fmt::Display for DockerCommand {
             Self::Start => "start",
             Self::Stop => "stop",
             Self::Resume => "resume",
+            Self::Kill => "kill",
+            Self::Recreate => "recreate",
+            Self::Rename => "rename",
+            Self::Commit => "commit",
         };
         write!(f, "{disp}")
     }
@@ -707,6 +827,11 @@ impl LogsTz {
         (Self(tz.to_owned()), content.to_owned())
     }
 
+    /// Parse the timestamp, to be able to compare log lines
+    pub fn timestamp(&self) -> Option<Timestamp> {
+        self.0.parse::<Timestamp>().ok()
+    }
+
     /// Display the timestamp in a given format, and if provided, with a timezone offset
     pub fn display_with_formatter(&self, tz: Option<&TimeZone>, format: &str) -> Option<String> {
         self.0.parse::<Timestamp>().map_or(None, |t| {
@@ -726,8 +851,30 @@ impl LogsTz {
 /// stateful list dependent on whether the timestamp is in the HashSet or not
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Logs {
-    lines: StatefulList<Text<'static>>,
+    lines: Vec<LogLine>,
+    view: StatefulList<usize>,
+    min_level: Option<LogLevel>,
+    stream: Option<LogStream>,
+    since: Option<Timestamp>,
+    until: Option<Timestamp>,
+    jump: Option<Timestamp>,
+    follow: bool,
+    unread: usize,
+    collapse: bool,
+    group: bool,
+    /// The number of consecutive repeats of a shown line, keyed by the index, in lines, of the last repeat, which is the line shown
+    repeats: HashMap<usize, usize>,
+    /// The number of continuation lines of each group, keyed by the index, in lines, of the parent line
+    children: HashMap<usize, usize>,
+    expanded: HashSet<usize>,
+    /// The index, in lines, of the last shown line that can be the parent of a group
+    parent: Option<usize>,
+    mark: Option<usize>,
+    level_counts: [usize; LogLevel::ALL.len()],
+    json_widths: Vec<usize>,
     tz: HashSet<LogsTz>,
+    oldest: Option<LogsTz>,
+    complete: bool,
     search_results: Vec<usize>,
     search_term: Option<String>,
     offset: usize,
@@ -739,11 +886,30 @@ pub struct Logs {
 
 impl Default for Logs {
     fn default() -> Self {
-        let mut lines = StatefulList::new(vec![]);
-        lines.end();
+        let mut view = StatefulList::new(vec![]);
+        view.end();
         Self {
-            lines,
+            lines: vec![],
+            view,
+            min_level: None,
+            stream: None,
+            since: None,
+            until: None,
+            jump: None,
+            follow: true,
+            unread: 0,
+            collapse: false,
+            group: false,
+            repeats: HashMap::new(),
+            children: HashMap::new(),
+            expanded: HashSet::new(),
+            parent: None,
+            mark: None,
+            level_counts: [0; LogLevel::ALL.len()],
+            json_widths: vec![],
             tz: HashSet::new(),
+            oldest: None,
+            complete: false,
             offset: 0,
             max_offset: 0,
             search_term: None,
@@ -772,7 +938,7 @@ pub struct LogSearch {
 /// LogSearch is used in FrameData
 impl From<&Logs> for LogSearch {
     fn from(l: &Logs) -> Self {
-        let buttons = l.lines.state.selected().as_ref().and_then(|x| {
+        let buttons = l.view.state.selected().as_ref().and_then(|x| {
             let show_next = l.search_results.iter().any(|n| n > x);
             let show_previous = l.search_results.iter().any(|n| n < x);
             match (show_next, show_previous) {
@@ -797,7 +963,7 @@ impl Logs {
 
     /// Scroll to the next or previous search result, accounts for when currently selected line isn't in the results vec
     pub fn search_scroll(&mut self, sd: &ScrollDirection) -> Option<()> {
-        if let Some(current_selected) = self.lines.state.selected() {
+        if let Some(current_selected) = self.view.state.selected() {
             if let Some(current_position) = self
                 .search_results
                 .iter()
@@ -810,7 +976,7 @@ impl Logs {
                     _ => None,
                 } && let Some(f) = self.search_results.get(new_index)
                 {
-                    self.lines.state.select(Some(*f));
+                    self.view.state.select(Some(*f));
                     return Some(());
                 }
             } else {
@@ -827,7 +993,7 @@ impl Logs {
                 };
                 for i in range {
                     if self.search_results.contains(&i) {
-                        self.lines.state.select(Some(i));
+                        self.view.state.select(Some(i));
                         return Some(());
                     }
                 }
@@ -842,7 +1008,7 @@ impl Logs {
         if self.search_results.is_empty() {
             return None;
         }
-        Some(self.lines.state.selected().map_or_else(
+        Some(self.view.state.selected().map_or_else(
             || format!("{}", self.search_results.len()),
             |current_index| {
                 self.search_results
@@ -868,13 +1034,24 @@ impl Logs {
             } else {
                 search_term.to_lowercase()
             };
+            let filters = FieldFilter::parse_all(search_term);
             self.search_results = self
-                .lines
+                .view
                 .items
                 .iter()
                 .enumerate()
-                .filter_map(|(index, a)| {
-                    a.lines
+                .filter_map(|(index, line_index)| {
+                    let a = self.lines.get(*line_index)?;
+                    // A `key=value` search term only matches the fields of JSON lines
+                    if let Some(filters) = filters.as_ref() {
+                        return a
+                            .json
+                            .as_ref()
+                            .is_some_and(|i| i.fields.matches(filters, case_sensitive))
+                            .then_some(index);
+                    }
+                    a.text
+                        .lines
                         .iter()
                         .any(|b| {
                             b.spans.iter().any(|c| {
@@ -889,7 +1066,7 @@ impl Logs {
                 })
                 .collect();
             if !self.search_results.is_empty() && scroll {
-                self.lines.state.select(self.search_results.last().copied());
+                self.view.state.select(self.search_results.last().copied());
                 self.offset = 0;
             }
         } else {
@@ -925,10 +1102,19 @@ impl Logs {
     }
 
     /// Only allow a new log line to be inserted if the log timestamp isn't in the tz HashSet
-    pub fn insert(&mut self, line: Text<'static>, tz: LogsTz, case_sensitive: bool) {
-        if self.tz.insert(tz) {
-            self.max_log_len = self.max_log_len.max(line.width());
-            self.lines.items.push(line);
+    pub fn insert(&mut self, mut line: LogLine, tz: LogsTz, case_sensitive: bool) {
+        if self.tz.insert(tz.clone()) {
+            line.timestamp = tz.timestamp();
+            if self.oldest.is_none() {
+                self.oldest = Some(tz);
+            }
+            self.update_widths(&line);
+            self.count_level(&line);
+            let shows = self.shows(&line);
+            self.lines.push(line);
+            if shows {
+                self.push_view(self.lines.len() - 1);
+            }
             // Maybe - Ideally we'd re-render here
             if self.search_term.is_some() {
                 self.search(case_sensitive, false);
@@ -936,6 +1122,450 @@ impl Logs {
         }
     }
 
+    /// Insert lines that are older than every currently held line, i.e. a page fetched when scrolling to the top of the logs
+    /// The selected line is kept the same, so the newly inserted lines are above the cursor, unless waiting to jump to a time within them
+    pub fn insert_older(&mut self, lines: Vec<(LogLine, LogsTz)>, case_sensitive: bool) {
+        let mut older = vec![];
+        for (mut line, tz) in lines {
+            if self.tz.insert(tz.clone()) {
+                line.timestamp = tz.timestamp();
+                if older.is_empty() {
+                    self.oldest = Some(tz);
+                }
+                self.widen_columns(&line);
+                self.count_level(&line);
+                older.push(line);
+            }
+        }
+        let count = older.len();
+        if count > 0 {
+            let selected = self.selected_line();
+            older.append(&mut self.lines);
+            self.lines = older;
+            self.measure_lines();
+            if let Some(mark) = self.mark.as_mut() {
+                *mark += count;
+            }
+            self.expanded = self.expanded.iter().map(|i| i + count).collect();
+            self.build_view();
+            if let Some(selected) = selected {
+                self.select_line(selected + count);
+            }
+            if self.search_term.is_some() {
+                self.search(case_sensitive, false);
+            }
+        }
+        if let Some(jump) = self.jump.take() {
+            self.select_time(jump);
+        }
+    }
+
+    /// Widen the JSON columns to fit a line, returns true if any column was widened
+    fn widen_columns(&mut self, line: &LogLine) -> bool {
+        let mut widened = false;
+        for (index, width) in line.column_widths().into_iter().enumerate() {
+            if let Some(current) = self.json_widths.get_mut(index) {
+                widened |= width > *current;
+                *current = (*current).max(width);
+            } else {
+                widened = true;
+                self.json_widths.push(width);
+            }
+        }
+        widened
+    }
+
+    /// Recalculate the max line length from every line, needed whenever a column is widened, as that widens every JSON line
+    fn measure_lines(&mut self) {
+        self.max_log_len = self
+            .lines
+            .iter()
+            .map(|i| i.aligned(&self.json_widths).width())
+            .max()
+            .unwrap_or_default();
+    }
+
+    /// Widen the JSON columns to fit a new line, which must then be pushed, and update the max line length, used for horizontal scrolling
+    fn update_widths(&mut self, line: &LogLine) {
+        if self.widen_columns(line) {
+            self.measure_lines();
+        }
+        self.max_log_len = self
+            .max_log_len
+            .max(line.aligned(&self.json_widths).width());
+    }
+
+    /// Add a line to the per level counters
+    const fn count_level(&mut self, line: &LogLine) {
+        if let Some(level) = line.level {
+            self.level_counts[level as usize] += 1;
+        }
+    }
+
+    /// Whether a line passes the stream filter, minimum level filter, & time range, lines without a detected level, or timestamp, are always shown by the level filter, or time range
+    fn shows(&self, line: &LogLine) -> bool {
+        self.stream.is_none_or(|stream| line.stream == stream)
+            && self
+                .min_level
+                .is_none_or(|min| line.level.is_none_or(|level| level >= min))
+            && line.timestamp.is_none_or(|timestamp| {
+                self.since.is_none_or(|since| timestamp >= since)
+                    && self.until.is_none_or(|until| timestamp <= until)
+            })
+    }
+
+    /// The number of lines of each level, ignoring the filter, levels without any lines are skipped
+    pub fn level_counts(&self) -> Vec<(LogLevel, usize)> {
+        LogLevel::ALL
+            .into_iter()
+            .rev()
+            .filter_map(|level| {
+                let count = self.level_counts[level as usize];
+                (count > 0).then_some((level, count))
+            })
+            .collect()
+    }
+
+    pub const fn min_level(&self) -> Option<LogLevel> {
+        self.min_level
+    }
+
+    pub const fn stream(&self) -> Option<LogStream> {
+        self.stream
+    }
+
+    /// Cycle the minimum level filter
+    pub fn cycle_level_filter(&mut self, case_sensitive: bool) {
+        self.min_level = LogLevel::next_filter(self.min_level);
+        self.rebuild_view(case_sensitive);
+    }
+
+    /// Cycle the stream filter, the lines of both streams are always held, so no refetch is needed
+    pub fn cycle_stream_filter(&mut self, case_sensitive: bool) {
+        self.stream = LogStream::next_filter(self.stream);
+        self.rebuild_view(case_sensitive);
+    }
+
+    pub const fn time_range(&self) -> (Option<Timestamp>, Option<Timestamp>) {
+        (self.since, self.until)
+    }
+
+    /// The lines to save for a given scope, the results are the search matches, if searching, else every line that passes the filters
+    pub fn save_lines(&self, scope: SaveScope) -> SaveLines {
+        match scope {
+            SaveScope::All => SaveLines::All,
+            SaveScope::Range => SaveLines::Range(self.time_range()),
+            SaveScope::Results => {
+                let timestamps = if self.search_term.is_some() {
+                    self.search_results
+                        .iter()
+                        .filter_map(|i| self.view.items.get(*i))
+                        .filter_map(|i| self.lines.get(*i)?.timestamp)
+                        .collect()
+                } else {
+                    self.lines
+                        .iter()
+                        .filter(|i| self.shows(i))
+                        .filter_map(|i| i.timestamp)
+                        .collect()
+                };
+                SaveLines::Results(timestamps)
+            }
+        }
+    }
+
+    /// Only show the lines between since & until, either can be unbounded
+    pub fn set_time_range(
+        &mut self,
+        since: Option<Timestamp>,
+        until: Option<Timestamp>,
+        case_sensitive: bool,
+    ) {
+        self.since = since;
+        self.until = until;
+        self.rebuild_view(case_sensitive);
+    }
+
+    /// Select the first shown line at, or after, a given time, or the last line if every line is older
+    /// If older lines could still be fetched, the jump is repeated once they've been inserted
+    pub fn jump_to(&mut self, timestamp: Timestamp) {
+        self.select_time(timestamp);
+        self.jump = self.needs_from(timestamp).then_some(timestamp);
+    }
+
+    /// Select the first shown line at, or after, a given time
+    fn select_time(&mut self, timestamp: Timestamp) {
+        let position = self
+            .view
+            .items
+            .iter()
+            .position(|i| {
+                self.lines
+                    .get(*i)
+                    .and_then(|i| i.timestamp)
+                    .is_some_and(|i| i >= timestamp)
+            })
+            .or_else(|| self.view.items.len().checked_sub(1));
+        self.view.state.select(position);
+        self.offset = 0;
+    }
+
+    /// Lines at, or after, a given time haven't been fetched yet
+    pub fn needs_from(&self, timestamp: Timestamp) -> bool {
+        !self.complete
+            && self
+                .oldest
+                .as_ref()
+                .and_then(LogsTz::timestamp)
+                .is_some_and(|oldest| timestamp < oldest)
+    }
+
+    /// Add a shown line to the end of the visible lines
+    /// If collapsing repeats, a repeat of the last visible line replaces it, and if grouping, a continuation line is added to the group of the last parent line, and is only visible once the group is expanded
+    fn push_view(&mut self, index: usize) {
+        let Some(line) = self.lines.get(index) else {
+            return;
+        };
+        if self.group
+            && line.continuation
+            && let Some(parent) = self.parent
+        {
+            *self.children.entry(parent).or_default() += 1;
+            if self.expanded.contains(&parent) {
+                self.view.items.push(index);
+            }
+            return;
+        }
+        if self.collapse
+            && let Some(last) = self.view.items.last().copied()
+            && !self.children.contains_key(&last)
+            && self.lines.get(last).is_some_and(|i| i.repeats(line))
+        {
+            let count = self.repeats.remove(&last).unwrap_or(1) + 1;
+            self.repeats.insert(index, count);
+            if let Some(last) = self.view.items.last_mut() {
+                *last = index;
+            }
+        } else {
+            self.view.items.push(index);
+        }
+        if !line.continuation {
+            self.parent = Some(index);
+        }
+    }
+
+    /// Build the visible lines, from every line that passes the filters
+    fn build_view(&mut self) {
+        self.view.items.clear();
+        self.repeats.clear();
+        self.children.clear();
+        self.parent = None;
+        for index in 0..self.lines.len() {
+            if self.lines.get(index).is_some_and(|i| self.shows(i)) {
+                self.push_view(index);
+            }
+        }
+    }
+
+    /// Select a line, or the closest visible line before it, or the first line if there's none before it
+    fn select_line(&mut self, line: usize) {
+        let position = self
+            .view
+            .items
+            .iter()
+            .rposition(|i| *i <= line)
+            .or_else(|| (!self.view.items.is_empty()).then_some(0));
+        self.view.state.select(position);
+    }
+
+    /// Rebuild the visible lines after a filter change, keeping the selection on, or just before, the previously selected line
+    fn rebuild_view(&mut self, case_sensitive: bool) {
+        let selected = self.selected_line();
+        self.build_view();
+        match selected {
+            Some(selected) => self.select_line(selected),
+            None => self.view.end(),
+        }
+        self.offset = 0;
+        self.search(case_sensitive, false);
+    }
+
+    pub const fn collapse(&self) -> bool {
+        self.collapse
+    }
+
+    pub const fn group(&self) -> bool {
+        self.group
+    }
+
+    /// Toggle collapsing consecutive repeated lines into the last repeat
+    pub fn toggle_collapse(&mut self, case_sensitive: bool) {
+        self.collapse = !self.collapse;
+        self.rebuild_view(case_sensitive);
+    }
+
+    /// Toggle grouping continuation lines, such as a stack trace, under the line before them
+    pub fn toggle_group(&mut self, case_sensitive: bool) {
+        self.group = !self.group;
+        self.rebuild_view(case_sensitive);
+    }
+
+    /// Expand, or collapse, the group of the selected line, a continuation line toggles the group it's in
+    /// Returns false if the selected line isn't in a group
+    pub fn toggle_expanded(&mut self, case_sensitive: bool) -> bool {
+        let Some(position) = self.view.state.selected() else {
+            return false;
+        };
+        let Some(parent) = self
+            .view
+            .items
+            .get(..=position)
+            .and_then(|items| {
+                items.iter().rev().find(|i| {
+                    self.children.contains_key(i)
+                        || self.lines.get(**i).is_none_or(|i| !i.continuation)
+                })
+            })
+            .copied()
+            .filter(|i| self.children.contains_key(i))
+        else {
+            return false;
+        };
+        if !self.expanded.remove(&parent) {
+            self.expanded.insert(parent);
+        }
+        self.build_view();
+        self.select_line(parent);
+        self.search(case_sensitive, false);
+        true
+    }
+
+    /// The fields of the selected line, if it's a JSON line
+    pub fn selected_json(&self) -> Option<&JsonLog> {
+        self.selected_line()
+            .and_then(|i| self.lines.get(i))
+            .and_then(|i| i.json.as_ref())
+            .map(|i| &i.fields)
+    }
+
+    /// The index, in lines, of the selected line
+    fn selected_line(&self) -> Option<usize> {
+        self.view
+            .state
+            .selected()
+            .and_then(|i| self.view.items.get(i))
+            .copied()
+    }
+
+    /// Start a selection at the selected line, or cancel the current selection
+    pub fn toggle_mark(&mut self) {
+        self.mark = if self.mark.is_some() {
+            None
+        } else {
+            self.selected_line()
+        };
+    }
+
+    pub const fn clear_mark(&mut self) {
+        self.mark = None;
+    }
+
+    pub const fn is_marked(&self) -> bool {
+        self.mark.is_some()
+    }
+
+    /// The first & last index, in lines, of the selection, from the mark to the selected line
+    fn marked_range(&self) -> Option<(usize, usize)> {
+        let mark = self.mark?;
+        let selected = self.selected_line()?;
+        Some((mark.min(selected), mark.max(selected)))
+    }
+
+    /// The number of shown lines in the selection
+    pub fn marked_count(&self) -> usize {
+        self.marked_range().map_or(0, |(start, end)| {
+            self.view
+                .items
+                .iter()
+                .filter(|i| (start..=end).contains(*i))
+                .count()
+        })
+    }
+
+    /// The shown lines in the selection, or just the selected line if there's no selection, as they are drawn, to copy to the clipboard
+    pub fn marked_text(&self) -> Vec<String> {
+        let Some((start, end)) = self
+            .marked_range()
+            .or_else(|| self.selected_line().map(|i| (i, i)))
+        else {
+            return vec![];
+        };
+        self.view
+            .items
+            .iter()
+            .filter(|i| (start..=end).contains(*i))
+            .filter_map(|i| self.lines.get(*i))
+            .map(|i| i.plain_text(&self.json_widths))
+            .collect()
+    }
+
+    /// The timestamp of the oldest line, used as the upper bound when fetching older lines
+    pub const fn oldest(&self) -> Option<&LogsTz> {
+        self.oldest.as_ref()
+    }
+
+    /// Every line, from when the container was started, has been fetched
+    pub const fn set_complete(&mut self) {
+        self.complete = true;
+    }
+
+    pub const fn follow(&self) -> bool {
+        self.follow
+    }
+
+    /// Pause, or resume, following new lines, resuming selects the last line
+    pub fn toggle_follow(&mut self) {
+        self.follow = !self.follow;
+        if self.follow {
+            self.end();
+        }
+    }
+
+    /// Select the last line, and follow new lines
+    pub fn live(&mut self) {
+        self.follow = true;
+        self.end();
+    }
+
+    /// The number of lines, below the selected line, that have arrived since following was paused
+    pub fn unread(&self) -> usize {
+        let below = self
+            .view
+            .state
+            .selected()
+            .map_or(0, |i| self.len().saturating_sub(i + 1));
+        self.unread.min(below)
+    }
+
+    /// After new lines have been inserted, select the last line if following, otherwise count the new lines as unread
+    /// Following is paused if the last line wasn't selected before the new lines were inserted, i.e. the logs have been scrolled up
+    pub fn follow_new(&mut self, previous_len: usize) {
+        let selected = self.view.state.selected();
+        if self.follow && selected.is_some_and(|i| i + 1 < previous_len) {
+            self.follow = false;
+        }
+        if self.follow || selected.is_none() {
+            self.end();
+        } else {
+            self.unread += self.len().saturating_sub(previous_len);
+        }
+    }
+
+    /// The first line is selected, and there's possibly older lines to fetch
+    pub fn needs_older(&self) -> bool {
+        !self.complete && self.oldest.is_some() && self.view.state.selected() == Some(0)
+    }
+
     /// If scrolling horizontally along the logs, display a counter of the position in the in the scroll, `x/y`
     pub fn get_scroll_title(&mut self, width: u16) -> Option<String> {
         if self.horizontal_scroll_able(width) {
@@ -997,41 +1627,182 @@ impl Logs {
         })
     }
 
+    /// Split a log line into rows of at most `width` chars, each continuation row starts with the wrap indicator
+    /// The line is a single list item, so the whole line is highlighted when selected, and the list scrolls over the rows of each item
+    fn wrap_log_line(text: &Text<'static>, width: usize) -> Text<'static> {
+        let indicator_len = WRAP_INDICATOR.chars().count();
+        let Some(line) = text.lines.first() else {
+            return Text::default();
+        };
+        if width <= indicator_len {
+            return Text::from(line.clone());
+        }
+        let mut rows = vec![];
+        let mut row = vec![];
+        let mut row_len = 0;
+        let mut row_width = width;
+        for span in &line.spans {
+            let mut content = String::new();
+            for c in span.content.chars() {
+                if row_len == row_width {
+                    if !content.is_empty() {
+                        row.push(Span::styled(std::mem::take(&mut content), span.style));
+                    }
+                    rows.push(Line::from(std::mem::take(&mut row)));
+                    row.push(Span::styled(
+                        WRAP_INDICATOR,
+                        Style::default().add_modifier(Modifier::DIM),
+                    ));
+                    row_len = 0;
+                    row_width = width - indicator_len;
+                }
+                content.push(c);
+                row_len += 1;
+            }
+            if !content.is_empty() {
+                row.push(Span::styled(content, span.style));
+            }
+        }
+        rows.push(Line::from(row));
+        Text::from(rows)
+    }
+
+    /// Draw a line, followed by the number of repeats it stands for, and the number of lines in its group, if the group is collapsed
+    fn draw_line(&self, line: &LogLine, line_index: usize, colors: &AppColors) -> Text<'static> {
+        let mut text = line.draw(&self.json_widths, colors);
+        let mut suffix = String::new();
+        if let Some(count) = self.repeats.get(&line_index) {
+            suffix.push_str(&format!(" (x{count})"));
+        }
+        if let Some(count) = self.children.get(&line_index)
+            && !self.expanded.contains(&line_index)
+        {
+            suffix.push_str(&format!(" (+{count} lines)"));
+        }
+        if !suffix.is_empty()
+            && let Some(row) = text.lines.first_mut()
+        {
+            row.spans.push(Span::styled(
+                suffix,
+                Style::default().add_modifier(Modifier::BOLD),
+            ));
+        }
+        text
+    }
+
+    /// Draw the time column before the first row of a line, the continuation rows of a wrapped line are padded instead
+    /// A delta of at least a second is drawn in the warn colour, to make stalls stand out
+    fn add_time_column(
+        &self,
+        text: &mut Text<'static>,
+        line_index: usize,
+        column: LogTimeColumn,
+        selected: Option<Timestamp>,
+        colors: &AppColors,
+    ) {
+        let from = match column {
+            LogTimeColumn::Relative => selected,
+            LogTimeColumn::Delta => line_index
+                .checked_sub(1)
+                .and_then(|i| self.lines.get(i))
+                .and_then(|i| i.timestamp),
+        };
+        let (value, style) = match (from, self.lines.get(line_index).and_then(|i| i.timestamp)) {
+            (Some(from), Some(to)) => {
+                let stall = column == LogTimeColumn::Delta
+                    && to.duration_since(from) >= SignedDuration::from_secs(STALL_SECONDS);
+                let style = if stall {
+                    Style::default().fg(colors.logs.warn)
+                } else {
+                    Style::default().add_modifier(Modifier::DIM)
+                };
+                (LogTimeColumn::format(from, to), style)
+            }
+            _ => (String::new(), Style::default()),
+        };
+        let width = LogTimeColumn::WIDTH - 1;
+        for (index, row) in text.lines.iter_mut().enumerate() {
+            let content = if index == 0 {
+                format!("{value:>width$} ")
+            } else {
+                " ".repeat(LogTimeColumn::WIDTH)
+            };
+            row.spans.insert(0, Span::styled(content, style));
+        }
+    }
+
     /// Get the logs vec, but instead of cloning to whole vec, only clone items within x of the currently selected index, as well as only the current screen widths number of chars
     /// Where x is the abs different of the index plus the panel height & a padding
-    /// Take into account the char offset, so that can scroll a line
+    /// Take into account the char offset, so that can scroll a line, unless wrapping, where each line is split into rows of the panel width
     /// The rest can be just empty list items
-    pub fn get_visible_logs(&self, size: Size, padding: usize) -> Vec<Text<'static>> {
-        let current_index = self.lines.state.selected().unwrap_or_default();
+    pub fn get_visible_logs(
+        &self,
+        size: Size,
+        padding: usize,
+        colors: &AppColors,
+        wrap: bool,
+        column: Option<LogTimeColumn>,
+    ) -> Vec<Text<'static>> {
+        let current_index = self.view.state.selected().unwrap_or_default();
         let height_padding = usize::from(size.height) + padding;
         let char_offset = if self.offset > self.max_log_len {
             self.max_log_len
         } else {
             self.offset
         };
-
-        self.lines
+        let marked = self.marked_range();
+        let column_width = column.map_or(0, |_| LogTimeColumn::WIDTH);
+        let selected_time = self
+            .selected_line()
+            .and_then(|i| self.lines.get(i))
+            .and_then(|i| i.timestamp);
+
+        self.view
             .items
             .iter()
             .enumerate()
-            .map(|(index, item)| {
-                if current_index.abs_diff(index) <= height_padding {
-                    Self::format_log_line(item, char_offset, size.width)
-                } else {
-                    Text::from("")
+            .map(|(index, line_index)| {
+                let mut text = match self.lines.get(*line_index) {
+                    Some(item) if wrap && current_index.abs_diff(index) <= height_padding => {
+                        Self::wrap_log_line(
+                            &self.draw_line(item, *line_index, colors),
+                            usize::from(size.width)
+                                .saturating_sub(LOGS_PANEL_PADDING + column_width),
+                        )
+                    }
+                    Some(item) if current_index.abs_diff(index) <= height_padding => {
+                        Self::format_log_line(
+                            &self.draw_line(item, *line_index, colors),
+                            char_offset,
+                            size.width
+                                .saturating_sub(u16::try_from(column_width).unwrap_or_default()),
+                        )
+                    }
+                    _ => return Text::from(""),
+                };
+                if let Some(column) = column {
+                    self.add_time_column(&mut text, *line_index, column, selected_time, colors);
                 }
+                if marked.is_some_and(|(start, end)| (start..=end).contains(line_index)) {
+                    for line in &mut text.lines {
+                        for span in &mut line.spans {
+                            span.style = span.style.add_modifier(Modifier::REVERSED);
+                        }
+                    }
+                }
+                text
             })
             .collect()
     }
 
     /// The rest of the methods are basically forwarding from the underlying StatefulList
     pub fn get_state_title(&self) -> String {
-        self.lines.get_state_title()
+        self.view.get_state_title()
     }
 
     /// Return true it currently selected container logs are wide enough to horizontally scroll
     pub fn horizontal_scroll_able(&mut self, width: u16) -> bool {
-        if self.lines.items.is_empty() {
+        if self.view.items.is_empty() {
             return false;
         }
         self.adjusted_max_width = self.max_log_len.saturating_sub(width.into()) + 4;
@@ -1057,31 +1828,33 @@ impl Logs {
 
     /// Scroll lines down by one
     pub fn next(&mut self) {
-        self.lines.next();
+        self.view.next();
+        self.unread = self.unread();
     }
 
     /// Scroll lines up by one
     pub fn previous(&mut self) {
-        self.lines.previous();
+        self.view.previous();
     }
 
     /// Go to the end of the lines
     pub fn end(&mut self) {
-        self.lines.end();
+        self.view.end();
+        self.unread = 0;
     }
 
     /// Go to the start of the lines
     pub fn start(&mut self) {
-        self.lines.start();
+        self.view.start();
     }
 
     /// Get total number of log lines
     pub const fn len(&self) -> usize {
-        self.lines.items.len()
+        self.view.items.len()
     }
 
     pub const fn state(&mut self) -> &mut ListState {
-        &mut self.lines.state
+        &mut self.view.state
     }
 }
 
@@ -1287,19 +2060,32 @@ impl Columns {
 #[allow(clippy::unwrap_used)]
 mod tests {
 
-    use jiff::tz::TimeZone;
+    use jiff::{Timestamp, tz::TimeZone};
     use ratatui::{
         layout::Size,
-        text::{Line, Text},
+        style::{Color, Modifier, Style},
+        text::{Line, Span, Text},
     };
 
     use crate::{
-        app_data::{ContainerImage, LogSearch, Logs, LogsTz, RunningState},
+        app_data::{
+            ContainerImage, JsonLog, LogLevel, LogLine, LogSearch, LogTimeColumn, Logs, LogsTz,
+            RunningState, SaveLines, SaveScope,
+        },
+        config::AppColors,
         ui::log_sanitizer,
     };
 
     use super::{ByteStats, ContainerName, ContainerStatus, CpuStats, State};
 
+    /// A log line, with a timestamp of `i` seconds, and the level detected from the content
+    fn gen_log_line(i: usize, content: &str) -> (LogLine, LogsTz) {
+        let input = format!("2023-01-14T19:13:{i:02}.000000000Z {content}");
+        let (tz, content) = LogsTz::splitter(&input);
+        let level = LogLevel::detect(&content);
+        (LogLine::plain(Text::from(content), level), tz)
+    }
+
     #[test]
     /// Display CpuStats as a string
     fn test_container_state_cpustats_to_string() {
@@ -1431,21 +2217,414 @@ mod tests {
         let mut logs = Logs::default();
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(Text::from(line.clone()), tz.clone(), true);
-        logs.insert(Text::from(line.clone()), tz.clone(), true);
-        logs.insert(Text::from(line), tz, true);
+        logs.insert(LogLine::from(Text::from(line.clone())), tz.clone(), true);
+        logs.insert(LogLine::from(Text::from(line.clone())), tz.clone(), true);
+        logs.insert(LogLine::from(Text::from(line)), tz, true);
 
-        assert_eq!(logs.lines.items.len(), 1);
+        assert_eq!(logs.lines.len(), 1);
 
         let input = "2023-01-15T19:13:30.783138328Z Lorem ipsum dolor sit amet";
         let (tz, _) = LogsTz::splitter(input);
         let line = log_sanitizer::remove_ansi(input);
 
-        logs.insert(Text::from(line.clone()), tz.clone(), true);
-        logs.insert(Text::from(line.clone()), tz.clone(), true);
-        logs.insert(Text::from(line), tz, true);
+        logs.insert(LogLine::from(Text::from(line.clone())), tz.clone(), true);
+        logs.insert(LogLine::from(Text::from(line.clone())), tz.clone(), true);
+        logs.insert(LogLine::from(Text::from(line)), tz, true);
 
-        assert_eq!(logs.lines.items.len(), 2);
+        assert_eq!(logs.lines.len(), 2);
+    }
+
+    #[test]
+    /// Older lines are inserted before the current lines, duplicates are ignored, and the selected line is kept
+    fn test_container_state_logz_insert_older() {
+        let gen_line = |i: usize| gen_log_line(i, &format!("line {i}"));
+        let mut logs = Logs::default();
+        assert!(!logs.needs_older());
+        for i in 5..=7 {
+            let (line, tz) = gen_line(i);
+            logs.insert(line, tz, true);
+        }
+        assert_eq!(logs.oldest(), Some(&gen_line(5).1));
+        logs.start();
+        logs.next();
+        assert!(!logs.needs_older());
+        logs.start();
+        assert!(logs.needs_older());
+
+        logs.insert_older((1..=5).map(gen_line).collect(), true);
+        assert_eq!(logs.lines.len(), 7);
+        assert_eq!(logs.lines[0].text, Text::from("line 1"));
+        assert_eq!(logs.lines[4].text, Text::from("line 5"));
+        assert_eq!(logs.view.state.selected(), Some(4));
+        assert_eq!(logs.oldest(), Some(&gen_line(1).1));
+
+        logs.start();
+        assert!(logs.needs_older());
+        logs.set_complete();
+        assert!(!logs.needs_older());
+    }
+
+    #[test]
+    /// When a JSON column is widened, every earlier JSON line is padded wider, so the max line length is recalculated
+    fn test_container_state_logz_max_log_len() {
+        let columns = ["level", "msg"].map(str::to_owned);
+        let gen_line = |i: usize, level: &str, msg: &str| {
+            let (_, tz) = gen_log_line(i, "");
+            let log = JsonLog::parse(&format!(r#"{{"level":"{level}","msg":"{msg}"}}"#)).unwrap();
+            (LogLine::json(None, &columns, log), tz)
+        };
+        let mut logs = Logs::default();
+        let (line, tz) = gen_line(1, "info", "a long message");
+        logs.insert(line, tz, true);
+        assert_eq!(logs.max_log_len, 20);
+
+        let (line, tz) = gen_line(2, "warning", "short");
+        logs.insert(line, tz, true);
+        assert_eq!(logs.max_log_len, 23);
+
+        logs.insert_older(vec![gen_line(0, "critical", "a longer message")], true);
+        assert_eq!(logs.max_log_len, 26);
+    }
+
+    #[test]
+    /// The level filter hides lines below the minimum level, but keeps lines without a level, and the counts ignore the filter
+    fn test_container_state_logz_level_filter() {
+        let mut logs = Logs::default();
+        for (i, content) in [
+            "INFO starting",
+            "DEBUG config loaded",
+            "ERROR connection refused",
+            "    at db/pool.go:42",
+            "WARN retrying",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let (line, tz) = gen_log_line(i + 10, content);
+            logs.insert(line, tz, true);
+        }
+        logs.end();
+        assert_eq!(
+            logs.level_counts(),
+            [
+                (LogLevel::Error, 1),
+                (LogLevel::Warn, 1),
+                (LogLevel::Info, 1),
+                (LogLevel::Debug, 1)
+            ]
+        );
+        assert_eq!(logs.len(), 5);
+
+        logs.cycle_level_filter(true);
+        assert_eq!(logs.min_level(), Some(LogLevel::Debug));
+        assert_eq!(logs.len(), 5);
+
+        logs.cycle_level_filter(true);
+        logs.cycle_level_filter(true);
+        assert_eq!(logs.min_level(), Some(LogLevel::Warn));
+        assert_eq!(logs.view.items, [2, 3, 4]);
+        assert_eq!(logs.get_state_title(), " 3/3");
+
+        // New lines are only shown if they pass the filter
+        let (line, tz) = gen_log_line(20, "INFO done");
+        logs.insert(line, tz, true);
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs.level_counts()[2], (LogLevel::Info, 2));
+
+        // Search results are positions in the filtered lines
+        logs.search_term_push('r', true);
+        assert_eq!(logs.search_results, [0, 2]);
+
+        // Older lines are prepended, and the selected line is kept the same
+        logs.insert_older(
+            vec![
+                gen_log_line(1, "ERROR first"),
+                gen_log_line(2, "INFO second"),
+            ],
+            true,
+        );
+        assert_eq!(logs.view.items, [0, 4, 5, 6]);
+        assert_eq!(logs.view.state.selected(), Some(3));
+
+        // The selected line is hidden, so the closest line before it is selected
+        logs.cycle_level_filter(true);
+        assert_eq!(logs.view.items, [0, 4, 5]);
+        assert_eq!(logs.view.state.selected(), Some(2));
+
+        logs.cycle_level_filter(true);
+        assert_eq!(logs.min_level(), None);
+        assert_eq!(logs.len(), 8);
+        assert_eq!(logs.view.state.selected(), Some(5));
+    }
+
+    #[test]
+    /// A selection runs from the mark to the selected line, in either direction, and only includes shown lines
+    fn test_container_state_logz_mark() {
+        let gen_line = |i: usize| gen_log_line(i, &format!("INFO line {i}"));
+        let mut logs = Logs::default();
+        for i in 5..=9 {
+            let (line, tz) = gen_line(i);
+            logs.insert(line, tz, true);
+        }
+        logs.end();
+        assert!(!logs.is_marked());
+        assert_eq!(logs.marked_text(), ["INFO line 9"]);
+
+        logs.toggle_mark();
+        assert!(logs.is_marked());
+        assert_eq!(logs.marked_count(), 1);
+        logs.previous();
+        logs.previous();
+        assert_eq!(logs.marked_count(), 3);
+        assert_eq!(
+            logs.marked_text(),
+            ["INFO line 7", "INFO line 8", "INFO line 9"]
+        );
+
+        // The mark stays on the same line when older lines are inserted
+        logs.start();
+        logs.insert_older((1..=4).map(gen_line).collect(), true);
+        assert_eq!(logs.marked_count(), 5);
+
+        // Hidden lines aren't included
+        logs.cycle_level_filter(true);
+        logs.cycle_level_filter(true);
+        logs.cycle_level_filter(true);
+        assert_eq!(logs.len(), 0);
+        assert_eq!(logs.marked_count(), 0);
+        assert!(logs.marked_text().is_empty());
+
+        logs.toggle_mark();
+        assert!(!logs.is_marked());
+    }
+
+    #[test]
+    /// The time range hides lines outside of it, and a jump selects the first line at, or after, the time, repeated once older lines are inserted
+    fn test_container_state_logz_time() {
+        let gen_line = |i: usize| gen_log_line(i, &format!("line {i}"));
+        let time = |i: usize| {
+            format!("2023-01-14T19:13:{i:02}Z")
+                .parse::<Timestamp>()
+                .unwrap()
+        };
+        let mut logs = Logs::default();
+        for i in [10, 11, 15, 16, 30] {
+            let (line, tz) = gen_line(i);
+            logs.insert(line, tz, true);
+        }
+        logs.end();
+
+        logs.set_time_range(Some(time(11)), Some(time(16)), true);
+        assert_eq!(logs.time_range(), (Some(time(11)), Some(time(16))));
+        assert_eq!(logs.view.items, [1, 2, 3]);
+        assert_eq!(logs.view.state.selected(), Some(2));
+
+        logs.set_time_range(None, None, true);
+        logs.jump_to(time(12));
+        assert_eq!(logs.view.state.selected(), Some(2));
+        assert!(!logs.needs_from(time(12)));
+
+        // Every line is older, so the last line is selected
+        logs.jump_to(time(45));
+        assert_eq!(logs.view.state.selected(), Some(4));
+
+        // The jump is older than every line, so it's repeated when the older lines are inserted
+        logs.jump_to(time(3));
+        assert!(logs.needs_from(time(3)));
+        assert_eq!(logs.view.state.selected(), Some(0));
+        logs.insert_older((1..=5).map(gen_line).collect(), true);
+        assert_eq!(logs.view.state.selected(), Some(2));
+        assert!(!logs.needs_from(time(3)));
+
+        // The delta column shows the time since the previous line, relative to the selected line otherwise
+        let size = Size {
+            width: 30,
+            height: 10,
+        };
+        let result = logs.get_visible_logs(
+            size,
+            10,
+            &AppColors::new(),
+            false,
+            Some(LogTimeColumn::Delta),
+        );
+        assert_eq!(result[0].to_string(), "         line 1");
+        assert_eq!(result[1].to_string(), " +1.000s line 2");
+        assert_eq!(result[9].to_string(), "+14.000s line 30");
+        assert_eq!(result[9].lines[0].spans[0].style.fg, Some(Color::Yellow));
+
+        let result = logs.get_visible_logs(
+            size,
+            10,
+            &AppColors::new(),
+            false,
+            Some(LogTimeColumn::Relative),
+        );
+        assert_eq!(result[0].to_string(), " -2.000s line 1");
+        assert_eq!(result[2].to_string(), " +0.000s line 3");
+        assert_eq!(result[9].to_string(), "+27.000s line 30");
+    }
+
+    #[test]
+    /// Repeated lines, ignoring the timestamp, are collapsed into the last repeat, and continuation lines are grouped under their parent, which expands & collapses
+    fn test_container_state_logz_collapse_group() {
+        let gen_line = |i: usize, content: &str| {
+            let (line, tz) = gen_log_line(i, &format!("{i:02} {content}"));
+            (line.content(content), tz)
+        };
+        let mut logs = Logs::default();
+        for (i, content) in [
+            "GET /health",
+            "GET /health",
+            "GET /health",
+            "Exception in thread main",
+            "\tat com.example.Main.run",
+            "Caused by: java.io.IOException",
+            "\tat com.example.Io.read",
+            "GET /health",
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let (line, tz) = gen_line(i, content);
+            logs.insert(line, tz, true);
+        }
+        logs.end();
+        let size = Size {
+            width: 60,
+            height: 10,
+        };
+        let visible = |logs: &Logs| {
+            logs.get_visible_logs(size, 10, &AppColors::new(), false, None)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(logs.len(), 8);
+
+        logs.toggle_collapse(true);
+        assert!(logs.collapse());
+        assert_eq!(logs.view.items, [2, 3, 4, 5, 6, 7]);
+        assert_eq!(visible(&logs)[0], "02 GET /health (x3)");
+        assert_eq!(logs.view.state.selected(), Some(5));
+
+        // A new repeat replaces the last line
+        let (line, tz) = gen_line(8, "GET /health");
+        logs.insert(line, tz, true);
+        assert_eq!(logs.view.items, [2, 3, 4, 5, 6, 8]);
+        assert_eq!(visible(&logs)[5], "08 GET /health (x2)");
+
+        logs.toggle_group(true);
+        assert!(logs.group());
+        assert_eq!(logs.view.items, [2, 3, 8]);
+        assert_eq!(visible(&logs)[1], "03 Exception in thread main (+3 lines)");
+        assert!(!logs.toggle_expanded(true));
+
+        logs.previous();
+        assert!(logs.toggle_expanded(true));
+        assert_eq!(logs.view.items, [2, 3, 4, 5, 6, 8]);
+        assert_eq!(visible(&logs)[1], "03 Exception in thread main");
+
+        // A continuation line collapses the group it's in
+        logs.next();
+        logs.next();
+        assert!(logs.toggle_expanded(true));
+        assert_eq!(logs.view.items, [2, 3, 8]);
+        assert_eq!(logs.view.state.selected(), Some(1));
+
+        logs.toggle_collapse(true);
+        logs.toggle_group(true);
+        assert_eq!(logs.len(), 9);
+    }
+
+    #[test]
+    /// The results to save are the search matches, else every line that passes the filters, and the range is the since & until times
+    fn test_container_state_logz_save_lines() {
+        let mut logs = Logs::default();
+        let mut timestamps = vec![];
+        for (i, content) in ["alpha", "beta", "alphabet"].into_iter().enumerate() {
+            let (line, tz) = gen_log_line(i, content);
+            timestamps.extend(tz.timestamp());
+            logs.insert(line, tz, true);
+        }
+        let results = |indexes: &[usize]| {
+            SaveLines::Results(indexes.iter().map(|i| timestamps[*i]).collect())
+        };
+
+        assert_eq!(logs.save_lines(SaveScope::All), SaveLines::All);
+        assert_eq!(logs.save_lines(SaveScope::Results), results(&[0, 1, 2]));
+        assert_eq!(
+            logs.save_lines(SaveScope::Range),
+            SaveLines::Range((None, None))
+        );
+
+        for c in "alpha".chars() {
+            logs.search_term_push(c, true);
+        }
+        assert_eq!(logs.save_lines(SaveScope::Results), results(&[0, 2]));
+        logs.search_term_clear();
+
+        logs.set_time_range(Some(timestamps[1]), None, true);
+        assert_eq!(logs.save_lines(SaveScope::Results), results(&[1, 2]));
+        assert_eq!(
+            logs.save_lines(SaveScope::Range),
+            SaveLines::Range((Some(timestamps[1]), None))
+        );
+    }
+
+    #[test]
+    /// New lines are followed until the logs are scrolled up, then they're counted as unread, until scrolled past, or following is resumed
+    fn test_container_state_logz_follow() {
+        let gen_line = |i: usize| gen_log_line(i, &format!("line {i}"));
+        let mut logs = Logs::default();
+        let insert = |logs: &mut Logs, range: std::ops::RangeInclusive<usize>| {
+            let previous_len = logs.len();
+            for i in range {
+                let (line, tz) = gen_line(i);
+                logs.insert(line, tz, true);
+            }
+            logs.follow_new(previous_len);
+        };
+        insert(&mut logs, 1..=3);
+        assert!(logs.follow());
+        assert_eq!(logs.view.state.selected(), Some(2));
+
+        insert(&mut logs, 4..=4);
+        assert_eq!(logs.view.state.selected(), Some(3));
+        assert_eq!(logs.unread(), 0);
+
+        logs.previous();
+        insert(&mut logs, 5..=7);
+        assert!(!logs.follow());
+        assert_eq!(logs.view.state.selected(), Some(2));
+        assert_eq!(logs.unread(), 3);
+
+        // Scrolling down reads the new lines
+        logs.next();
+        logs.next();
+        assert_eq!(logs.unread(), 2);
+        logs.next();
+        logs.next();
+        assert_eq!(logs.unread(), 0);
+        logs.previous();
+        assert_eq!(logs.unread(), 0);
+
+        // Whilst paused, the selected line stays the same, even if it's the last line
+        logs.end();
+        insert(&mut logs, 8..=8);
+        assert_eq!(logs.view.state.selected(), Some(6));
+        assert_eq!(logs.unread(), 1);
+
+        logs.toggle_follow();
+        assert!(logs.follow());
+        assert_eq!(logs.view.state.selected(), Some(7));
+        assert_eq!(logs.unread(), 0);
+
+        logs.toggle_follow();
+        insert(&mut logs, 9..=9);
+        logs.live();
+        assert!(logs.follow());
+        assert_eq!(logs.view.state.selected(), Some(8));
     }
 
     #[test]
@@ -1506,15 +2685,15 @@ mod tests {
 
         let input = "2023-01-14T19:13:30.783138328Z Hello world some long line".to_owned();
         let (tz, _) = LogsTz::splitter(&input);
-        logs.insert(Text::from(input), tz, true);
+        logs.insert(LogLine::from(Text::from(input)), tz, true);
 
         let input = "2023-01-14T19:13:31.783138328Z Hello world some line".to_owned();
         let (tz, _) = LogsTz::splitter(&input);
-        logs.insert(Text::from(input), tz, true);
+        logs.insert(LogLine::from(Text::from(input)), tz, true);
 
         let input = "2023-01-14T19:13:32.783138328Z Hello world".to_owned();
         let (tz, _) = LogsTz::splitter(&input);
-        logs.insert(Text::from(input), tz, true);
+        logs.insert(LogLine::from(Text::from(input)), tz, true);
 
         logs.offset = 43;
         let result = logs.get_visible_logs(
@@ -1523,6 +2702,9 @@ mod tests {
                 height: 10,
             },
             10,
+            &AppColors::new(),
+            false,
+            None,
         );
         assert_eq!(
             vec![
@@ -1534,6 +2716,67 @@ mod tests {
         );
     }
 
+    #[test]
+    /// When wrapping, each line is split into rows of the panel width, less the borders & highlight symbol, the char offset is ignored
+    fn test_to_vec_wrap() {
+        let mut logs = Logs::default();
+
+        let input = "2023-01-14T19:13:30.783138328Z Hello world some long line".to_owned();
+        let (tz, content) = LogsTz::splitter(&input);
+        logs.insert(LogLine::from(Text::from(content)), tz, true);
+
+        let input = "2023-01-14T19:13:31.783138328Z short".to_owned();
+        let (tz, content) = LogsTz::splitter(&input);
+        logs.insert(LogLine::from(Text::from(content)), tz, true);
+
+        logs.offset = 4;
+        let result = logs.get_visible_logs(
+            Size {
+                width: 14,
+                height: 10,
+            },
+            10,
+            &AppColors::new(),
+            true,
+            None,
+        );
+        assert_eq!(
+            result
+                .iter()
+                .map(|i| i.lines.iter().map(ToString::to_string).collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [
+                vec!["Hello worl", "↪ d some l", "↪ ong line"],
+                vec!["short"]
+            ]
+        );
+    }
+
+    #[test]
+    /// Styles are kept when a span is split across rows, and a line exactly the width isn't given an empty row
+    fn test_wrap_log_line() {
+        let text = Text::from(Line::from(vec![
+            Span::from("abc"),
+            Span::styled("defgh", Style::default().fg(Color::Red)),
+        ]));
+        let result = Logs::wrap_log_line(&text, 5);
+        assert_eq!(result.lines.len(), 2);
+        assert_eq!(result.lines[0].spans[1].content, "de");
+        assert_eq!(result.lines[0].spans[1].style.fg, Some(Color::Red));
+        assert_eq!(result.lines[1].spans[0].content, "↪ ");
+        assert!(
+            result.lines[1].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::DIM)
+        );
+        assert_eq!(result.lines[1].spans[1].content, "fgh");
+        assert_eq!(result.lines[1].spans[1].style.fg, Some(Color::Red));
+
+        let result = Logs::wrap_log_line(&Text::from("abcde"), 5);
+        assert_eq!(result.lines.len(), 1);
+    }
+
     #[test]
     /// Test the get_scroll_title methods
     fn test_scroll_title() {
@@ -1544,14 +2787,14 @@ mod tests {
 
         let input = "short".to_owned();
         let (tz, _) = LogsTz::splitter(&input);
-        logs.insert(Text::from(input), tz, true);
+        logs.insert(LogLine::from(Text::from(input)), tz, true);
 
         let result = logs.get_scroll_title(10);
         assert!(result.is_none());
 
         let input = "2023-01-14T19:13:30.783138328Z Hello world some long line".to_owned();
         let (tz, _) = LogsTz::splitter(&input);
-        logs.insert(Text::from(input), tz, true);
+        logs.insert(LogLine::from(Text::from(input)), tz, true);
 
         let result = logs.get_scroll_title(10);
         assert_eq!(result, Some("    0/51 → ".to_owned()));
@@ -1580,7 +2823,7 @@ mod tests {
                 format!("{i}, Hello world some long line {i}")
             };
             let (tz, _) = LogsTz::splitter(&input);
-            logs.insert(Text::from(input), tz, true);
+            logs.insert(LogLine::from(Text::from(input)), tz, true);
         }
 
         logs.search_term_push('H', true);
@@ -1598,7 +2841,7 @@ mod tests {
         for i in 1..=10 {
             let input = format!("{i}, Hello world some long line {i}");
             let (tz, _) = LogsTz::splitter(&input);
-            logs.insert(Text::from(input), tz, true);
+            logs.insert(LogLine::from(Text::from(input)), tz, true);
         }
 
         let log_search = LogSearch::from(&logs);
@@ -1660,7 +2903,7 @@ mod tests {
         logs.search_term_clear();
         logs.search_term_push('2', true);
         let log_search = LogSearch::from(&logs);
-        assert_eq!(logs.lines.state.selected(), Some(1));
+        assert_eq!(logs.view.state.selected(), Some(1));
         assert_eq!(
             log_search,
             LogSearch {
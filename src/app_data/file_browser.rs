@@ -0,0 +1,163 @@
+use std::fmt;
+
+use super::{ContainerId, StatefulList};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FileKind {
+    Dir,
+    Symlink,
+    File,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEntry {
+    pub name: String,
+    pub kind: FileKind,
+}
+
+impl FileEntry {
+    pub fn new(name: &str, kind: FileKind) -> Self {
+        Self {
+            name: name.to_owned(),
+            kind,
+        }
+    }
+}
+
+/// Display in the same style as `ls -F`, directories end with a `/`, symlinks with an `@`
+impl fmt::Display for FileEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            FileKind::Dir => write!(f, "{}/", self.name),
+            FileKind::Symlink => write!(f, "{}@", self.name),
+            FileKind::File => write!(f, "{}", self.name),
+        }
+    }
+}
+
+/// State of the file browser of a single container
+/// The upload text is the local path of a file, that will be uploaded into the current directory
+#[derive(Debug, Clone)]
+pub struct FileBrowser {
+    pub entries: StatefulList<FileEntry>,
+    pub id: ContainerId,
+    pub name: String,
+    pub path: String,
+    pub preview: Option<(String, String)>,
+    pub truncated: bool,
+    pub upload: String,
+}
+
+impl FileBrowser {
+    pub fn new(id: ContainerId, name: String) -> Self {
+        Self {
+            entries: StatefulList::new(vec![]),
+            id,
+            name,
+            path: "/".to_owned(),
+            preview: None,
+            truncated: false,
+            upload: String::new(),
+        }
+    }
+
+    /// Join a name onto a directory path
+    pub fn join(path: &str, name: &str) -> String {
+        if path.ends_with('/') {
+            format!("{path}{name}")
+        } else {
+            format!("{path}/{name}")
+        }
+    }
+
+    /// Get the parent directory of the current path, the parent of `/` is itself
+    pub fn parent(&self) -> String {
+        match self.path.trim_end_matches('/').rsplit_once('/') {
+            Some((parent, _)) if !parent.is_empty() => parent.to_owned(),
+            _ => "/".to_owned(),
+        }
+    }
+
+    /// Get the full path of the currently selected entry
+    pub fn selected(&self) -> Option<(String, FileKind)> {
+        self.entries
+            .state
+            .selected()
+            .and_then(|i| self.entries.items.get(i))
+            .map(|i| (Self::join(&self.path, &i.name), i.kind))
+    }
+
+    /// Replace the current directory & entries, directories are listed first, and the first entry is selected
+    /// Truncated is true if only some of the entries could be listed
+    pub fn set_entries(&mut self, path: String, mut entries: Vec<FileEntry>, truncated: bool) {
+        entries.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+        entries.dedup();
+        self.path = path;
+        self.preview = None;
+        self.truncated = truncated;
+        self.entries = StatefulList::new(entries);
+        self.entries.start();
+    }
+
+    /// Parse the output of `ls -1Ap`, directories have a trailing `/`
+    pub fn parse_ls(output: &str) -> Vec<FileEntry> {
+        output
+            .lines()
+            .map(|i| i.trim_end_matches('\r'))
+            .filter(|i| !i.is_empty())
+            .map(|i| {
+                i.strip_suffix('/').map_or_else(
+                    || FileEntry::new(i, FileKind::File),
+                    |dir| FileEntry::new(dir, FileKind::Dir),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FileBrowser, FileEntry, FileKind};
+    use crate::app_data::ContainerId;
+
+    #[test]
+    /// Paths are joined, and parents are calculated, correctly
+    fn test_file_browser_paths() {
+        let mut browser = FileBrowser::new(ContainerId::from("1"), "container_1".to_owned());
+        assert_eq!(FileBrowser::join("/", "etc"), "/etc");
+        assert_eq!(FileBrowser::join("/etc", "hosts"), "/etc/hosts");
+        assert_eq!(browser.parent(), "/");
+
+        browser.path = "/etc".to_owned();
+        assert_eq!(browser.parent(), "/");
+
+        browser.path = "/usr/local/bin".to_owned();
+        assert_eq!(browser.parent(), "/usr/local");
+    }
+
+    #[test]
+    /// ls output parsed into directories & files, which are then sorted with directories first
+    fn test_file_browser_parse_ls() {
+        let mut browser = FileBrowser::new(ContainerId::from("1"), "container_1".to_owned());
+        let entries = FileBrowser::parse_ls("hosts\r\nssl/\n\n.profile\napk/\n");
+        assert_eq!(entries.len(), 4);
+        browser.set_entries("/etc".to_owned(), entries, false);
+        assert_eq!(
+            browser
+                .entries
+                .items
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["apk/", "ssl/", ".profile", "hosts"]
+        );
+        assert_eq!(
+            browser.selected(),
+            Some(("/etc/apk".to_owned(), FileKind::Dir))
+        );
+        assert_eq!(
+            FileEntry::new("link", FileKind::Symlink).to_string(),
+            "link@"
+        );
+    }
+}
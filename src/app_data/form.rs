@@ -0,0 +1,206 @@
+/// A field of a form, typed fields accept text input, the others can only be chosen from a list
+pub trait Field: Copy + PartialEq {
+    fn typed(self) -> bool {
+        true
+    }
+}
+
+/// Store the error of a field, if there is one, and return the parsed value
+pub(super) fn check<F, T>(
+    errors: &mut Vec<(F, String)>,
+    field: F,
+    result: Result<T, String>,
+) -> Option<T> {
+    result.map_err(|e| errors.push((field, e))).ok()
+}
+
+/// The state shared by every form, each field is stored as typed, in the order they are shown, and only parsed when the form is submitted
+/// Errors are shown next to their field, and the status is the error returned by Docker once the form has been submitted
+#[derive(Debug, Clone)]
+pub struct Form<F> {
+    pub errors: Vec<(F, String)>,
+    pub selected: F,
+    pub status: Option<String>,
+    values: Vec<(F, String)>,
+}
+
+impl<F: Field> Form<F> {
+    pub fn new(values: Vec<(F, String)>, selected: F) -> Self {
+        Self {
+            errors: vec![],
+            selected,
+            status: None,
+            values,
+        }
+    }
+
+    /// The fields of the form, in the order they are shown
+    pub fn fields(&self) -> Vec<F> {
+        self.values.iter().map(|(i, _)| *i).collect()
+    }
+
+    pub fn value(&self, field: F) -> &str {
+        self.values
+            .iter()
+            .find(|(i, _)| *i == field)
+            .map_or("", |(_, value)| value.as_str())
+    }
+
+    /// The trimmed value of a field, None if it's empty
+    pub fn optional(&self, field: F) -> Option<&str> {
+        Some(self.value(field).trim()).filter(|i| !i.is_empty())
+    }
+
+    fn value_mut(&mut self, field: F) -> Option<&mut String> {
+        self.values
+            .iter_mut()
+            .find(|(i, _)| *i == field)
+            .map(|(_, value)| value)
+    }
+
+    pub fn error(&self, field: F) -> Option<&str> {
+        self.errors
+            .iter()
+            .find(|(i, _)| *i == field)
+            .map(|(_, error)| error.as_str())
+    }
+
+    /// Select the next, or previous, field, wrapping around at each end
+    pub fn change_field(&mut self, forward: bool) {
+        let fields = self.fields();
+        let len = fields.len();
+        let index = fields
+            .iter()
+            .position(|i| *i == self.selected)
+            .unwrap_or_default();
+        let index = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        self.selected = fields[index];
+    }
+
+    /// Type into the selected field, if it can be typed into
+    pub fn push(&mut self, c: char) {
+        if self.selected.typed()
+            && let Some(value) = self.value_mut(self.selected)
+        {
+            value.push(c);
+        }
+    }
+
+    pub fn pop(&mut self) {
+        if self.selected.typed()
+            && let Some(value) = self.value_mut(self.selected)
+        {
+            value.pop();
+        }
+    }
+
+    /// Replace the value of the selected field with the next, or previous, choice, wrapping around at each end
+    pub fn choose<S: AsRef<str>>(&mut self, choices: &[S], forward: bool) {
+        if choices.is_empty() {
+            return;
+        }
+        let len = choices.len();
+        let next = match choices
+            .iter()
+            .position(|i| i.as_ref() == self.value(self.selected))
+        {
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+        if let Some(value) = self.value_mut(self.selected) {
+            choices[next].as_ref().clone_into(value);
+        }
+    }
+
+    /// Parse every field, with the parsers of the form, any errors are stored, to be shown next to the field, and None is returned
+    pub fn validate<T>(
+        &mut self,
+        parse: impl FnOnce(&Self, &mut Vec<(F, String)>) -> Option<T>,
+    ) -> Option<T> {
+        self.status = None;
+        let mut errors = vec![];
+        let output = parse(self, &mut errors);
+        let valid = errors.is_empty();
+        self.errors = errors;
+        output.filter(|_| valid)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Field, Form, check};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TestField {
+        Text,
+        Choice,
+    }
+
+    impl Field for TestField {
+        fn typed(self) -> bool {
+            self == Self::Text
+        }
+    }
+
+    fn gen_form() -> Form<TestField> {
+        Form::new(
+            vec![
+                (TestField::Text, String::new()),
+                (TestField::Choice, "b".to_owned()),
+            ],
+            TestField::Text,
+        )
+    }
+
+    #[test]
+    /// Fields wrap at each end, only typed fields can be typed into, and choices wrap at each end
+    fn test_form_edit() {
+        let mut form = gen_form();
+        form.push('a');
+        form.push('b');
+        form.pop();
+        assert_eq!(form.value(TestField::Text), "a");
+
+        form.change_field(false);
+        assert_eq!(form.selected, TestField::Choice);
+        form.push('c');
+        assert_eq!(form.value(TestField::Choice), "b");
+        form.choose(&["a", "b"], true);
+        assert_eq!(form.value(TestField::Choice), "a");
+        form.choose(&["a", "b"], false);
+        assert_eq!(form.value(TestField::Choice), "b");
+        form.change_field(true);
+        assert_eq!(form.selected, TestField::Text);
+    }
+
+    #[test]
+    /// Errors are stored against their field, and the status is cleared, when validating
+    fn test_form_validate() {
+        let mut form = gen_form();
+        form.status = Some("error".to_owned());
+        let result = form.validate(|form, errors| {
+            check(
+                errors,
+                TestField::Text,
+                form.optional(TestField::Text)
+                    .ok_or_else(|| "required".to_owned()),
+            )
+            .map(str::to_owned)
+        });
+        assert!(result.is_none());
+        assert!(form.status.is_none());
+        assert_eq!(form.error(TestField::Text), Some("required"));
+
+        form.push('a');
+        let result = form.validate(|form, _| form.optional(TestField::Text).map(str::to_owned));
+        assert_eq!(result, Some("a".to_owned()));
+        assert!(form.error(TestField::Text).is_none());
+    }
+}
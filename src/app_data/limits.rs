@@ -0,0 +1,406 @@
+use std::fmt;
+
+use bollard::models::HostConfig;
+
+use super::{
+    ContainerId, RESTART_POLICIES,
+    form::{Field, Form, check},
+    new_container::{parse_cpus, parse_memory},
+};
+
+/// The cpu period used when the container limits its cpus with a quota, the same as the Docker default
+pub const CPU_PERIOD: i64 = 100_000;
+
+/// The fields of the limits form, in the order they are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitsField {
+    Memory,
+    Swap,
+    Cpus,
+    CpuShares,
+    Cpuset,
+    Pids,
+    Restart,
+}
+
+impl LimitsField {
+    pub const ALL: [Self; 7] = [
+        Self::Memory,
+        Self::Swap,
+        Self::Cpus,
+        Self::CpuShares,
+        Self::Cpuset,
+        Self::Pids,
+        Self::Restart,
+    ];
+
+    /// Example input, shown below the form when the field is selected
+    pub const fn hint(self) -> &'static str {
+        match self {
+            Self::Memory => "with a b, k, m, or g suffix, e.g. 512m, empty to leave unchanged",
+            Self::Swap => "memory + swap, at least the memory limit, -1 for unlimited swap",
+            Self::Cpus => "number of cpus, e.g. 1.5, empty to leave unchanged",
+            Self::CpuShares => "relative cpu weight, the default is 1024",
+            Self::Cpuset => "cpus the container can use, e.g. 0-3 or 0,2",
+            Self::Pids => "maximum number of processes, -1 for unlimited",
+            Self::Restart => "← → to choose the restart policy",
+        }
+    }
+
+    /// Fields whose value is chosen from a list, rather than typed
+    pub const fn is_choice(self) -> bool {
+        matches!(self, Self::Restart)
+    }
+}
+
+impl Field for LimitsField {
+    fn typed(self) -> bool {
+        !self.is_choice()
+    }
+}
+
+impl fmt::Display for LimitsField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Memory => "memory",
+            Self::Swap => "swap",
+            Self::Cpus => "cpus",
+            Self::CpuShares => "shares",
+            Self::Cpuset => "cpuset",
+            Self::Pids => "pids",
+            Self::Restart => "restart",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The validated limits, ready to be sent to Docker, a None value is left unchanged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LimitsSpec {
+    pub cpu_quota: bool,
+    pub cpu_shares: Option<i64>,
+    pub cpuset: Option<String>,
+    pub max_retry: Option<i64>,
+    pub memory: Option<i64>,
+    pub memory_swap: Option<i64>,
+    pub nano_cpus: Option<i64>,
+    pub pids_limit: Option<i64>,
+    pub restart: String,
+}
+
+/// Show a number of bytes with the largest suffix that it's an exact multiple of, so that it can be parsed back
+fn memory_value(bytes: i64) -> String {
+    [(1024 * 1024 * 1024, "g"), (1024 * 1024, "m"), (1024, "k")]
+        .iter()
+        .find(|(size, _)| bytes % size == 0)
+        .map_or_else(
+            || bytes.to_string(),
+            |(size, suffix)| format!("{}{suffix}", bytes / size),
+        )
+}
+
+/// Show a number of nano cpus as a number of cpus, without any trailing zeros
+#[allow(clippy::cast_precision_loss)]
+fn cpus_value(nano_cpus: i64) -> String {
+    format!("{}", nano_cpus as f64 / 1_000_000_000.0)
+}
+
+fn parse_swap(value: &str) -> Result<i64, String> {
+    if value == "-1" {
+        Ok(-1)
+    } else {
+        parse_memory(value)
+    }
+}
+
+fn parse_shares(value: &str) -> Result<i64, String> {
+    value
+        .parse::<i64>()
+        .ok()
+        .filter(|i| *i >= 2)
+        .ok_or_else(|| "must be a whole number, of at least 2".to_owned())
+}
+
+/// A comma separated list of cpus, or ranges of cpus, e.g. `0-3,6`
+fn parse_cpuset(value: &str) -> Result<String, String> {
+    let valid = value.split(',').all(|part| {
+        let mut range = part.splitn(2, '-').map(|i| i.trim().parse::<u16>());
+        match (range.next(), range.next()) {
+            (Some(Ok(_)), None) => true,
+            (Some(Ok(start)), Some(Ok(end))) => start <= end,
+            _ => false,
+        }
+    });
+    if valid {
+        Ok(value.replace(' ', ""))
+    } else {
+        Err("must be a list of cpus, or ranges of cpus, e.g. 0-3,6".to_owned())
+    }
+}
+
+/// 0, or -1, both mean unlimited, Docker expects -1
+fn parse_pids(value: &str) -> Result<i64, String> {
+    match value.parse::<i64>() {
+        Ok(-1 | 0) => Ok(-1),
+        Ok(i) if i > 0 => Ok(i),
+        _ => Err("must be a whole number, or -1 for unlimited".to_owned()),
+    }
+}
+
+/// The limits form, prefilled with the current limits of the container
+#[derive(Debug, Clone)]
+pub struct Limits {
+    /// The container uses a cpu quota, rather than nano cpus, Docker won't accept both being set
+    cpu_quota: bool,
+    pub form: Form<LimitsField>,
+    pub id: ContainerId,
+    /// The maximum retry count of an on-failure restart policy, kept as long as the policy is still on-failure
+    max_retry: Option<i64>,
+    pub name: String,
+}
+
+impl Limits {
+    pub fn new(id: ContainerId, name: String, host_config: Option<&HostConfig>) -> Self {
+        let host_config = host_config.cloned().unwrap_or_default();
+        let positive = |i: Option<i64>| i.filter(|i| *i > 0);
+        let cpu_quota = positive(host_config.cpu_quota).is_some();
+        let cpus = if cpu_quota {
+            positive(host_config.cpu_quota).map(|quota| {
+                quota * 1_000_000_000 / positive(host_config.cpu_period).unwrap_or(CPU_PERIOD)
+            })
+        } else {
+            positive(host_config.nano_cpus)
+        };
+        let max_retry = positive(
+            host_config
+                .restart_policy
+                .as_ref()
+                .and_then(|i| i.maximum_retry_count),
+        );
+        let restart = host_config
+            .restart_policy
+            .and_then(|i| i.name)
+            .map(|i| i.to_string())
+            .filter(|i| RESTART_POLICIES.contains(&i.as_str()))
+            .unwrap_or_else(|| RESTART_POLICIES[0].to_owned());
+
+        let values = LimitsField::ALL
+            .iter()
+            .map(|field| {
+                let value = match field {
+                    LimitsField::Memory => positive(host_config.memory).map(memory_value),
+                    LimitsField::Swap => host_config.memory_swap.filter(|i| *i != 0).map(|i| {
+                        if i < 0 {
+                            "-1".to_owned()
+                        } else {
+                            memory_value(i)
+                        }
+                    }),
+                    LimitsField::Cpus => cpus.map(cpus_value),
+                    LimitsField::CpuShares => {
+                        positive(host_config.cpu_shares).map(|i| i.to_string())
+                    }
+                    LimitsField::Cpuset => host_config.cpuset_cpus.clone(),
+                    LimitsField::Pids => positive(host_config.pids_limit).map(|i| i.to_string()),
+                    LimitsField::Restart => Some(restart.clone()),
+                };
+                (*field, value.unwrap_or_default())
+            })
+            .collect();
+
+        Self {
+            cpu_quota,
+            form: Form::new(values, LimitsField::Memory),
+            id,
+            max_retry,
+            name,
+        }
+    }
+
+    /// Choose the next, or previous, restart policy, wrapping around at each end
+    pub fn choose(&mut self, forward: bool) {
+        if self.form.selected.is_choice() {
+            self.form.choose(&RESTART_POLICIES, forward);
+        }
+    }
+
+    /// Parse every field, any errors are stored, to be shown next to the field, and None is returned
+    pub fn validate(&mut self) -> Option<LimitsSpec> {
+        let cpu_quota = self.cpu_quota;
+        let max_retry = self.max_retry;
+        self.form.validate(|form, errors| {
+            let mut parse = |field: LimitsField, parse: fn(&str) -> Result<_, String>| {
+                check(errors, field, form.optional(field).map(parse).transpose())
+            };
+            let memory = parse(LimitsField::Memory, parse_memory);
+            let memory_swap = parse(LimitsField::Swap, parse_swap);
+            let nano_cpus = parse(LimitsField::Cpus, parse_cpus);
+            let cpu_shares = parse(LimitsField::CpuShares, parse_shares);
+            let pids_limit = parse(LimitsField::Pids, parse_pids);
+            let cpuset = check(
+                errors,
+                LimitsField::Cpuset,
+                form.optional(LimitsField::Cpuset)
+                    .map(parse_cpuset)
+                    .transpose(),
+            );
+
+            if let (Some(Some(memory)), Some(Some(swap))) = (memory, memory_swap)
+                && swap != -1
+                && swap < memory
+            {
+                errors.push((
+                    LimitsField::Swap,
+                    "must be at least the memory limit".to_owned(),
+                ));
+            }
+
+            Some(LimitsSpec {
+                cpu_quota,
+                cpu_shares: cpu_shares?,
+                cpuset: cpuset?,
+                max_retry: max_retry.filter(|_| form.value(LimitsField::Restart) == "on-failure"),
+                memory: memory?,
+                memory_swap: memory_swap?,
+                nano_cpus: nano_cpus?,
+                pids_limit: pids_limit?,
+                restart: form.value(LimitsField::Restart).to_owned(),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::models::{HostConfig, RestartPolicy, RestartPolicyNameEnum};
+
+    use super::{Limits, LimitsField, parse_cpuset};
+    use crate::app_data::ContainerId;
+
+    fn gen_limits(host_config: &HostConfig) -> Limits {
+        Limits::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            Some(host_config),
+        )
+    }
+
+    fn type_into(form: &mut Limits, field: LimitsField, value: &str) {
+        form.form.selected = field;
+        while !form.form.value(field).is_empty() {
+            form.form.pop();
+        }
+        for c in value.chars() {
+            form.form.push(c);
+        }
+    }
+
+    #[test]
+    /// The form is prefilled with the current limits, a cpu quota is shown as a number of cpus
+    fn test_limits_prefill() {
+        let form = gen_limits(&HostConfig {
+            memory: Some(512 * 1024 * 1024),
+            memory_swap: Some(-1),
+            cpu_quota: Some(150_000),
+            cpu_period: Some(100_000),
+            cpu_shares: Some(0),
+            cpuset_cpus: Some("0-1".to_owned()),
+            pids_limit: Some(100),
+            restart_policy: Some(RestartPolicy {
+                name: Some(RestartPolicyNameEnum::UNLESS_STOPPED),
+                maximum_retry_count: None,
+            }),
+            ..Default::default()
+        });
+        assert_eq!(form.form.value(LimitsField::Memory), "512m");
+        assert_eq!(form.form.value(LimitsField::Swap), "-1");
+        assert_eq!(form.form.value(LimitsField::Cpus), "1.5");
+        assert_eq!(form.form.value(LimitsField::CpuShares), "");
+        assert_eq!(form.form.value(LimitsField::Cpuset), "0-1");
+        assert_eq!(form.form.value(LimitsField::Pids), "100");
+        assert_eq!(form.form.value(LimitsField::Restart), "unless-stopped");
+
+        let form = gen_limits(&HostConfig::default());
+        assert_eq!(form.form.value(LimitsField::Memory), "");
+        assert_eq!(form.form.value(LimitsField::Restart), "no");
+    }
+
+    #[test]
+    /// The maximum retry count of an on-failure restart policy is kept, unless the policy is changed
+    fn test_limits_max_retry() {
+        let mut form = gen_limits(&HostConfig {
+            restart_policy: Some(RestartPolicy {
+                name: Some(RestartPolicyNameEnum::ON_FAILURE),
+                maximum_retry_count: Some(5),
+            }),
+            ..Default::default()
+        });
+        assert_eq!(form.validate().unwrap().max_retry, Some(5));
+
+        form.form.selected = LimitsField::Restart;
+        form.choose(true);
+        let spec = form.validate().unwrap();
+        assert_ne!(spec.restart, "on-failure");
+        assert_eq!(spec.max_retry, None);
+    }
+
+    #[test]
+    /// A valid form gives a spec, empty fields are left unchanged, and the cpu quota is kept if the container already uses one
+    fn test_limits_validate() {
+        let mut form = gen_limits(&HostConfig {
+            cpu_quota: Some(50_000),
+            ..Default::default()
+        });
+        type_into(&mut form, LimitsField::Memory, "1g");
+        type_into(&mut form, LimitsField::Swap, "2g");
+        type_into(&mut form, LimitsField::Pids, "0");
+        form.form.selected = LimitsField::Restart;
+        form.choose(true);
+        let spec = form.validate().unwrap();
+        assert_eq!(spec.memory, Some(1024 * 1024 * 1024));
+        assert_eq!(spec.memory_swap, Some(2 * 1024 * 1024 * 1024));
+        assert_eq!(spec.nano_cpus, Some(500_000_000));
+        assert!(spec.cpu_quota);
+        assert_eq!(spec.cpu_shares, None);
+        assert_eq!(spec.cpuset, None);
+        assert_eq!(spec.pids_limit, Some(-1));
+        assert_eq!(spec.restart, "always");
+    }
+
+    #[test]
+    /// Every invalid field has an error, and the swap limit can't be less than the memory limit
+    fn test_limits_validate_errors() {
+        let mut form = gen_limits(&HostConfig::default());
+        type_into(&mut form, LimitsField::Memory, "1g");
+        type_into(&mut form, LimitsField::Swap, "512m");
+        type_into(&mut form, LimitsField::Cpus, "none");
+        type_into(&mut form, LimitsField::CpuShares, "1");
+        type_into(&mut form, LimitsField::Pids, "-5");
+        assert!(form.validate().is_none());
+        assert_eq!(
+            form.form.error(LimitsField::Swap),
+            Some("must be at least the memory limit")
+        );
+        assert!(form.form.error(LimitsField::Memory).is_none());
+        assert!(form.form.error(LimitsField::Cpus).is_some());
+        assert!(form.form.error(LimitsField::CpuShares).is_some());
+        assert!(form.form.error(LimitsField::Pids).is_some());
+
+        type_into(&mut form, LimitsField::Swap, "-1");
+        type_into(&mut form, LimitsField::Cpus, "");
+        type_into(&mut form, LimitsField::CpuShares, "");
+        type_into(&mut form, LimitsField::Pids, "");
+        assert!(form.validate().is_some());
+        assert!(form.form.errors.is_empty());
+    }
+
+    #[test]
+    /// Cpusets can be single cpus, or ranges
+    fn test_limits_parse_cpuset() {
+        assert_eq!(parse_cpuset("0-3, 6").unwrap(), "0-3,6");
+        assert!(parse_cpuset("3-0").is_err());
+        assert!(parse_cpuset("a").is_err());
+        assert!(parse_cpuset("1,").is_err());
+    }
+}
@@ -0,0 +1,765 @@
+use std::{
+    fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use jiff::Timestamp;
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span, Text},
+};
+use serde_json::{Map, Value};
+
+use super::{ScrollDirection, StatefulList};
+use crate::config::AppColors;
+
+/// The max width a JSON column is padded to, so that a single long value doesn't push every other column off screen
+const MAX_COLUMN_WIDTH: usize = 40;
+
+/// Cut a column value down to the max column width, ending with an ellipsis if it was cut
+fn ellipsize(value: &str) -> String {
+    if value.chars().count() > MAX_COLUMN_WIDTH {
+        let mut output = value.chars().take(MAX_COLUMN_WIDTH - 1).collect::<String>();
+        output.push('…');
+        output
+    } else {
+        value.to_owned()
+    }
+}
+
+/// The keys of a JSON log line, or logfmt line, that hold the level
+const LEVEL_KEYS: [&str; 3] = ["level", "lvl", "severity"];
+
+/// The starts of a line that continue the entry before it, as well as any indented line, i.e. the parts of a stack trace
+const CONTINUATION_PREFIXES: [&str; 2] = ["Caused by:", "Traceback"];
+
+/// Plain text level markers are only searched for in the first few words of a line, to avoid matching the message
+const LEVEL_WORDS: usize = 4;
+
+/// The level of a log line, in order of severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Trace => "trace",
+            Self::Debug => "debug",
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl LogLevel {
+    pub const ALL: [Self; 5] = [
+        Self::Trace,
+        Self::Debug,
+        Self::Info,
+        Self::Warn,
+        Self::Error,
+    ];
+
+    /// Parse a level name, e.g. the value of a `level` field, case insensitive
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_uppercase().as_str() {
+            "ERROR" | "ERR" | "FATAL" | "CRITICAL" | "CRIT" | "PANIC" | "ALERT" | "EMERG" => {
+                Some(Self::Error)
+            }
+            "WARN" | "WARNING" => Some(Self::Warn),
+            "INFO" | "NOTICE" => Some(Self::Info),
+            "DEBUG" | "DBG" => Some(Self::Debug),
+            "TRACE" | "TRC" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+
+    /// A syslog priority, `<PRI>`, where the severity is the priority mod 8
+    fn from_syslog(content: &str) -> Option<Self> {
+        let priority = content.strip_prefix('<')?.split_once('>')?.0;
+        if priority.is_empty() || priority.len() > 3 {
+            return None;
+        }
+        match priority.parse::<u8>().ok()? % 8 {
+            0..=3 => Some(Self::Error),
+            4 => Some(Self::Warn),
+            5 | 6 => Some(Self::Info),
+            _ => Some(Self::Debug),
+        }
+    }
+
+    /// Detect the level of a plain text line, returns the level, and the marker to colour
+    /// Checks, in order, a syslog priority prefix, a logfmt `level=` pair, and then an uppercase level word, e.g. `ERROR` or `[WARN]`, near the start of the line
+    pub fn detect(content: &str) -> Option<(Self, String)> {
+        let content = content.trim_start();
+        if let Some(level) = Self::from_syslog(content) {
+            let marker = content.split_once('>').map(|(pri, _)| format!("{pri}>"))?;
+            return Some((level, marker));
+        }
+        let words = content.split_whitespace().collect::<Vec<_>>();
+        for word in &words {
+            if let Some((key, value)) = word.split_once('=')
+                && LEVEL_KEYS.contains(&key.to_ascii_lowercase().as_str())
+                && let Some(level) = Self::from_name(value.trim_matches(['"', '\'']))
+            {
+                return Some((level, (*word).to_owned()));
+            }
+        }
+        words.iter().take(LEVEL_WORDS).find_map(|word| {
+            let name = word.trim_matches(|c: char| !c.is_ascii_alphabetic());
+            if name.is_empty() || name.chars().any(|c| c.is_ascii_lowercase()) {
+                return None;
+            }
+            Self::from_name(name).map(|level| (level, name.to_owned()))
+        })
+    }
+
+    /// Detect the level of a JSON line, from the first level field
+    pub fn detect_json(fields: &JsonLog) -> Option<Self> {
+        LEVEL_KEYS
+            .iter()
+            .find_map(|key| fields.get(key))
+            .and_then(|value| {
+                Self::from_name(value).or_else(|| {
+                    // Some loggers, e.g. pino & bunyan, use numbers, 10 trace to 60 fatal
+                    match value.parse::<u8>().ok()? {
+                        0..=19 => Some(Self::Trace),
+                        20..=29 => Some(Self::Debug),
+                        30..=39 => Some(Self::Info),
+                        40..=49 => Some(Self::Warn),
+                        _ => Some(Self::Error),
+                    }
+                })
+            })
+    }
+
+    pub const fn color(self, colors: &AppColors) -> Color {
+        match self {
+            Self::Trace => colors.logs.trace,
+            Self::Debug => colors.logs.debug,
+            Self::Info => colors.logs.info,
+            Self::Warn => colors.logs.warn,
+            Self::Error => colors.logs.error,
+        }
+    }
+
+    /// The next minimum level filter, no filter, then each level from debug to error, and then back to no filter
+    pub const fn next_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Debug),
+            Some(Self::Trace | Self::Debug) => Some(Self::Info),
+            Some(Self::Info) => Some(Self::Warn),
+            Some(Self::Warn) => Some(Self::Error),
+            Some(Self::Error) => None,
+        }
+    }
+}
+
+/// The output stream a log line was written to
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogStream {
+    #[default]
+    Stdout,
+    Stderr,
+}
+
+impl fmt::Display for LogStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Stdout => "stdout",
+            Self::Stderr => "stderr",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl LogStream {
+    /// The next stream filter, both streams, then stdout only, then stderr only, and then back to both
+    pub const fn next_filter(filter: Option<Self>) -> Option<Self> {
+        match filter {
+            None => Some(Self::Stdout),
+            Some(Self::Stdout) => Some(Self::Stderr),
+            Some(Self::Stderr) => None,
+        }
+    }
+}
+
+/// The optional time column drawn before each log line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogTimeColumn {
+    /// The time of each line, relative to the selected line
+    Relative,
+    /// The time since the previous line, to help spot stalls
+    Delta,
+}
+
+impl fmt::Display for LogTimeColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Relative => "relative",
+            Self::Delta => "delta",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl LogTimeColumn {
+    /// The width of the column, including the trailing space
+    pub const WIDTH: usize = 9;
+
+    /// The next column, hidden, then relative, then delta, and then back to hidden
+    pub const fn next(column: Option<Self>) -> Option<Self> {
+        match column {
+            None => Some(Self::Relative),
+            Some(Self::Relative) => Some(Self::Delta),
+            Some(Self::Delta) => None,
+        }
+    }
+
+    /// Format the time between two lines as a short signed duration, e.g. `+0.250s`, `-5m07s`, `+2h03m`, or `+1d04h`
+    pub fn format(from: Timestamp, to: Timestamp) -> String {
+        let duration = to.duration_since(from);
+        let sign = if duration.is_negative() { '-' } else { '+' };
+        let duration = duration.abs();
+        let secs = duration.as_secs();
+        let value = match secs {
+            0..60 => format!("{secs}.{:03}s", duration.subsec_millis()),
+            60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+            3600..86400 => format!("{}h{:02}m", secs / 3600, secs % 3600 / 60),
+            _ => format!("{}d{:02}h", secs / 86400, secs % 86400 / 3600),
+        };
+        format!("{sign}{value}")
+    }
+}
+
+/// The fields of a JSON log line, sorted by key, with nested objects & arrays kept as compact JSON
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonLog(Vec<(String, String)>);
+
+impl JsonLog {
+    /// Parse the content of a log line, only a JSON object is a JSON log line
+    pub fn parse(content: &str) -> Option<Self> {
+        let content = content.trim();
+        if !content.starts_with('{') {
+            return None;
+        }
+        let map = serde_json::from_str::<Map<String, Value>>(content).ok()?;
+        let mut fields = map
+            .into_iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    Value::String(s) => s,
+                    value => value.to_string(),
+                };
+                (key, value.replace(['\n', '\r'], " "))
+            })
+            .collect::<Vec<_>>();
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        Some(Self(fields))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.0
+    }
+
+    /// Check that every filter matches a field of the line
+    pub fn matches(&self, filters: &[FieldFilter], case_sensitive: bool) -> bool {
+        filters.iter().all(|filter| {
+            self.get(&filter.key).is_some_and(|value| {
+                if case_sensitive {
+                    value == filter.value
+                } else {
+                    value.to_lowercase() == filter.value.to_lowercase()
+                }
+            })
+        })
+    }
+}
+
+/// A `key=value` term of the log search, matched against the fields of JSON log lines, e.g. `level=error`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldFilter {
+    pub key: String,
+    pub value: String,
+}
+
+impl FieldFilter {
+    /// Parse a search term, only if every whitespace separated part of it is a `key=value` pair, otherwise it's a plain text search
+    pub fn parse_all(term: &str) -> Option<Vec<Self>> {
+        let filters = term
+            .split_whitespace()
+            .map(|part| {
+                part.split_once('=')
+                    .filter(|(key, _)| {
+                        !key.is_empty()
+                            && key
+                                .chars()
+                                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                    })
+                    .map(|(key, value)| Self {
+                        key: key.to_owned(),
+                        value: value.to_owned(),
+                    })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        (!filters.is_empty()).then_some(filters)
+    }
+}
+
+/// The configured columns, the optional timestamp, and every field, of a JSON log line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonLine {
+    pub columns: Vec<String>,
+    pub fields: JsonLog,
+    pub level_column: Option<usize>,
+    pub timestamp: Option<String>,
+}
+
+/// A single log line, with its level, if detected, a JSON line also keeps its fields, to be drawn as aligned columns, shown in the detail popup, & filtered by
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// The line continues the entry before it, such as an indented line of a stack trace
+    pub continuation: bool,
+    /// A hash of the content of the line, without the timestamp, used to find repeated lines
+    key: u64,
+    pub json: Option<JsonLine>,
+    pub level: Option<LogLevel>,
+    /// The char position & length of the level marker of a plain text line
+    marker: Option<(usize, usize)>,
+    /// The configured stderr marker, drawn before a stderr line
+    prefix: Option<String>,
+    pub stream: LogStream,
+    pub text: Text<'static>,
+    /// The Docker timestamp of the line, set when the line is inserted into the logs
+    pub timestamp: Option<Timestamp>,
+}
+
+impl From<Text<'static>> for LogLine {
+    fn from(text: Text<'static>) -> Self {
+        let content = text.to_string();
+        Self {
+            continuation: false,
+            key: 0,
+            json: None,
+            level: None,
+            marker: None,
+            prefix: None,
+            stream: LogStream::Stdout,
+            text,
+            timestamp: None,
+        }
+        .content(&content)
+    }
+}
+
+/// Colour a range of chars of a line, splitting the spans that the range starts, or ends, within
+fn color_range(line: &Line<'static>, start: usize, len: usize, color: Color) -> Line<'static> {
+    let end = start + len;
+    let mut position = 0;
+    let mut spans = vec![];
+    for span in &line.spans {
+        let chars = span.content.chars().collect::<Vec<_>>();
+        let span_start = position;
+        position += chars.len();
+        if position <= start || span_start >= end {
+            spans.push(span.clone());
+            continue;
+        }
+        let from = start.saturating_sub(span_start);
+        let to = (end - span_start).min(chars.len());
+        for (range, style) in [
+            (0..from, span.style),
+            (from..to, span.style.fg(color)),
+            (to..chars.len(), span.style),
+        ] {
+            if !range.is_empty() {
+                spans.push(Span::styled(chars[range].iter().collect::<String>(), style));
+            }
+        }
+    }
+    Line::from(spans)
+}
+
+impl LogLine {
+    /// A plain text line, the level marker is found in the formatted text, so that it can be coloured when drawn
+    pub fn plain(text: Text<'static>, level: Option<(LogLevel, String)>) -> Self {
+        let mut output = Self::from(text);
+        if let Some((level, marker)) = level {
+            let line = output.text.to_string();
+            output.marker = line
+                .find(&marker)
+                .map(|i| (line[..i].chars().count(), marker.chars().count()));
+            output.level = Some(level);
+        }
+        output
+    }
+
+    /// A JSON log line, the text, used for searching, is the timestamp & columns separated by a single space, missing fields are shown as `-`
+    pub fn json(timestamp: Option<String>, column_keys: &[String], fields: JsonLog) -> Self {
+        let columns = column_keys
+            .iter()
+            .map(|key| fields.get(key).unwrap_or("-").to_owned())
+            .collect::<Vec<_>>();
+        let text = Text::from(
+            timestamp
+                .iter()
+                .chain(columns.iter())
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(" "),
+        );
+        let output = Self {
+            continuation: false,
+            key: 0,
+            level: LogLevel::detect_json(&fields),
+            json: Some(JsonLine {
+                columns,
+                fields,
+                level_column: column_keys
+                    .iter()
+                    .position(|i| LEVEL_KEYS.contains(&i.as_str())),
+                timestamp,
+            }),
+            marker: None,
+            prefix: None,
+            stream: LogStream::Stdout,
+            text,
+            timestamp: None,
+        };
+        let content = output.text.to_string();
+        output.content(&content)
+    }
+
+    /// Set the content of the line, without the timestamp & ansi formatting, which is compared to find repeated lines, and continuation lines
+    pub fn content(mut self, content: &str) -> Self {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        self.key = hasher.finish();
+        self.continuation = (content.starts_with([' ', '\t']) && !content.trim().is_empty())
+            || CONTINUATION_PREFIXES.iter().any(|i| content.starts_with(i));
+        self
+    }
+
+    /// The line has the same content, ignoring the timestamp, and stream, as another line
+    pub fn repeats(&self, other: &Self) -> bool {
+        self.key == other.key && self.stream == other.stream
+    }
+
+    /// Mark the line as written to stderr, an empty marker isn't drawn
+    pub fn stderr(mut self, marker: &str) -> Self {
+        self.stream = LogStream::Stderr;
+        self.prefix = (!marker.is_empty()).then(|| format!("{marker} "));
+        self
+    }
+
+    /// The char width of each column of a JSON line, capped, used to align the columns of every JSON line
+    pub fn column_widths(&self) -> Vec<usize> {
+        self.json.as_ref().map_or_else(Vec::new, |json| {
+            json.columns
+                .iter()
+                .map(|i| i.chars().count().min(MAX_COLUMN_WIDTH))
+                .collect()
+        })
+    }
+
+    /// The line, a JSON line has each column, other than the last, cut to the max column width, and padded to the given widths, and a stderr line starts with the stderr marker
+    pub fn aligned(&self, widths: &[usize]) -> Text<'static> {
+        self.with_prefix(self.columns(widths), Style::default())
+    }
+
+    /// The line as drawn, without the stderr marker, used when copying lines
+    pub fn plain_text(&self, widths: &[usize]) -> String {
+        self.columns(widths).to_string()
+    }
+
+    /// Insert the stderr marker, if set, at the start of the line
+    fn with_prefix(&self, mut text: Text<'static>, style: Style) -> Text<'static> {
+        if let Some(prefix) = self.prefix.as_ref()
+            && let Some(line) = text.lines.first_mut()
+        {
+            line.spans.insert(0, Span::styled(prefix.clone(), style));
+        }
+        text
+    }
+
+    /// The line without the stderr marker
+    fn columns(&self, widths: &[usize]) -> Text<'static> {
+        self.json.as_ref().map_or_else(
+            || self.text.clone(),
+            |json| {
+                let last = json.columns.len().saturating_sub(1);
+                let mut spans = json
+                    .timestamp
+                    .iter()
+                    .map(|i| Span::from(format!("{i} ")))
+                    .collect::<Vec<_>>();
+                for (index, column) in json.columns.iter().enumerate() {
+                    let width = widths.get(index).copied().unwrap_or_default();
+                    spans.push(Span::from(if index == last {
+                        column.clone()
+                    } else {
+                        format!("{:<width$}  ", ellipsize(column))
+                    }));
+                }
+                Text::from(Line::from(spans))
+            },
+        )
+    }
+
+    /// The line to draw, aligned, with the level marker, or level column, in the colour of the level, and the stderr marker in the stderr colour
+    pub fn draw(&self, widths: &[usize], colors: &AppColors) -> Text<'static> {
+        let mut text = self.columns(widths);
+        let style = Style::default().fg(colors.logs.stderr);
+        let Some(color) = self.level.map(|i| i.color(colors)) else {
+            return self.with_prefix(text, style);
+        };
+        if let Some(json) = self.json.as_ref() {
+            if let Some(column) = json.level_column
+                && let Some(line) = text.lines.first_mut()
+                && let Some(span) = line
+                    .spans
+                    .get_mut(column + usize::from(json.timestamp.is_some()))
+            {
+                span.style = span.style.patch(Style::default().fg(color));
+            }
+        } else if let Some((start, len)) = self.marker
+            && let Some(line) = text.lines.first_mut()
+        {
+            *line = color_range(line, start, len, color);
+        }
+        self.with_prefix(text, style)
+    }
+}
+
+/// Every field of the selected JSON log line, shown in a popup
+#[derive(Debug, Clone)]
+pub struct LogDetail {
+    pub fields: StatefulList<(String, String)>,
+    pub name: String,
+}
+
+impl LogDetail {
+    pub fn new(name: String, fields: &JsonLog) -> Self {
+        let mut fields = StatefulList::new(fields.fields().to_vec());
+        fields.start();
+        Self { fields, name }
+    }
+
+    pub fn scroll(&mut self, sd: &ScrollDirection) {
+        self.fields.scroll(sd);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use jiff::Timestamp;
+    use ratatui::{style::Color, text::Text};
+
+    use super::{FieldFilter, JsonLog, LogLevel, LogLine, LogStream, LogTimeColumn};
+    use crate::config::AppColors;
+
+    #[test]
+    /// Only JSON objects are parsed, fields are sorted, and non string values are kept as JSON
+    fn test_log_line_json_parse() {
+        assert!(JsonLog::parse("plain text line").is_none());
+        assert!(JsonLog::parse("[1, 2, 3]").is_none());
+        assert!(JsonLog::parse("{not json").is_none());
+
+        let result =
+            JsonLog::parse(r#" {"msg":"hello\nworld","level":"info","user":{"id":42},"ok":true}"#)
+                .unwrap();
+        assert_eq!(
+            result.fields(),
+            [
+                ("level".to_owned(), "info".to_owned()),
+                ("msg".to_owned(), "hello world".to_owned()),
+                ("ok".to_owned(), "true".to_owned()),
+                ("user".to_owned(), r#"{"id":42}"#.to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    /// A search term is only a field filter when every part is a key=value pair
+    fn test_log_line_field_filter() {
+        assert!(FieldFilter::parse_all("error").is_none());
+        assert!(FieldFilter::parse_all("level=error timeout").is_none());
+        assert!(FieldFilter::parse_all("=error").is_none());
+        assert!(FieldFilter::parse_all("").is_none());
+        let filters = FieldFilter::parse_all("level=error user_id=42").unwrap();
+        assert_eq!(filters.len(), 2);
+
+        let log = JsonLog::parse(r#"{"level":"ERROR","user_id":42}"#).unwrap();
+        assert!(log.matches(&filters, false));
+        assert!(!log.matches(&filters, true));
+        let log = JsonLog::parse(r#"{"level":"error"}"#).unwrap();
+        assert!(!log.matches(&filters, false));
+    }
+
+    #[test]
+    /// JSON lines are drawn as columns, padded to the given widths, missing fields are shown as -
+    fn test_log_line_json_aligned() {
+        let columns = ["level", "msg", "caller"].map(str::to_owned);
+        let log = JsonLog::parse(r#"{"level":"info","msg":"started"}"#).unwrap();
+        let line = LogLine::json(Some("12:00:00".to_owned()), &columns, log);
+        assert_eq!(line.text, Text::from("12:00:00 info started -"));
+        assert_eq!(line.column_widths(), [4, 7, 1]);
+        assert_eq!(
+            line.aligned(&[5, 10, 1]).to_string(),
+            "12:00:00 info   started     -"
+        );
+
+        let long = "a".repeat(50);
+        let log = JsonLog::parse(&format!(r#"{{"level":"{long}","msg":"started"}}"#)).unwrap();
+        let line = LogLine::json(None, &columns, log);
+        assert_eq!(line.column_widths(), [40, 7, 1]);
+        assert_eq!(
+            line.aligned(&[40, 7, 1]).to_string(),
+            format!("{}…  started  -", "a".repeat(39))
+        );
+
+        let line = LogLine::from(Text::from("plain"));
+        assert!(line.column_widths().is_empty());
+        assert_eq!(line.aligned(&[5]), Text::from("plain"));
+    }
+
+    #[test]
+    /// Levels are detected from syslog priorities, logfmt pairs, and uppercase words near the start of a line
+    fn test_log_line_level_detect() {
+        let detect = |content: &str| LogLevel::detect(content).map(|(level, _)| level);
+        assert_eq!(
+            LogLevel::detect("<3>disk failure"),
+            Some((LogLevel::Error, "<3>".to_owned()))
+        );
+        assert_eq!(detect("<14>user login"), Some(LogLevel::Info));
+        assert_eq!(
+            LogLevel::detect(r#"ts=1 level=warn msg="slow query""#),
+            Some((LogLevel::Warn, "level=warn".to_owned()))
+        );
+        assert_eq!(
+            LogLevel::detect("2024/01/01 12:00:00 [ERROR] connection refused"),
+            Some((LogLevel::Error, "ERROR".to_owned()))
+        );
+        assert_eq!(detect("DEBUG cache miss"), Some(LogLevel::Debug));
+        assert_eq!(detect("   TRACE entering"), Some(LogLevel::Trace));
+        assert_eq!(detect("an error occurred"), None);
+        assert_eq!(detect("one two three four ERROR"), None);
+        assert_eq!(detect("<abc>not syslog"), None);
+
+        let json = JsonLog::parse(r#"{"severity":"WARNING"}"#).unwrap();
+        assert_eq!(LogLevel::detect_json(&json), Some(LogLevel::Warn));
+        let json = JsonLog::parse(r#"{"level":50,"msg":"failed"}"#).unwrap();
+        assert_eq!(LogLevel::detect_json(&json), Some(LogLevel::Error));
+        let json = JsonLog::parse(r#"{"msg":"ERROR"}"#).unwrap();
+        assert_eq!(LogLevel::detect_json(&json), None);
+
+        assert_eq!(LogLevel::next_filter(None), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::next_filter(Some(LogLevel::Error)), None);
+    }
+
+    #[test]
+    /// Only the level marker, or the JSON level column, is coloured
+    fn test_log_line_level_draw() {
+        let colors = AppColors::new();
+        let level = LogLevel::detect("12:00 WARN disk low");
+        let line = LogLine::plain(Text::from("12:00 WARN disk low"), level);
+        let text = line.draw(&[], &colors);
+        let spans = &text.lines[0].spans;
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].content, "12:00 ");
+        assert_eq!(spans[1].content, "WARN");
+        assert_eq!(spans[1].style.fg, Some(Color::Yellow));
+        assert_eq!(spans[2].content, " disk low");
+        assert_eq!(spans[2].style.fg, None);
+
+        let columns = ["msg", "level"].map(str::to_owned);
+        let log = JsonLog::parse(r#"{"level":"error","msg":"failed"}"#).unwrap();
+        let line = LogLine::json(Some("12:00".to_owned()), &columns, log);
+        let text = line.draw(&line.column_widths(), &colors);
+        let spans = &text.lines[0].spans;
+        assert_eq!(spans[2].content, "error");
+        assert_eq!(spans[2].style.fg, Some(Color::Red));
+        assert_eq!(spans[1].style.fg, None);
+    }
+
+    #[test]
+    /// A stderr line starts with the stderr marker, in the stderr colour, an empty marker isn't drawn
+    fn test_log_line_stderr() {
+        let colors = AppColors::new();
+        let level = LogLevel::detect("ERROR failed");
+        let line = LogLine::plain(Text::from("ERROR failed"), level).stderr("!");
+        assert_eq!(line.stream, LogStream::Stderr);
+        assert_eq!(line.aligned(&[]).to_string(), "! ERROR failed");
+        let text = line.draw(&[], &colors);
+        let spans = &text.lines[0].spans;
+        assert_eq!(spans[0].content, "! ");
+        assert_eq!(spans[0].style.fg, Some(Color::LightRed));
+        assert_eq!(spans[1].content, "ERROR");
+        assert_eq!(spans[1].style.fg, Some(Color::Red));
+
+        let line = LogLine::from(Text::from("plain")).stderr("");
+        assert_eq!(line.stream, LogStream::Stderr);
+        assert_eq!(line.draw(&[], &colors).to_string(), "plain");
+
+        assert_eq!(LogStream::next_filter(None), Some(LogStream::Stdout));
+        assert_eq!(
+            LogStream::next_filter(Some(LogStream::Stdout)),
+            Some(LogStream::Stderr)
+        );
+        assert_eq!(LogStream::next_filter(Some(LogStream::Stderr)), None);
+    }
+
+    #[test]
+    /// The time column is a short signed duration, with the largest two units
+    fn test_log_line_time_column_format() {
+        let time = |i: &str| i.parse::<Timestamp>().unwrap();
+        let from = time("2025-02-18T12:00:00Z");
+        let format = |to: &str| LogTimeColumn::format(from, time(to));
+        assert_eq!(format("2025-02-18T12:00:00.25Z"), "+0.250s");
+        assert_eq!(format("2025-02-18T11:59:48Z"), "-12.000s");
+        assert_eq!(format("2025-02-18T12:05:07Z"), "+5m07s");
+        assert_eq!(format("2025-02-18T09:57:00Z"), "-2h03m");
+        assert_eq!(format("2025-02-19T16:00:00Z"), "+1d04h");
+
+        assert_eq!(LogTimeColumn::next(None), Some(LogTimeColumn::Relative));
+        assert_eq!(
+            LogTimeColumn::next(Some(LogTimeColumn::Relative)),
+            Some(LogTimeColumn::Delta)
+        );
+        assert_eq!(LogTimeColumn::next(Some(LogTimeColumn::Delta)), None);
+    }
+
+    #[test]
+    /// Lines repeat when the content, and stream, match, indented lines, and the starts of stack traces, are continuation lines
+    fn test_log_line_content() {
+        let line =
+            |text: &str, content: &str| LogLine::from(Text::from(text.to_owned())).content(content);
+        let first = line("12:00 GET /health", "GET /health");
+        assert!(first.repeats(&line("12:01 GET /health", "GET /health")));
+        assert!(!first.repeats(&line("12:01 GET /", "GET /")));
+        assert!(!first.repeats(&line("12:01 GET /health", "GET /health").stderr("!")));
+
+        assert!(!first.continuation);
+        assert!(line("", "    at Main.run").continuation);
+        assert!(line("", "\tat Main.run").continuation);
+        assert!(line("", "Caused by: java.io.IOException").continuation);
+        assert!(line("", "Traceback (most recent call last):").continuation);
+        assert!(!line("", "   ").continuation);
+    }
+}
@@ -1,5 +1,7 @@
 use bollard::models::{ContainerInspectResponse, ContainerSummary};
+use cansi::v3::categorise_text;
 use core::fmt;
+use jiff::{Timestamp, tz::TimeZone};
 use parking_lot::Mutex;
 use ratatui::{layout::Size, text::Text, widgets::ListState};
 use std::{
@@ -8,7 +10,19 @@ use std::{
     time::{SystemTime, UNIX_EPOCH},
 };
 
+mod changes;
 mod container_state;
+mod file_browser;
+mod form;
+mod limits;
+mod log_line;
+mod networks;
+mod new_container;
+mod processes;
+mod prompt;
+mod prune;
+mod recreate;
+mod save_logs;
 
 use crate::{
     ENTRY_POINT,
@@ -16,7 +30,18 @@ use crate::{
     config::Config,
     ui::{GuiState, Rerender, Status, log_sanitizer},
 };
+pub use changes::*;
 pub use container_state::*;
+pub use file_browser::*;
+pub use limits::*;
+pub use log_line::*;
+pub use networks::*;
+pub use new_container::*;
+pub use processes::*;
+pub use prompt::*;
+pub use prune::*;
+pub use recreate::*;
+pub use save_logs::*;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SortedOrder {
@@ -153,16 +178,98 @@ impl From<ContainerInspectResponse> for InspectData {
     }
 }
 
+impl InspectData {
+    /// The key & value of a line of the inspect output, strings are unescaped, and an object, or array, value includes every line up to its closing bracket
+    pub fn value_at(&self, index: usize) -> Option<(String, String)> {
+        let mut lines = self.as_string.lines().skip(index);
+        let line = lines.next()?;
+        let indent = line.len() - line.trim_start().len();
+        let trimmed = line.trim().trim_end_matches(',');
+        let (key, value) = trimmed
+            .strip_prefix('"')
+            .and_then(|i| i.split_once("\": "))
+            .map_or((String::new(), trimmed), |(key, value)| {
+                (key.to_owned(), value)
+            });
+        let value = match value {
+            "{" | "[" => {
+                let close = if value == "{" { '}' } else { ']' };
+                let mut output = vec![value.to_owned()];
+                for line in lines {
+                    let dedented = line.get(indent..).unwrap_or(line);
+                    if dedented.starts_with(close) {
+                        output.push(dedented.trim_end_matches(',').to_owned());
+                        break;
+                    }
+                    output.push(dedented.to_owned());
+                }
+                output.join("\n")
+            }
+            _ => serde_json::from_str::<String>(value).unwrap_or_else(|_| value.to_owned()),
+        };
+        Some((key, value))
+    }
+}
+
+/// The result of running a single, non-interactive, command in a container
+#[derive(Debug, Clone)]
+pub struct CommandOutput {
+    pub command: String,
+    pub exit_code: Option<i64>,
+    pub height: usize,
+    pub name: String,
+    pub output: String,
+    pub width: usize,
+}
+
+impl CommandOutput {
+    /// Strip any ansi formatting from the output, and calculate the width & height
+    pub fn new(name: String, command: String, output: &str, exit_code: Option<i64>) -> Self {
+        let output = categorise_text(output)
+            .into_iter()
+            .map(|i| i.text)
+            .collect::<String>()
+            .replace('\r', "");
+        let width = output
+            .lines()
+            .map(|i| i.chars().count())
+            .max()
+            .unwrap_or_default();
+        Self {
+            command,
+            exit_code,
+            height: output.lines().count(),
+            name,
+            output,
+            width,
+        }
+    }
+}
+
 /// Global app_state, stored in an Arc<Mutex>
 #[derive(Debug, Clone)]
 #[cfg(not(test))]
 pub struct AppData {
+    command_output: Option<CommandOutput>,
+    container_changes: Option<ContainerChanges>,
     containers: StatefulList<ContainerItem>,
     error: Option<AppError>,
+    file_browser: Option<FileBrowser>,
     filter: Filter,
     hidden_containers: Vec<ContainerItem>,
     inspect_data: Option<InspectData>,
+    limits: Option<Limits>,
+    log_detail: Option<LogDetail>,
+    log_time_column: Option<LogTimeColumn>,
+    log_wrap: bool,
+    networks: Option<ContainerNetworks>,
+    new_container: Option<NewContainer>,
+    processes: Option<ContainerProcesses>,
+    prompt: Option<Prompt>,
+    prune: Option<Prune>,
+    recreate: Option<Recreate>,
     rerender: Arc<Rerender>,
+    save_logs: Option<SaveLogs>,
     sorted_by: Option<(Header, SortedOrder)>,
     current_sorted_id: Vec<ContainerId>,
     pub config: Config,
@@ -171,14 +278,28 @@ pub struct AppData {
 #[derive(Debug, Clone)]
 #[cfg(test)]
 pub struct AppData {
+    pub command_output: Option<CommandOutput>,
     pub config: Config,
+    pub container_changes: Option<ContainerChanges>,
     pub containers: StatefulList<ContainerItem>,
     pub error: Option<AppError>,
+    pub file_browser: Option<FileBrowser>,
     pub filter: Filter,
     pub hidden_containers: Vec<ContainerItem>,
     pub inspect_data: Option<InspectData>,
+    pub limits: Option<Limits>,
+    pub log_detail: Option<LogDetail>,
+    pub log_time_column: Option<LogTimeColumn>,
+    pub log_wrap: bool,
+    pub networks: Option<ContainerNetworks>,
+    pub new_container: Option<NewContainer>,
+    pub processes: Option<ContainerProcesses>,
+    pub prompt: Option<Prompt>,
+    pub prune: Option<Prune>,
+    pub recreate: Option<Recreate>,
     pub current_sorted_id: Vec<ContainerId>,
     pub rerender: Arc<Rerender>,
+    pub save_logs: Option<SaveLogs>,
     pub sorted_by: Option<(Header, SortedOrder)>,
 }
 
@@ -186,14 +307,28 @@ impl AppData {
     /// Generate a default app_state
     pub fn new(config: Config, redraw: &Arc<Rerender>) -> Self {
         Self {
+            command_output: None,
             config,
+            container_changes: None,
             containers: StatefulList::new(vec![]),
             current_sorted_id: vec![],
             error: None,
+            file_browser: None,
             filter: Filter::new(),
             hidden_containers: vec![],
             inspect_data: None,
+            limits: None,
+            log_detail: None,
+            log_time_column: None,
+            log_wrap: false,
+            networks: None,
+            new_container: None,
+            processes: None,
+            prompt: None,
+            prune: None,
+            recreate: None,
             rerender: Arc::clone(redraw),
+            save_logs: None,
             sorted_by: None,
         }
     }
@@ -218,6 +353,348 @@ impl AppData {
     pub fn get_inspect_data(&self) -> Option<InspectData> {
         self.inspect_data.clone()
     }
+
+    pub fn clear_command_output(&mut self) {
+        self.command_output = None;
+    }
+
+    pub fn set_command_output(&mut self, output: CommandOutput) {
+        self.command_output = Some(output);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_command_output(&self) -> Option<CommandOutput> {
+        self.command_output.clone()
+    }
+
+    /// Open the detail popup for the selected log line, only if it's a JSON line, returns true if opened
+    pub fn set_log_detail(&mut self) -> bool {
+        let detail = self.get_selected_container().and_then(|i| {
+            i.logs
+                .selected_json()
+                .map(|json| LogDetail::new(i.name.get().to_owned(), json))
+        });
+        let opened = detail.is_some();
+        self.log_detail = detail;
+        self.rerender.update_draw();
+        opened
+    }
+
+    pub fn get_log_detail(&self) -> Option<LogDetail> {
+        self.log_detail.clone()
+    }
+
+    pub fn clear_log_detail(&mut self) {
+        self.log_detail = None;
+    }
+
+    /// Apply a change, such as scrolling, to the log detail popup
+    pub fn update_log_detail(&mut self, f: impl FnOnce(&mut LogDetail)) {
+        if let Some(detail) = self.log_detail.as_mut() {
+            f(detail);
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn set_container_changes(&mut self, changes: ContainerChanges) {
+        self.container_changes = Some(changes);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_container_changes(&self) -> Option<ContainerChanges> {
+        self.container_changes.clone()
+    }
+
+    pub fn clear_container_changes(&mut self) {
+        self.container_changes = None;
+    }
+
+    /// Apply a change, such as scrolling, collapsing, or filtering, to the container changes tree
+    pub fn update_container_changes(&mut self, f: impl FnOnce(&mut ContainerChanges)) {
+        if let Some(changes) = self.container_changes.as_mut() {
+            f(changes);
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn set_processes(&mut self, processes: ContainerProcesses) {
+        self.processes = Some(processes);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_processes(&self) -> Option<ContainerProcesses> {
+        self.processes.clone()
+    }
+
+    /// Get the id of the container whose processes are being shown, used to refresh the process list
+    pub fn get_processes_id(&self) -> Option<ContainerId> {
+        self.processes.as_ref().map(|i| i.id.clone())
+    }
+
+    pub fn clear_processes(&mut self) {
+        self.processes = None;
+    }
+
+    /// Apply a change to the process list, if it is open for the given container, or for any container if id is None
+    pub fn update_processes(
+        &mut self,
+        id: Option<&ContainerId>,
+        f: impl FnOnce(&mut ContainerProcesses),
+    ) {
+        if let Some(processes) = self.processes.as_mut()
+            && id.is_none_or(|id| id == &processes.id)
+        {
+            f(processes);
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = Some(limits);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_limits(&self) -> Option<Limits> {
+        self.limits.clone()
+    }
+
+    pub fn clear_limits(&mut self) {
+        self.limits = None;
+    }
+
+    /// Apply a change, such as typing into a field, to the limits form
+    pub fn update_limits<T>(&mut self, f: impl FnOnce(&mut Limits) -> T) -> Option<T> {
+        let output = self.limits.as_mut().map(f);
+        self.rerender.update_draw();
+        output
+    }
+
+    pub fn set_networks(&mut self, networks: ContainerNetworks) {
+        self.networks = Some(networks);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_networks(&self) -> Option<ContainerNetworks> {
+        self.networks.clone()
+    }
+
+    pub fn clear_networks(&mut self) {
+        self.networks = None;
+    }
+
+    /// Apply a change, such as scrolling, or typing into the connect form, to the networks popup
+    pub fn update_networks<T>(&mut self, f: impl FnOnce(&mut ContainerNetworks) -> T) -> Option<T> {
+        let output = self.networks.as_mut().map(f);
+        self.rerender.update_draw();
+        output
+    }
+
+    pub fn set_new_container(&mut self, new_container: NewContainer) {
+        self.new_container = Some(new_container);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_new_container(&self) -> Option<NewContainer> {
+        self.new_container.clone()
+    }
+
+    pub fn clear_new_container(&mut self) {
+        self.new_container = None;
+    }
+
+    /// Apply a change, such as typing into a field, or setting the image list, to the new container form
+    pub fn update_new_container<T>(&mut self, f: impl FnOnce(&mut NewContainer) -> T) -> Option<T> {
+        let output = self.new_container.as_mut().map(f);
+        self.rerender.update_draw();
+        output
+    }
+
+    pub fn set_prompt(&mut self, prompt: Prompt) {
+        self.prompt = Some(prompt);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_prompt(&self) -> Option<Prompt> {
+        self.prompt.clone()
+    }
+
+    pub fn clear_prompt(&mut self) {
+        self.prompt = None;
+    }
+
+    /// Apply a change, such as typing into a field, to the rename or commit prompt
+    pub fn update_prompt<T>(&mut self, f: impl FnOnce(&mut Prompt) -> T) -> Option<T> {
+        let output = self.prompt.as_mut().map(f);
+        self.rerender.update_draw();
+        output
+    }
+
+    pub fn set_prune(&mut self, prune: Prune) {
+        self.prune = Some(prune);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_prune(&self) -> Option<Prune> {
+        self.prune.clone()
+    }
+
+    pub fn clear_prune(&mut self) {
+        self.prune = None;
+    }
+
+    /// Apply a change, such as scrolling, or setting the preview, to the prune menu
+    pub fn update_prune(&mut self, f: impl FnOnce(&mut Prune)) {
+        if let Some(prune) = self.prune.as_mut() {
+            f(prune);
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn set_recreate(&mut self, recreate: Recreate) {
+        self.recreate = Some(recreate);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_recreate(&self) -> Option<Recreate> {
+        self.recreate.clone()
+    }
+
+    pub fn clear_recreate(&mut self) {
+        self.recreate = None;
+        self.rerender.update_draw();
+    }
+
+    /// Apply a change, such as the pull progress, or the current stage, to the recreate in progress
+    pub fn update_recreate(&mut self, f: impl FnOnce(&mut Recreate)) {
+        if let Some(recreate) = self.recreate.as_mut() {
+            f(recreate);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Open the save logs popup for the selected container, returns false if no container is selected
+    pub fn set_save_logs(&mut self) -> bool {
+        let Some(name) = self
+            .get_selected_container()
+            .map(|i| i.name.get().to_owned())
+        else {
+            return false;
+        };
+        self.save_logs = Some(SaveLogs::new(name, self.containers.items.len()));
+        self.rerender.update_draw();
+        true
+    }
+
+    pub fn get_save_logs(&self) -> Option<SaveLogs> {
+        self.save_logs.clone()
+    }
+
+    pub fn clear_save_logs(&mut self) {
+        self.save_logs = None;
+        self.rerender.update_draw();
+    }
+
+    /// Apply a change, such as scrolling, or cycling the value of a row, to the save logs popup
+    pub fn update_save_logs(&mut self, f: impl FnOnce(&mut SaveLogs)) {
+        if let Some(save_logs) = self.save_logs.as_mut() {
+            f(save_logs);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// The containers, and the lines of each, to save with the chosen options, either the selected container, or every container in the current filter
+    pub fn get_save_logs_spec(&self) -> Option<SaveLogsSpec> {
+        let save_logs = self.save_logs.as_ref()?;
+        let to_target = |i: &ContainerItem| SaveTarget {
+            id: i.id.clone(),
+            name: i.name.get().to_owned(),
+            lines: i.logs.save_lines(save_logs.scope),
+        };
+        let targets = if save_logs.all {
+            self.containers.items.iter().map(to_target).collect()
+        } else {
+            vec![to_target(self.get_selected_container()?)]
+        };
+        Some(SaveLogsSpec {
+            dir: save_logs.all,
+            format: save_logs.format,
+            gzip: save_logs.gzip,
+            targets,
+        })
+    }
+
+    /// Open the file browser for the given container, the entries are populated once docker has listed the root directory
+    pub fn set_file_browser(&mut self, file_browser: FileBrowser) {
+        self.file_browser = Some(file_browser);
+        self.rerender.update_draw();
+    }
+
+    pub fn get_file_browser(&self) -> Option<FileBrowser> {
+        self.file_browser.clone()
+    }
+
+    pub fn clear_file_browser(&mut self) {
+        self.file_browser = None;
+    }
+
+    /// Apply a change to the file browser, if it is open for the given container
+    /// Used so that slow docker responses don't get applied to a browser that has since been closed or changed
+    pub fn update_file_browser(&mut self, id: &ContainerId, f: impl FnOnce(&mut FileBrowser)) {
+        if let Some(file_browser) = self.file_browser.as_mut()
+            && &file_browser.id == id
+        {
+            f(file_browser);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Change the selected entry in the file browser
+    pub fn file_browser_scroll(&mut self, sd: &ScrollDirection) {
+        if let Some(file_browser) = self.file_browser.as_mut() {
+            file_browser.entries.scroll(sd);
+            file_browser.preview = None;
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn file_browser_start(&mut self) {
+        if let Some(file_browser) = self.file_browser.as_mut() {
+            file_browser.entries.start();
+            file_browser.preview = None;
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Add a char to the local path of the file to upload
+    pub fn file_browser_upload_push(&mut self, c: char) {
+        if let Some(file_browser) = self.file_browser.as_mut() {
+            file_browser.upload.push(c);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Remove the final char from the local path of the file to upload
+    pub fn file_browser_upload_pop(&mut self) {
+        if let Some(file_browser) = self.file_browser.as_mut() {
+            file_browser.upload.pop();
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn file_browser_upload_clear(&mut self) {
+        if let Some(file_browser) = self.file_browser.as_mut() {
+            file_browser.upload.clear();
+            self.rerender.update_draw();
+        }
+    }
+
+    pub fn file_browser_end(&mut self) {
+        if let Some(file_browser) = self.file_browser.as_mut() {
+            file_browser.entries.end();
+            file_browser.preview = None;
+            self.rerender.update_draw();
+        }
+    }
     /// Filter related methods
     /// Get the filterby and filter_term
     pub const fn get_filter(&self) -> (FilterBy, Option<&String>) {
@@ -596,7 +1073,7 @@ impl AppData {
     pub fn get_selected_ports(&self) -> Option<(Vec<ContainerPorts>, State)> {
         if let Some(item) = self.get_selected_container() {
             let mut ports = item.ports.clone();
-            ports.sort_by(|a, b| a.private.cmp(&b.private));
+            ports.sort_by_key(|a| a.private);
             return Some((ports, item.state));
         }
         None
@@ -710,7 +1187,10 @@ impl AppData {
     /// 1) "logs x/x - container_name - container_image"
     /// 2) "logs - container_name - container_image" when no logs found
     /// 3) " " no container currently selected - aka no containers on system
+    ///
+    /// Followed by the count of each detected level, the minimum level filter, the stream filter, if following is paused, the collapse & group options, the time range, the time column, and the number of selected lines, if set
     pub fn get_log_title(&self) -> String {
+        let tz = self.config.timezone.clone().unwrap_or(TimeZone::UTC);
         self.get_selected_container()
             .map_or_else(String::new, |ci| {
                 let logs_len = ci.logs.get_state_title();
@@ -719,17 +1199,142 @@ impl AppData {
                 } else {
                     format!("{logs_len} ")
                 };
-                format!("{}- {} - {}", prefix, ci.name.get(), ci.image.get())
+                let counts = ci
+                    .logs
+                    .level_counts()
+                    .into_iter()
+                    .map(|(level, count)| format!("{level} {count}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let mut levels = String::new();
+                if !counts.is_empty() {
+                    levels.push_str(&format!(" - {counts}"));
+                }
+                if let Some(level) = ci.logs.min_level() {
+                    levels.push_str(&format!(" - level ≥ {level}"));
+                }
+                if let Some(stream) = ci.logs.stream() {
+                    levels.push_str(&format!(" - {stream} only"));
+                }
+                if !ci.logs.follow() {
+                    levels.push_str(" - paused");
+                }
+                if ci.logs.collapse() {
+                    levels.push_str(" - repeats collapsed");
+                }
+                if ci.logs.group() {
+                    levels.push_str(" - traces grouped");
+                }
+                let (since, until) = ci.logs.time_range();
+                if let Some(since) = since {
+                    levels.push_str(&format!(" - since {}", format_time(since, &tz)));
+                }
+                if let Some(until) = until {
+                    levels.push_str(&format!(" - until {}", format_time(until, &tz)));
+                }
+                if let Some(column) = self.log_time_column {
+                    levels.push_str(&format!(" - {column} time"));
+                }
+                if ci.logs.is_marked() {
+                    levels.push_str(&format!(" - {} selected", ci.logs.marked_count()));
+                }
+                format!("{}- {} - {}{levels}", prefix, ci.name.get(), ci.image.get())
             })
     }
 
     /// If scrolling horizontally along the logs, display a counter of the position in the in the scroll, `x/y`
+    /// Lines can't be scrolled horizontally when they're wrapped
     pub fn get_scroll_title(&mut self, width: u16) -> Option<String> {
+        if self.log_wrap {
+            return None;
+        }
         self.get_mut_selected_container()
             .and_then(|i| i.logs.get_scroll_title(width))
     }
 
+    /// Start, or cancel, a selection of log lines, from the selected line
+    pub fn log_mark_toggle(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.toggle_mark();
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Cancel the selection of log lines
+    pub fn log_mark_clear(&mut self) {
+        if let Some(i) = self.get_mut_selected_container()
+            && i.logs.is_marked()
+        {
+            i.logs.clear_mark();
+            self.rerender.update_draw();
+        }
+    }
+
+    /// The selected log lines, or just the selected line if there's no selection, the selection is cleared
+    pub fn take_log_yank(&mut self) -> Vec<String> {
+        let output = self
+            .get_mut_selected_container()
+            .map(|i| {
+                let text = i.logs.marked_text();
+                i.logs.clear_mark();
+                text
+            })
+            .unwrap_or_default();
+        self.rerender.update_draw();
+        output
+    }
+
+    /// Toggle wrapping long log lines to the width of the logs panel
+    pub fn log_wrap_toggle(&mut self) {
+        self.log_wrap = !self.log_wrap;
+        self.rerender.update_draw();
+    }
+
+    /// Cycle the time column of the logs panel, hidden, relative to the selected line, and delta from the previous line
+    pub fn log_time_column_cycle(&mut self) {
+        self.log_time_column = LogTimeColumn::next(self.log_time_column);
+        self.rerender.update_draw();
+    }
+
+    /// Open the log time prompt for the selected container, prefilled with the current time range of its logs
+    pub fn set_log_time_prompt(&mut self) -> bool {
+        let tz = self.config.timezone.clone();
+        let Some(prompt) = self.get_selected_container().map(|i| {
+            Prompt::log_time(
+                i.id.clone(),
+                i.name.get().to_owned(),
+                i.logs.time_range(),
+                tz,
+            )
+        }) else {
+            return false;
+        };
+        self.set_prompt(prompt);
+        true
+    }
+
+    /// Limit a given container's logs to the time range of the log time prompt, jump to the time, if given, and close the prompt
+    /// Returns the earliest of the since, or jump, times, if lines from then haven't been fetched yet
+    pub fn log_time_apply(&mut self, id: &ContainerId, spec: LogTimeSpec) -> Option<Timestamp> {
+        let cs = self.config.log_search_case_sensitive;
+        self.prompt = None;
+        self.rerender.update_draw();
+        let container = self.get_any_container_by_id(id)?;
+        container.logs.set_time_range(spec.since, spec.until, cs);
+        if let Some(jump) = spec.jump {
+            container.logs.jump_to(jump);
+        }
+        [spec.since, spec.jump]
+            .into_iter()
+            .flatten()
+            .min()
+            .filter(|i| container.logs.needs_from(*i))
+    }
+
     pub fn logs_horizontal_scroll(&mut self, sd: &ScrollDirection, width: u16) {
+        if self.log_wrap {
+            return;
+        }
         // Change this to set a max_offset, instead of taking in width each time, then can be combined with the log_scroll beneath
         match sd {
             ScrollDirection::Down => {
@@ -778,13 +1383,90 @@ impl AppData {
         }
     }
 
+    /// Cycle the minimum level of the log lines shown for the selected container
+    pub fn log_level_filter_cycle(&mut self) {
+        let cs = self.config.log_search_case_sensitive;
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.cycle_level_filter(cs);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Pause, or resume, following the new log lines of the selected container
+    pub fn log_follow_toggle(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.toggle_follow();
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Select the last log line of the selected container, and follow new lines
+    pub fn log_live(&mut self) {
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.live();
+            self.rerender.update_draw();
+        }
+    }
+
+    /// The number of new log lines, of the selected container, below the selected line, since following was paused
+    pub fn get_log_unread(&self) -> usize {
+        self.get_selected_container().map_or(0, |i| i.logs.unread())
+    }
+
+    /// Toggle collapsing consecutive repeated log lines of the selected container
+    pub fn log_collapse_toggle(&mut self) {
+        let cs = self.config.log_search_case_sensitive;
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.toggle_collapse(cs);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Toggle grouping the continuation lines, such as stack traces, of the selected container's logs
+    pub fn log_group_toggle(&mut self) {
+        let cs = self.config.log_search_case_sensitive;
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.toggle_group(cs);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Expand, or collapse, the group of the selected log line, returns false if the line isn't in a group
+    pub fn log_group_expand_toggle(&mut self) -> bool {
+        let cs = self.config.log_search_case_sensitive;
+        let toggled = self
+            .get_mut_selected_container()
+            .is_some_and(|i| i.logs.toggle_expanded(cs));
+        if toggled {
+            self.rerender.update_draw();
+        }
+        toggled
+    }
+
+    /// Cycle the selected container's logs between both streams, stdout only, and stderr only
+    pub fn log_stream_filter_cycle(&mut self) {
+        let cs = self.config.log_search_case_sensitive;
+        if let Some(i) = self.get_mut_selected_container() {
+            i.logs.cycle_stream_filter(cs);
+            self.rerender.update_draw();
+        }
+    }
+
     /// Get mutable Vec of current containers logs
     pub fn get_logs(&self, size: Size, padding: usize) -> Vec<Text<'static>> {
         self.containers
             .state
             .selected()
             .and_then(|i| self.containers.items.get(i))
-            .map_or(vec![], |i| i.logs.get_visible_logs(size, padding))
+            .map_or(vec![], |i| {
+                i.logs.get_visible_logs(
+                    size,
+                    padding,
+                    &self.config.app_colors,
+                    self.log_wrap,
+                    self.log_time_column,
+                )
+            })
     }
 
     /// Get mutable Option of the currently selected container Logs state
@@ -927,6 +1609,16 @@ impl AppData {
         self.sort_containers();
     }
 
+    /// Set the memory limit of a container, so that a changed limit is shown straight away, rather than after the next stats update
+    pub fn set_mem_limit(&mut self, id: &ContainerId, mem_limit: u64) {
+        if let Some(container) = self.get_any_container_by_id(id) {
+            container.mem_limit.update(mem_limit);
+        }
+        if self.is_selected_container(id) {
+            self.rerender.update_draw();
+        }
+    }
+
     /// Update, or insert, containers
     pub fn update_containers(&mut self, mut all_containers: Vec<ContainerSummary>) {
         let all_ids = self
@@ -938,7 +1630,7 @@ impl AppData {
 
         // Only sort it no containers currently set, as afterwards the order is fixed
         if self.containers.items.is_empty() {
-            all_containers.sort_by(|a, b| a.created.cmp(&b.created));
+            all_containers.sort_by_key(|a| a.created);
         }
 
         if !all_containers.is_empty() && self.containers.state.selected().is_none() {
@@ -1049,57 +1741,123 @@ impl AppData {
         }
     }
 
-    /// Update logs of a given container, based on id
-    pub fn update_log_by_id(&mut self, logs: Vec<String>, id: &ContainerId) {
-        let color = self.config.color_logs;
-        let raw = self.config.raw_logs;
-        let format = self.config.timestamp_format.clone();
-        let config_tz = self.config.timezone.clone();
+    /// Split each log line into its timestamp & content, and format the content, dependent on the timestamp, colour, & raw config options
+    /// JSON lines are split into the configured columns, unless no columns are configured, stderr lines are prefixed with the stderr marker
+    fn format_logs(&self, logs: Vec<(LogStream, String)>) -> Vec<(LogLine, LogsTz)> {
+        logs.into_iter()
+            .map(|(stream, i)| {
+                let (line, log_tz) = self.format_log(&i);
+                match stream {
+                    LogStream::Stdout => (line, log_tz),
+                    LogStream::Stderr => (line.stderr(&self.config.log_stderr_marker), log_tz),
+                }
+            })
+            .collect()
+    }
+
+    /// Format a single log line
+    fn format_log(&self, i: &str) -> (LogLine, LogsTz) {
+        let format = &self.config.timestamp_format;
+        let config_tz = self.config.timezone.as_ref();
+        let columns = &self.config.log_json_columns;
+        let (log_tz, log_content) = LogsTz::splitter(i);
+        let timestamp = self.config.show_timestamp.then(|| {
+            log_tz
+                .display_with_formatter(config_tz, format)
+                .unwrap_or_else(|| log_tz.to_string())
+        });
+        let content = log_sanitizer::strip_ansi(&log_content);
+        if !columns.is_empty()
+            && let Some(json) = JsonLog::parse(&log_content)
+        {
+            return (
+                LogLine::json(timestamp, columns, json).content(&content),
+                log_tz,
+            );
+        }
+        let i = timestamp.map_or(log_content.clone(), |t| format!("{t} {log_content}"));
+        let lines = if self.config.color_logs {
+            log_sanitizer::colorize_logs(&i)
+        } else if self.config.raw_logs {
+            log_sanitizer::raw(&i)
+        } else {
+            log_sanitizer::remove_ansi(&i)
+        };
+        let level = LogLevel::detect(&content);
+        (
+            LogLine::plain(Text::from(lines), level).content(&content),
+            log_tz,
+        )
+    }
 
+    /// Update logs of a given container, based on id
+    pub fn update_log_by_id(&mut self, logs: Vec<(LogStream, String)>, id: &ContainerId) {
         let cs = self.config.log_search_case_sensitive;
-
-        let show_timestamp = self.config.show_timestamp;
+        let logs = self.format_logs(logs);
 
         if let Some(container) = self.get_any_container_by_id(id) {
             if !container.is_oxker {
                 container.last_updated = Self::get_systemtime();
                 let current_len = container.logs.len();
-                for mut i in logs {
-                    let (log_tz, log_content) = LogsTz::splitter(i.as_str());
-                    if show_timestamp {
-                        i = format!(
-                            "{} {}",
-                            log_tz
-                                .display_with_formatter(config_tz.as_ref(), &format)
-                                .unwrap_or_else(|| log_tz.to_string()),
-                            log_content
-                        );
-                    } else {
-                        i = log_content;
-                    }
-                    let lines = if color {
-                        log_sanitizer::colorize_logs(&i)
-                    } else if raw {
-                        log_sanitizer::raw(&i)
-                    } else {
-                        log_sanitizer::remove_ansi(&i)
-                    };
-                    container.logs.insert(Text::from(lines), log_tz, cs);
+                for (line, log_tz) in logs {
+                    container.logs.insert(line, log_tz, cs);
                 }
 
-                // Set the logs selected row for each container
-                // Either when no long currently selected, or currently selected (before updated) is already at end
-                if container.logs.state().selected().is_none()
-                    || container.logs.state().selected().map_or(1, |f| f + 1) == current_len
-                {
-                    container.logs.end();
-                }
+                // Select the last line when following, otherwise the new lines are counted as unread
+                container.logs.follow_new(current_len);
             }
             if self.is_selected_container(id) {
                 self.rerender.update_draw();
             }
         }
     }
+
+    /// Insert a page of older logs of a given container, fetched when scrolling to the top of the logs
+    /// `complete` is true when there are no more lines, older than these, to fetch
+    pub fn update_older_log_by_id(
+        &mut self,
+        logs: Vec<(LogStream, String)>,
+        id: &ContainerId,
+        complete: bool,
+    ) {
+        let cs = self.config.log_search_case_sensitive;
+        let logs = self.format_logs(logs);
+
+        if let Some(container) = self.get_any_container_by_id(id) {
+            container.logs.insert_older(logs, cs);
+            if complete {
+                container.logs.set_complete();
+            }
+            if self.is_selected_container(id) {
+                self.rerender.update_draw();
+            }
+        }
+    }
+
+    /// Every line of a given container's logs has been fetched
+    pub fn set_logs_complete(&mut self, id: &ContainerId) {
+        if let Some(container) = self.get_any_container_by_id(id) {
+            container.logs.set_complete();
+        }
+    }
+
+    /// The timestamp of the oldest log line of a given container
+    pub fn get_logs_oldest(&mut self, id: &ContainerId) -> Option<LogsTz> {
+        self.get_any_container_by_id(id)
+            .and_then(|i| i.logs.oldest().cloned())
+    }
+
+    /// The time the container was created, in seconds, the logs can't be older than this
+    pub fn get_container_created(&mut self, id: &ContainerId) -> Option<u64> {
+        self.get_any_container_by_id(id).map(|i| i.created)
+    }
+
+    /// The id of the selected container, if the first log line is selected, and older lines can be fetched
+    pub fn get_logs_older_id(&self) -> Option<ContainerId> {
+        self.get_selected_container()
+            .filter(|i| !i.is_oxker && i.logs.needs_older())
+            .map(|i| i.id.clone())
+    }
 }
 
 #[cfg(test)]
@@ -1107,7 +1865,7 @@ impl AppData {
 mod tests {
 
     use super::*;
-    use crate::tests::{gen_appdata, gen_container_summary, gen_containers};
+    use crate::tests::{gen_appdata, gen_container_summary, gen_containers, gen_stdout};
     use std::collections::VecDeque;
 
     // ******* //
@@ -1754,7 +2512,7 @@ mod tests {
         app_data.docker_controls_scroll(&ScrollDirection::Up);
 
         let result = app_data.selected_docker_controls();
-        assert_eq!(result, Some(DockerCommand::Stop));
+        assert_eq!(result, Some(DockerCommand::Commit));
 
         // previous has no effect when at start
         app_data.docker_controls_start();
@@ -1792,6 +2550,9 @@ mod tests {
             &mut vec![
                 DockerCommand::Start,
                 DockerCommand::Restart,
+                DockerCommand::Recreate,
+                DockerCommand::Rename,
+                DockerCommand::Commit,
                 DockerCommand::Delete,
             ],
         );
@@ -1800,6 +2561,9 @@ mod tests {
             &mut vec![
                 DockerCommand::Start,
                 DockerCommand::Restart,
+                DockerCommand::Recreate,
+                DockerCommand::Rename,
+                DockerCommand::Commit,
                 DockerCommand::Delete,
             ],
         );
@@ -1808,13 +2572,24 @@ mod tests {
             &mut vec![
                 DockerCommand::Resume,
                 DockerCommand::Stop,
+                DockerCommand::Kill,
+                DockerCommand::Recreate,
+                DockerCommand::Rename,
+                DockerCommand::Commit,
                 DockerCommand::Delete,
             ],
         );
         test_state(State::Removing, &mut vec![DockerCommand::Delete]);
         test_state(
             State::Restarting,
-            &mut vec![DockerCommand::Stop, DockerCommand::Delete],
+            &mut vec![
+                DockerCommand::Stop,
+                DockerCommand::Kill,
+                DockerCommand::Recreate,
+                DockerCommand::Rename,
+                DockerCommand::Commit,
+                DockerCommand::Delete,
+            ],
         );
         test_state(
             State::Running(RunningState::Healthy),
@@ -1822,6 +2597,10 @@ mod tests {
                 DockerCommand::Pause,
                 DockerCommand::Restart,
                 DockerCommand::Stop,
+                DockerCommand::Kill,
+                DockerCommand::Recreate,
+                DockerCommand::Rename,
+                DockerCommand::Commit,
                 DockerCommand::Delete,
             ],
         );
@@ -2017,7 +2796,7 @@ mod tests {
 
         // On last line of logs
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
         let result = app_data.get_log_title();
         assert_eq!(result, " 3/3 - container_1 - image_1");
 
@@ -2027,6 +2806,313 @@ mod tests {
         assert_eq!(result, " 2/3 - container_1 - image_1");
     }
 
+    #[test]
+    /// The value of a line of the inspect output, strings are unescaped, objects & arrays include every nested line
+    fn test_app_data_inspect_value_at() {
+        let as_string = [
+            r#"  "Id": "abc123","#,
+            r#"  "Path": "/bin/sh\tx","#,
+            r#"  "RestartCount": 0,"#,
+            r#"  "Args": ["#,
+            r#"    "-c","#,
+            r#"    "sleep""#,
+            r#"  ],"#,
+            r#"  "State": {"#,
+            r#"    "Running": true"#,
+            r#"  }"#,
+        ]
+        .join("\n");
+        let data = InspectData {
+            width: 0,
+            height: 10,
+            as_string,
+            name: "container_1".to_owned(),
+            id: ContainerId::from("abc123"),
+        };
+        assert_eq!(
+            data.value_at(0),
+            Some(("Id".to_owned(), "abc123".to_owned()))
+        );
+        assert_eq!(
+            data.value_at(1),
+            Some(("Path".to_owned(), "/bin/sh\tx".to_owned()))
+        );
+        assert_eq!(
+            data.value_at(2),
+            Some(("RestartCount".to_owned(), "0".to_owned()))
+        );
+        assert_eq!(
+            data.value_at(3),
+            Some(("Args".to_owned(), "[\n  \"-c\",\n  \"sleep\"\n]".to_owned()))
+        );
+        assert_eq!(data.value_at(4), Some((String::new(), "-c".to_owned())));
+        assert_eq!(
+            data.value_at(7),
+            Some(("State".to_owned(), "{\n  \"Running\": true\n}".to_owned()))
+        );
+        assert!(data.value_at(10).is_none());
+    }
+
+    #[test]
+    /// A selection of log lines is shown in the title, and cleared once copied
+    fn test_app_data_log_mark() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.config.show_timestamp = false;
+        app_data.containers_start();
+        let logs = (1..=4).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+
+        app_data.log_mark_toggle();
+        app_data.log_scroll(&ScrollDirection::Up);
+        assert_eq!(
+            app_data.get_log_title(),
+            " 3/4 - container_1 - image_1 - 2 selected"
+        );
+        assert_eq!(app_data.take_log_yank(), ["line 3", "line 4"]);
+        assert_eq!(app_data.get_log_title(), " 3/4 - container_1 - image_1");
+        assert_eq!(app_data.take_log_yank(), ["line 3"]);
+
+        app_data.log_mark_toggle();
+        app_data.log_mark_clear();
+        assert_eq!(app_data.get_log_title(), " 3/4 - container_1 - image_1");
+    }
+
+    #[test]
+    /// log title shows the count of each level, and the minimum level filter
+    fn test_app_data_get_log_title_levels() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+
+        let logs = [
+            "1 ERROR failed",
+            "2 INFO started",
+            "3 level=warn msg=slow",
+            "4 no level",
+            "5 INFO done",
+        ]
+        .map(str::to_owned)
+        .to_vec();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        let result = app_data.get_log_title();
+        assert_eq!(
+            result,
+            " 5/5 - container_1 - image_1 - error 1 warn 1 info 2"
+        );
+
+        for _ in 0..3 {
+            app_data.log_level_filter_cycle();
+        }
+        let result = app_data.get_log_title();
+        assert_eq!(
+            result,
+            " 3/3 - container_1 - image_1 - error 1 warn 1 info 2 - level ≥ warn"
+        );
+    }
+
+    #[test]
+    /// stderr lines are marked, and the stream filter shows only the lines of one stream, without refetching
+    fn test_app_data_log_stream_filter() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.config.show_timestamp = false;
+        app_data.containers_start();
+
+        let logs = vec![
+            (LogStream::Stdout, "1 started".to_owned()),
+            (LogStream::Stderr, "2 failed".to_owned()),
+            (LogStream::Stdout, "3 done".to_owned()),
+        ];
+        app_data.update_log_by_id(logs, &ids[0]);
+        let size = Size {
+            width: 20,
+            height: 4,
+        };
+        let get_logs = |app_data: &AppData| {
+            app_data
+                .get_logs(size, 1)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(get_logs(&app_data), ["started", "! failed", "done"]);
+
+        app_data.log_stream_filter_cycle();
+        assert_eq!(get_logs(&app_data), ["started", "done"]);
+        assert_eq!(
+            app_data.get_log_title(),
+            " 2/2 - container_1 - image_1 - stdout only"
+        );
+
+        app_data.log_stream_filter_cycle();
+        assert_eq!(get_logs(&app_data), ["! failed"]);
+        assert_eq!(
+            app_data.get_log_title(),
+            " 1/1 - container_1 - image_1 - stderr only"
+        );
+
+        app_data.log_stream_filter_cycle();
+        assert_eq!(get_logs(&app_data), ["started", "! failed", "done"]);
+    }
+
+    #[test]
+    /// Repeats are collapsed, ignoring the timestamps, and a stack trace is grouped under the line before it, both shown in the title
+    fn test_app_data_log_collapse_group() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+
+        let logs = ["ping", "ping", "failed", "    at main", "ping"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, content)| format!("2025-02-18T12:00:{i:02}.000000000Z {content}"))
+            .collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+
+        app_data.log_collapse_toggle();
+        app_data.log_group_toggle();
+        assert_eq!(
+            app_data.get_log_title(),
+            " 3/3 - container_1 - image_1 - repeats collapsed - traces grouped"
+        );
+        assert!(!app_data.log_group_expand_toggle());
+
+        app_data.log_scroll(&ScrollDirection::Up);
+        assert!(app_data.log_group_expand_toggle());
+        assert_eq!(
+            app_data.get_log_title(),
+            " 2/4 - container_1 - image_1 - repeats collapsed - traces grouped"
+        );
+    }
+
+    #[test]
+    /// Scrolling up pauses following, shown in the title, with new lines counted as unread until going back to live
+    fn test_app_data_log_follow() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+
+        let gen_logs = |range: std::ops::RangeInclusive<usize>| {
+            range
+                .map(|i| format!("2025-02-18T12:00:{i:02}.000000000Z line {i}"))
+                .collect::<Vec<_>>()
+        };
+        app_data.update_log_by_id(gen_stdout(gen_logs(1..=3)), &ids[0]);
+        assert_eq!(app_data.get_log_title(), " 3/3 - container_1 - image_1");
+
+        app_data.log_scroll(&ScrollDirection::Up);
+        app_data.update_log_by_id(gen_stdout(gen_logs(4..=5)), &ids[0]);
+        assert_eq!(
+            app_data.get_log_title(),
+            " 2/5 - container_1 - image_1 - paused"
+        );
+        assert_eq!(app_data.get_log_unread(), 2);
+
+        app_data.log_live();
+        assert_eq!(app_data.get_log_title(), " 5/5 - container_1 - image_1");
+        assert_eq!(app_data.get_log_unread(), 0);
+
+        app_data.log_follow_toggle();
+        app_data.update_log_by_id(gen_stdout(gen_logs(6..=6)), &ids[0]);
+        assert_eq!(
+            app_data.get_log_title(),
+            " 5/6 - container_1 - image_1 - paused"
+        );
+        assert_eq!(app_data.get_log_unread(), 1);
+    }
+
+    #[test]
+    /// The save logs spec is either the selected container, or every container in the current filter, saved into a directory
+    fn test_app_data_save_logs_spec() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        assert!(app_data.get_save_logs_spec().is_none());
+        assert!(!app_data.set_save_logs());
+
+        app_data.containers_start();
+        assert!(app_data.set_save_logs());
+        let spec = app_data.get_save_logs_spec().unwrap();
+        assert!(!spec.dir);
+        assert_eq!(spec.format, SaveFormat::Plain);
+        assert_eq!(
+            spec.targets,
+            [SaveTarget {
+                id: ids[0].clone(),
+                name: "container_1".to_owned(),
+                lines: SaveLines::All,
+            }]
+        );
+
+        app_data.filter_term_push('_');
+        app_data.filter_term_push('2');
+        app_data.filter_containers();
+        app_data.containers_start();
+        assert!(app_data.set_save_logs());
+        app_data.update_save_logs(|i| {
+            i.cycle(true);
+            for _ in 0..3 {
+                i.scroll(&ScrollDirection::Down);
+            }
+            i.cycle(true);
+        });
+        let spec = app_data.get_save_logs_spec().unwrap();
+        assert!(spec.dir);
+        assert_eq!(spec.targets.len(), 1);
+        assert_eq!(spec.targets[0].name, "container_2");
+        assert_eq!(spec.targets[0].lines, SaveLines::All);
+
+        app_data.clear_save_logs();
+        assert!(app_data.get_save_logs().is_none());
+    }
+
+    #[test]
+    /// The log time prompt limits the logs to a range, shown in the title, and returns the time to fetch from if it's before the oldest line
+    fn test_app_data_log_time() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.config.show_timestamp = false;
+        app_data.containers_start();
+
+        let logs = (10..=14)
+            .map(|i| format!("2025-02-18T12:00:{i}.000000000Z line {i}"))
+            .collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+
+        assert!(app_data.set_log_time_prompt());
+        assert!(app_data.get_prompt().is_some());
+
+        let time = |i: &str| Some(i.parse::<Timestamp>().unwrap());
+        let from = app_data.log_time_apply(
+            &ids[0],
+            LogTimeSpec {
+                jump: None,
+                since: time("2025-02-18T12:00:11Z"),
+                until: time("2025-02-18T12:00:13Z"),
+            },
+        );
+        assert!(from.is_none());
+        assert!(app_data.get_prompt().is_none());
+        assert_eq!(
+            app_data.get_log_title(),
+            " 3/3 - container_1 - image_1 - since 2025-02-18 12:00:11 - until 2025-02-18 12:00:13"
+        );
+
+        app_data.log_time_column_cycle();
+        app_data.log_time_column_cycle();
+        assert!(app_data.get_log_title().ends_with(" - delta time"));
+
+        let from = app_data.log_time_apply(
+            &ids[0],
+            LogTimeSpec {
+                jump: time("2025-02-18T11:00:00Z"),
+                since: time("2025-02-18T11:30:00Z"),
+                until: None,
+            },
+        );
+        assert_eq!(from, time("2025-02-18T11:00:00Z"));
+    }
+
     #[test]
     /// log title string generated correctly after container change
     fn test_app_data_get_log_title_after_container_change() {
@@ -2049,7 +3135,7 @@ mod tests {
 
         // On last line of logs
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
-        app_data.update_log_by_id(logs, &ids[1]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[1]);
         let result = app_data.get_log_title();
         assert_eq!(result, " 3/3 - container_2 - image_2");
 
@@ -2072,7 +3158,7 @@ mod tests {
         app_data.containers_start();
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
 
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         let result = app_data.get_log_state();
         assert!(result.is_some());
@@ -2092,6 +3178,90 @@ mod tests {
         assert_eq!(result, " 3/3 - container_1 - image_1");
     }
 
+    #[test]
+    /// JSON lines are drawn as aligned columns, can be searched by field, and every field is shown in the detail popup
+    fn test_app_data_update_log_by_id_json() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.config.show_timestamp = false;
+        app_data.containers_start();
+        let logs = vec![
+            r#"1 {"level":"info","msg":"started","caller":"main.go:10"}"#.to_owned(),
+            "2 plain line".to_owned(),
+            r#"3 {"level":"error","msg":"failed","user_id":42}"#.to_owned(),
+        ];
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+
+        let result = app_data.get_logs(
+            Size {
+                width: 40,
+                height: 4,
+            },
+            1,
+        );
+        assert_eq!(
+            result.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            [
+                "info   started  main.go:10",
+                "plain line",
+                "error  failed   -"
+            ]
+        );
+
+        for c in "level=error".chars() {
+            app_data.log_search_push(c);
+        }
+        assert_eq!(
+            app_data.gen_log_search().unwrap().result,
+            Some("1/1".to_owned())
+        );
+        app_data.logs_search_clear();
+
+        assert!(app_data.set_log_detail());
+        assert_eq!(
+            app_data.get_log_detail().unwrap().fields.items,
+            [
+                ("level".to_owned(), "error".to_owned()),
+                ("msg".to_owned(), "failed".to_owned()),
+                ("user_id".to_owned(), "42".to_owned()),
+            ]
+        );
+        app_data.log_scroll(&ScrollDirection::Up);
+        assert!(!app_data.set_log_detail());
+        assert!(app_data.get_log_detail().is_none());
+
+        app_data.config.log_json_columns = vec![];
+        let logs = vec![r#"4 {"level":"warn"}"#.to_owned()];
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        app_data.log_end();
+        assert!(!app_data.set_log_detail());
+    }
+
+    #[test]
+    /// Older logs are requested only when the first line is selected, and are inserted above it, until the logs are complete
+    fn test_app_data_update_older_log_by_id() {
+        let (ids, containers) = gen_containers();
+        let mut app_data = gen_appdata(&containers);
+        app_data.containers_start();
+        assert!(app_data.get_logs_older_id().is_none());
+
+        let logs = (4..=6).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
+        assert!(app_data.get_logs_older_id().is_none());
+        assert_eq!(app_data.get_logs_oldest(&ids[0]).unwrap().to_string(), "4");
+
+        app_data.log_start();
+        assert_eq!(app_data.get_logs_older_id(), Some(ids[0].clone()));
+
+        let logs = (1..=4).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
+        app_data.update_older_log_by_id(gen_stdout(logs), &ids[0], true);
+        assert_eq!(app_data.get_log_title(), " 4/6 - container_1 - image_1");
+        assert_eq!(app_data.get_logs_oldest(&ids[0]).unwrap().to_string(), "1");
+
+        app_data.log_start();
+        assert!(app_data.get_logs_older_id().is_none());
+    }
+
     #[test]
     /// logs state reset to start
     fn test_app_data_logs_start() {
@@ -2099,7 +3269,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_start();
 
@@ -2119,7 +3289,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_start();
 
@@ -2149,7 +3319,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_start();
 
@@ -2197,7 +3367,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
         app_data.containers_start();
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         app_data.log_end();
 
@@ -2479,7 +3649,7 @@ mod tests {
         let mut app_data = gen_appdata(&containers);
         let logs = (1..=3).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
 
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
         app_data.log_start();
 
         let result = app_data.get_log_state();
@@ -2500,7 +3670,7 @@ mod tests {
         app_data.containers_start();
         let logs = (0..=999).map(|i| format!("{i} {i}")).collect::<Vec<_>>();
 
-        app_data.update_log_by_id(logs, &ids[0]);
+        app_data.update_log_by_id(gen_stdout(logs), &ids[0]);
 
         let result = app_data.get_logs(
             Size {
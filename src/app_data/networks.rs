@@ -0,0 +1,269 @@
+use std::{fmt, net::IpAddr};
+
+use super::{
+    ContainerId, ScrollDirection, StatefulList,
+    form::{Field, Form, check},
+    new_container::valid_name,
+};
+
+/// A Docker network, and, if the container is attached to it, the container's aliases & ip address on it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkItem {
+    pub aliases: Vec<String>,
+    pub connected: bool,
+    pub driver: String,
+    pub ip: Option<String>,
+    pub name: String,
+}
+
+/// The optional fields when connecting a container to a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectField {
+    Alias,
+    Ip,
+}
+
+impl ConnectField {
+    pub const ALL: [Self; 2] = [Self::Alias, Self::Ip];
+
+    /// Example input, shown below the form when the field is selected
+    pub const fn hint(self) -> &'static str {
+        match self {
+            Self::Alias => "optional, extra name the container can be reached by, e.g. db",
+            Self::Ip => "optional, static ip within the network's subnet, e.g. 172.20.0.10",
+        }
+    }
+}
+
+impl Field for ConnectField {}
+
+impl fmt::Display for ConnectField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Alias => "alias",
+            Self::Ip => "ip",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The validated connect form, ready to be sent to Docker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkConnectSpec {
+    pub alias: Option<String>,
+    pub ip: Option<IpAddr>,
+    pub network: String,
+}
+
+/// The alias & ip address to use when connecting to a network
+#[derive(Debug, Clone)]
+pub struct ConnectForm {
+    pub form: Form<ConnectField>,
+    pub network: String,
+}
+
+impl ConnectForm {
+    fn new(network: String) -> Self {
+        Self {
+            form: Form::new(
+                ConnectField::ALL
+                    .iter()
+                    .map(|i| (*i, String::new()))
+                    .collect(),
+                ConnectField::Alias,
+            ),
+            network,
+        }
+    }
+
+    /// Parse both fields, any errors are stored, to be shown next to the field, and None is returned
+    pub fn validate(&mut self) -> Option<NetworkConnectSpec> {
+        let network = &self.network;
+        self.form.validate(|form, errors| {
+            let alias = check(
+                errors,
+                ConnectField::Alias,
+                form.optional(ConnectField::Alias)
+                    .map(|i| {
+                        if valid_name(i) {
+                            Ok(i.to_owned())
+                        } else {
+                            Err("must start with a letter or number, and only contain letters, numbers, _ . or -"
+                                .to_owned())
+                        }
+                    })
+                    .transpose(),
+            );
+            let ip = check(
+                errors,
+                ConnectField::Ip,
+                form.optional(ConnectField::Ip)
+                    .map(str::parse::<IpAddr>)
+                    .transpose()
+                    .map_err(|_| "must be an ipv4, or ipv6, address".to_owned()),
+            );
+            Some(NetworkConnectSpec {
+                alias: alias?,
+                ip: ip?,
+                network: network.clone(),
+            })
+        })
+    }
+}
+
+/// Every network, and whether the container is attached to each one, with the connect form, if it's open
+#[derive(Debug, Clone)]
+pub struct ContainerNetworks {
+    pub connect: Option<ConnectForm>,
+    pub id: ContainerId,
+    pub items: StatefulList<NetworkItem>,
+    pub name: String,
+    pub status: Option<String>,
+}
+
+impl ContainerNetworks {
+    pub fn new(id: ContainerId, name: String, items: Vec<NetworkItem>) -> Self {
+        let mut output = Self {
+            connect: None,
+            id,
+            items: StatefulList::new(vec![]),
+            name,
+            status: None,
+        };
+        output.set_items(items);
+        output
+    }
+
+    /// Replace the networks, after a connect or disconnect, keeping the same network selected
+    pub fn set_items(&mut self, mut items: Vec<NetworkItem>) {
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        let selected = self.selected().map(|i| i.name.clone());
+        self.items = StatefulList::new(items);
+        let index = selected
+            .and_then(|name| self.items.items.iter().position(|i| i.name == name))
+            .unwrap_or_default();
+        if !self.items.items.is_empty() {
+            self.items.state.select(Some(index));
+        }
+    }
+
+    pub fn selected(&self) -> Option<&NetworkItem> {
+        self.items
+            .state
+            .selected()
+            .and_then(|i| self.items.items.get(i))
+    }
+
+    pub fn scroll(&mut self, sd: &ScrollDirection) {
+        self.items.scroll(sd);
+    }
+
+    /// Open the connect form for the selected network, if the container isn't already attached to it
+    pub fn open_connect(&mut self) {
+        if let Some(network) = self.selected().filter(|i| !i.connected) {
+            self.connect = Some(ConnectForm::new(network.name.clone()));
+            self.status = None;
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::{ConnectField, ContainerNetworks, NetworkConnectSpec, NetworkItem};
+    use crate::app_data::{ContainerId, ScrollDirection};
+
+    fn gen_item(name: &str, connected: bool) -> NetworkItem {
+        NetworkItem {
+            aliases: vec![],
+            connected,
+            driver: "bridge".to_owned(),
+            ip: None,
+            name: name.to_owned(),
+        }
+    }
+
+    fn gen_networks() -> ContainerNetworks {
+        ContainerNetworks::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            vec![
+                gen_item("network_b", false),
+                gen_item("bridge", true),
+                gen_item("network_a", false),
+            ],
+        )
+    }
+
+    #[test]
+    /// Networks are sorted by name, and the selected network is kept when the list is replaced
+    fn test_networks_set_items() {
+        let mut networks = gen_networks();
+        assert_eq!(networks.selected().unwrap().name, "bridge");
+        networks.scroll(&ScrollDirection::Down);
+        networks.scroll(&ScrollDirection::Down);
+        assert_eq!(networks.selected().unwrap().name, "network_b");
+
+        networks.set_items(vec![
+            gen_item("network_b", true),
+            gen_item("bridge", true),
+            gen_item("network_a", false),
+        ]);
+        assert_eq!(networks.selected().unwrap().name, "network_b");
+        assert!(networks.selected().unwrap().connected);
+    }
+
+    #[test]
+    /// The connect form only opens for a network the container isn't attached to
+    fn test_networks_open_connect() {
+        let mut networks = gen_networks();
+        networks.open_connect();
+        assert!(networks.connect.is_none());
+        networks.scroll(&ScrollDirection::Down);
+        networks.open_connect();
+        assert_eq!(networks.connect.unwrap().network, "network_a");
+    }
+
+    #[test]
+    /// The alias & ip are optional, but must be valid if given
+    fn test_networks_connect_validate() {
+        let mut networks = gen_networks();
+        networks.scroll(&ScrollDirection::Down);
+        networks.open_connect();
+        let form = networks.connect.as_mut().unwrap();
+        assert_eq!(
+            form.validate(),
+            Some(NetworkConnectSpec {
+                alias: None,
+                ip: None,
+                network: "network_a".to_owned(),
+            })
+        );
+
+        for c in "-db".chars() {
+            form.form.push(c);
+        }
+        form.form.change_field(true);
+        for c in "172.20.0".chars() {
+            form.form.push(c);
+        }
+        assert!(form.validate().is_none());
+        assert!(form.form.error(ConnectField::Alias).is_some());
+        assert!(form.form.error(ConnectField::Ip).is_some());
+
+        form.form.push('.');
+        form.form.push('1');
+        form.form.push('0');
+        form.form.change_field(true);
+        while !form.form.value(ConnectField::Alias).is_empty() {
+            form.form.pop();
+        }
+        form.form.push('d');
+        form.form.push('b');
+        let result = form.validate().unwrap();
+        assert_eq!(result.alias, Some("db".to_owned()));
+        assert_eq!(result.ip, Some(IpAddr::V4(Ipv4Addr::new(172, 20, 0, 10))));
+    }
+}
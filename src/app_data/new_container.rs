@@ -0,0 +1,548 @@
+use std::fmt;
+
+use super::form::{Field, Form, check};
+
+/// Restart policies that can be chosen in the new container form, the first is the default
+pub const RESTART_POLICIES: [&str; 4] = ["no", "always", "unless-stopped", "on-failure"];
+
+/// Docker won't start a container with less than 6MB of memory
+const MIN_MEMORY: i64 = 6 * 1024 * 1024;
+
+/// The fields of the new container form, in the order they are shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormField {
+    Image,
+    Name,
+    Command,
+    Env,
+    Ports,
+    Volumes,
+    Network,
+    Restart,
+    Cpus,
+    Memory,
+}
+
+impl FormField {
+    pub const ALL: [Self; 10] = [
+        Self::Image,
+        Self::Name,
+        Self::Command,
+        Self::Env,
+        Self::Ports,
+        Self::Volumes,
+        Self::Network,
+        Self::Restart,
+        Self::Cpus,
+        Self::Memory,
+    ];
+
+    /// Example input, shown below the form when the field is selected
+    pub const fn hint(self) -> &'static str {
+        match self {
+            Self::Image => "← → to choose a local image, or type one to pull, e.g. redis:7",
+            Self::Name => "optional, e.g. scratch_postgres",
+            Self::Command => "optional, replaces the image command, e.g. redis-server --save ''",
+            Self::Env => "comma separated, e.g. POSTGRES_PASSWORD=secret, TZ=UTC",
+            Self::Ports => "comma separated [ip:]host:container[/protocol], e.g. 5432:5432",
+            Self::Volumes => "comma separated source:target[:ro], e.g. pg_data:/var/lib/postgresql",
+            Self::Network => "← → to choose a network, empty for the default bridge",
+            Self::Restart => "← → to choose the restart policy",
+            Self::Cpus => "optional, number of cpus, e.g. 1.5",
+            Self::Memory => "optional, with a b, k, m, or g suffix, e.g. 512m",
+        }
+    }
+
+    /// Fields whose value is chosen from a list, rather than only typed
+    pub const fn is_choice(self) -> bool {
+        matches!(self, Self::Image | Self::Network | Self::Restart)
+    }
+}
+
+/// The restart policy can only be chosen, the image & network can be chosen or typed
+impl Field for FormField {
+    fn typed(self) -> bool {
+        self != Self::Restart
+    }
+}
+
+impl fmt::Display for FormField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Image => "image",
+            Self::Name => "name",
+            Self::Command => "command",
+            Self::Env => "env",
+            Self::Ports => "ports",
+            Self::Volumes => "volumes",
+            Self::Network => "network",
+            Self::Restart => "restart",
+            Self::Cpus => "cpus",
+            Self::Memory => "memory",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single port binding, a None host port lets Docker choose a random port
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortMapping {
+    pub container_port: u16,
+    pub host_ip: Option<String>,
+    pub host_port: Option<u16>,
+    pub protocol: String,
+}
+
+/// The validated form, ready to be sent to Docker
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewContainerSpec {
+    pub command: Option<Vec<String>>,
+    pub env: Vec<String>,
+    pub image: String,
+    pub memory: Option<i64>,
+    pub name: Option<String>,
+    pub nano_cpus: Option<i64>,
+    pub network: Option<String>,
+    pub ports: Vec<PortMapping>,
+    pub restart: String,
+    pub volumes: Vec<String>,
+}
+
+/// Split a comma separated value, ignoring empty entries
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value.split(',').map(str::trim).filter(|i| !i.is_empty())
+}
+
+/// Split a command into arguments on whitespace, single & double quotes group arguments
+fn split_command(value: &str) -> Result<Vec<String>, String> {
+    let mut output = vec![];
+    let mut current = String::new();
+    let mut quote = None;
+    let mut in_arg = false;
+    for c in value.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    output.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if quote.is_some() {
+        return Err("unclosed quote".to_owned());
+    }
+    if in_arg {
+        output.push(current);
+    }
+    Ok(output)
+}
+
+/// Container names must start with a letter or number, and only contain letters, numbers, _ . or -
+pub(super) fn valid_name(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphanumeric())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+fn parse_image(value: &str) -> Result<String, String> {
+    if value.is_empty() {
+        Err("an image is required".to_owned())
+    } else if value.contains(char::is_whitespace) {
+        Err("can't contain spaces".to_owned())
+    } else {
+        Ok(value.to_owned())
+    }
+}
+
+fn parse_name(value: &str) -> Result<String, String> {
+    if valid_name(value) {
+        Ok(value.to_owned())
+    } else {
+        Err(
+            "must start with a letter or number, and only contain letters, numbers, _ . or -"
+                .to_owned(),
+        )
+    }
+}
+
+fn parse_env(value: &str) -> Result<Vec<String>, String> {
+    split_list(value)
+        .map(|i| match i.split_once('=') {
+            Some((key, _)) if !key.trim().is_empty() && !key.contains(char::is_whitespace) => {
+                Ok(i.to_owned())
+            }
+            _ => Err(format!("\"{i}\" isn't in the format KEY=value")),
+        })
+        .collect()
+}
+
+fn parse_port(value: &str, entry: &str) -> Result<u16, String> {
+    value
+        .parse::<u16>()
+        .ok()
+        .filter(|i| *i > 0)
+        .ok_or_else(|| format!("\"{entry}\" has an invalid port, must be 1-65535"))
+}
+
+/// Parse `[ip:]host:container[/protocol]`, or just `container[/protocol]`
+fn parse_ports(value: &str) -> Result<Vec<PortMapping>, String> {
+    split_list(value)
+        .map(|entry| {
+            let (ports, protocol) = entry.split_once('/').unwrap_or((entry, "tcp"));
+            if !matches!(protocol, "tcp" | "udp" | "sctp") {
+                return Err(format!(
+                    "\"{entry}\" has an invalid protocol, use tcp, udp, or sctp"
+                ));
+            }
+            let parts = ports.rsplitn(3, ':').collect::<Vec<_>>();
+            let (host_ip, host_port, container_port) = match parts.as_slice() {
+                [container] => (None, None, container),
+                [container, host] => (None, Some(parse_port(host, entry)?), container),
+                [container, host, ip] => (
+                    Some((*ip).to_owned()),
+                    Some(parse_port(host, entry)?),
+                    container,
+                ),
+                _ => return Err(format!("\"{entry}\" isn't in the format host:container")),
+            };
+            Ok(PortMapping {
+                container_port: parse_port(container_port, entry)?,
+                host_ip,
+                host_port,
+                protocol: protocol.to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Parse `source:target[:ro|rw]`, the target must be an absolute path
+fn parse_volumes(value: &str) -> Result<Vec<String>, String> {
+    split_list(value)
+        .map(|entry| {
+            let parts = entry.split(':').collect::<Vec<_>>();
+            match parts.as_slice() {
+                [source, target] | [source, target, "ro" | "rw"]
+                    if !source.is_empty() && target.starts_with('/') =>
+                {
+                    Ok(entry.to_owned())
+                }
+                _ => Err(format!(
+                    "\"{entry}\" isn't in the format source:/target[:ro]"
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Convert a number of cpus into the nano cpus Docker expects
+#[allow(clippy::cast_possible_truncation)]
+pub(super) fn parse_cpus(value: &str) -> Result<i64, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|i| i.is_finite() && *i > 0.0)
+        .map(|i| (i * 1_000_000_000.0) as i64)
+        .ok_or_else(|| "must be a number greater than 0".to_owned())
+}
+
+/// Convert a memory limit, with an optional b, k, m, or g suffix, into bytes
+pub(super) fn parse_memory(value: &str) -> Result<i64, String> {
+    let lower = value.to_lowercase();
+    let (number, multiplier) = match lower.chars().last() {
+        Some('b') => (&lower[..lower.len() - 1], 1),
+        Some('k') => (&lower[..lower.len() - 1], 1024),
+        Some('m') => (&lower[..lower.len() - 1], 1024 * 1024),
+        Some('g') => (&lower[..lower.len() - 1], 1024 * 1024 * 1024),
+        _ => (lower.as_str(), 1),
+    };
+    let bytes = number
+        .parse::<i64>()
+        .ok()
+        .and_then(|i| i.checked_mul(multiplier))
+        .ok_or_else(|| {
+            "must be a whole number, with an optional b, k, m, or g suffix".to_owned()
+        })?;
+    if bytes < MIN_MEMORY {
+        return Err("must be at least 6m".to_owned());
+    }
+    Ok(bytes)
+}
+
+/// The new container form, with the local images & networks that can be chosen
+#[derive(Debug, Clone)]
+pub struct NewContainer {
+    pub form: Form<FormField>,
+    pub images: Vec<String>,
+    pub networks: Vec<String>,
+}
+
+impl NewContainer {
+    pub fn new() -> Self {
+        Self {
+            form: Form::new(
+                FormField::ALL
+                    .iter()
+                    .map(|i| {
+                        let value = if *i == FormField::Restart {
+                            RESTART_POLICIES[0].to_owned()
+                        } else {
+                            String::new()
+                        };
+                        (*i, value)
+                    })
+                    .collect(),
+                FormField::Image,
+            ),
+            images: vec![],
+            networks: vec![],
+        }
+    }
+
+    /// Set the local images & networks that can be chosen, sorted by name
+    pub fn set_lists(&mut self, mut images: Vec<String>, mut networks: Vec<String>) {
+        images.sort();
+        networks.sort();
+        self.images = images;
+        self.networks = networks;
+    }
+
+    /// Replace the value of a choice field with the next, or previous, choice, wrapping around at each end
+    pub fn choose(&mut self, forward: bool) {
+        match self.form.selected {
+            FormField::Image => self.form.choose(&self.images, forward),
+            FormField::Network => self.form.choose(&self.networks, forward),
+            FormField::Restart => self.form.choose(&RESTART_POLICIES, forward),
+            _ => (),
+        }
+    }
+
+    /// Parse every field, any errors are stored, to be shown next to the field, and None is returned
+    pub fn validate(&mut self) -> Option<NewContainerSpec> {
+        self.form.validate(|form, errors| {
+            let image = check(
+                errors,
+                FormField::Image,
+                parse_image(form.value(FormField::Image).trim()),
+            );
+            let name = check(
+                errors,
+                FormField::Name,
+                form.optional(FormField::Name).map(parse_name).transpose(),
+            );
+            let command = check(
+                errors,
+                FormField::Command,
+                form.optional(FormField::Command)
+                    .map(split_command)
+                    .transpose(),
+            );
+            let env = check(
+                errors,
+                FormField::Env,
+                parse_env(form.value(FormField::Env).trim()),
+            );
+            let ports = check(
+                errors,
+                FormField::Ports,
+                parse_ports(form.value(FormField::Ports).trim()),
+            );
+            let volumes = check(
+                errors,
+                FormField::Volumes,
+                parse_volumes(form.value(FormField::Volumes).trim()),
+            );
+            let nano_cpus = check(
+                errors,
+                FormField::Cpus,
+                form.optional(FormField::Cpus).map(parse_cpus).transpose(),
+            );
+            let memory = check(
+                errors,
+                FormField::Memory,
+                form.optional(FormField::Memory)
+                    .map(parse_memory)
+                    .transpose(),
+            );
+            Some(NewContainerSpec {
+                command: command?,
+                env: env?,
+                image: image?,
+                memory: memory?,
+                name: name?,
+                nano_cpus: nano_cpus?,
+                network: form.optional(FormField::Network).map(str::to_owned),
+                ports: ports?,
+                restart: form.value(FormField::Restart).trim().to_owned(),
+                volumes: volumes?,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{
+        FormField, NewContainer, PortMapping, parse_memory, parse_ports, parse_volumes,
+        split_command,
+    };
+
+    fn type_into(form: &mut NewContainer, field: FormField, value: &str) {
+        form.form.selected = field;
+        for c in value.chars() {
+            form.form.push(c);
+        }
+    }
+
+    #[test]
+    /// Quotes group arguments, and an unclosed quote is an error
+    fn test_new_container_split_command() {
+        assert_eq!(
+            split_command("redis-server --save '' --appendonly \"yes please\"").unwrap(),
+            vec!["redis-server", "--save", "", "--appendonly", "yes please"]
+        );
+        assert!(split_command("sh -c 'echo").is_err());
+    }
+
+    #[test]
+    /// Ports with, and without, a host ip, host port, & protocol
+    fn test_new_container_parse_ports() {
+        let result = parse_ports("5432:5432, 127.0.0.1:8080:80/udp, 6379").unwrap();
+        assert_eq!(
+            result,
+            vec![
+                PortMapping {
+                    container_port: 5432,
+                    host_ip: None,
+                    host_port: Some(5432),
+                    protocol: "tcp".to_owned(),
+                },
+                PortMapping {
+                    container_port: 80,
+                    host_ip: Some("127.0.0.1".to_owned()),
+                    host_port: Some(8080),
+                    protocol: "udp".to_owned(),
+                },
+                PortMapping {
+                    container_port: 6379,
+                    host_ip: None,
+                    host_port: None,
+                    protocol: "tcp".to_owned(),
+                },
+            ]
+        );
+        assert!(parse_ports("70000:80").is_err());
+        assert!(parse_ports("80:80/http").is_err());
+        assert!(parse_ports("a:b:c:d").is_err());
+    }
+
+    #[test]
+    /// Volumes need an absolute target, and only ro or rw options
+    fn test_new_container_parse_volumes() {
+        assert_eq!(
+            parse_volumes("data:/data, /tmp:/host_tmp:ro").unwrap(),
+            vec!["data:/data", "/tmp:/host_tmp:ro"]
+        );
+        assert!(parse_volumes("data:relative").is_err());
+        assert!(parse_volumes("data:/data:xx").is_err());
+        assert!(parse_volumes("/data").is_err());
+    }
+
+    #[test]
+    /// Memory suffixes are converted to bytes, and the Docker minimum is enforced
+    fn test_new_container_parse_memory() {
+        assert_eq!(parse_memory("512m"), Ok(512 * 1024 * 1024));
+        assert_eq!(parse_memory("1G"), Ok(1024 * 1024 * 1024));
+        assert_eq!(parse_memory("10000000"), Ok(10_000_000));
+        assert!(parse_memory("1k").is_err());
+        assert!(parse_memory("lots").is_err());
+    }
+
+    #[test]
+    /// Every invalid field has an error, and no spec is returned
+    fn test_new_container_validate_errors() {
+        let mut form = NewContainer::new();
+        type_into(&mut form, FormField::Name, "-bad name");
+        type_into(&mut form, FormField::Env, "KEY=value, nope");
+        type_into(&mut form, FormField::Cpus, "0");
+        assert!(form.validate().is_none());
+        assert_eq!(
+            form.form.error(FormField::Image),
+            Some("an image is required")
+        );
+        assert!(form.form.error(FormField::Name).is_some());
+        assert_eq!(
+            form.form.error(FormField::Env),
+            Some("\"nope\" isn't in the format KEY=value")
+        );
+        assert!(form.form.error(FormField::Cpus).is_some());
+        assert!(form.form.error(FormField::Ports).is_none());
+    }
+
+    #[test]
+    /// A valid form gives a spec, and clears any previous errors
+    fn test_new_container_validate() {
+        let mut form = NewContainer::new();
+        assert!(form.validate().is_none());
+        type_into(&mut form, FormField::Image, "postgres:16");
+        type_into(&mut form, FormField::Name, "scratch_postgres");
+        type_into(&mut form, FormField::Env, "POSTGRES_PASSWORD=secret");
+        type_into(&mut form, FormField::Ports, "5432:5432");
+        type_into(&mut form, FormField::Cpus, "1.5");
+        type_into(&mut form, FormField::Memory, "512m");
+        let spec = form.validate().unwrap();
+        assert!(form.form.errors.is_empty());
+        assert_eq!(spec.image, "postgres:16");
+        assert_eq!(spec.name, Some("scratch_postgres".to_owned()));
+        assert_eq!(spec.env, vec!["POSTGRES_PASSWORD=secret"]);
+        assert_eq!(spec.nano_cpus, Some(1_500_000_000));
+        assert_eq!(spec.memory, Some(512 * 1024 * 1024));
+        assert_eq!(spec.restart, "no");
+        assert!(spec.command.is_none());
+        assert!(spec.network.is_none());
+    }
+
+    #[test]
+    /// Choice fields cycle through their choices, and the restart policy can't be typed into
+    fn test_new_container_choose() {
+        let mut form = NewContainer::new();
+        form.set_lists(vec!["redis:7".to_owned(), "postgres:16".to_owned()], vec![]);
+        form.choose(true);
+        assert_eq!(form.form.value(FormField::Image), "postgres:16");
+        form.choose(true);
+        assert_eq!(form.form.value(FormField::Image), "redis:7");
+        form.choose(true);
+        assert_eq!(form.form.value(FormField::Image), "postgres:16");
+
+        form.form.selected = FormField::Network;
+        form.choose(true);
+        assert_eq!(form.form.value(FormField::Network), "");
+
+        form.form.selected = FormField::Restart;
+        form.form.push('x');
+        form.choose(false);
+        assert_eq!(form.form.value(FormField::Restart), "on-failure");
+
+        form.form.change_field(true);
+        assert_eq!(form.form.selected, FormField::Cpus);
+        form.form.change_field(true);
+        form.form.change_field(true);
+        assert_eq!(form.form.selected, FormField::Image);
+        form.form.change_field(false);
+        assert_eq!(form.form.selected, FormField::Memory);
+    }
+}
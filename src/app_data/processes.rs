@@ -0,0 +1,265 @@
+use std::fmt;
+
+use super::{ContainerId, SortedOrder, StatefulList};
+
+/// The columns of the process list, each can be sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessHeader {
+    Pid,
+    User,
+    Cpu,
+    Rss,
+    Command,
+}
+
+impl ProcessHeader {
+    pub const ALL: [Self; 5] = [Self::Pid, Self::User, Self::Cpu, Self::Rss, Self::Command];
+}
+
+impl fmt::Display for ProcessHeader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Pid => "PID",
+            Self::User => "USER",
+            Self::Cpu => "CPU %",
+            Self::Rss => "RSS",
+            Self::Command => "COMMAND",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// Signals that can be sent to a process, via `kill -s` in a one-off exec
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    Term,
+    Int,
+    Hup,
+    Quit,
+    Kill,
+    Usr1,
+    Usr2,
+    Stop,
+    Cont,
+}
+
+impl Signal {
+    pub const ALL: [Self; 9] = [
+        Self::Term,
+        Self::Int,
+        Self::Hup,
+        Self::Quit,
+        Self::Kill,
+        Self::Usr1,
+        Self::Usr2,
+        Self::Stop,
+        Self::Cont,
+    ];
+
+    /// Get the next, or previous, signal, wrapping at either end
+    pub fn cycle(self, forward: bool) -> Self {
+        let len = Self::ALL.len();
+        let index = Self::ALL
+            .iter()
+            .position(|i| *i == self)
+            .unwrap_or_default();
+        let index = if forward {
+            (index + 1) % len
+        } else {
+            (index + len - 1) % len
+        };
+        Self::ALL[index]
+    }
+}
+
+/// The signal name, without the `SIG` prefix, as accepted by `kill -s`
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Term => "TERM",
+            Self::Int => "INT",
+            Self::Hup => "HUP",
+            Self::Quit => "QUIT",
+            Self::Kill => "KILL",
+            Self::Usr1 => "USR1",
+            Self::Usr2 => "USR2",
+            Self::Stop => "STOP",
+            Self::Cont => "CONT",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single process, as returned by `docker top`, the pid is the pid on the host
+#[derive(Debug, Clone, PartialEq)]
+pub struct Process {
+    pub command: String,
+    pub cpu: f64,
+    pub pid: u64,
+    pub rss: u64,
+    pub user: String,
+}
+
+/// The process list of a single container, `signals` is false if signals can't be sent, as the host pids can't be converted
+#[derive(Debug, Clone)]
+pub struct ContainerProcesses {
+    pub id: ContainerId,
+    pub name: String,
+    pub processes: StatefulList<Process>,
+    pub signal: Signal,
+    pub signals: bool,
+    pub sorted_by: Option<(ProcessHeader, SortedOrder)>,
+}
+
+impl ContainerProcesses {
+    pub fn new(id: ContainerId, name: String, processes: Vec<Process>, signals: bool) -> Self {
+        let mut output = Self {
+            id,
+            name,
+            processes: StatefulList::new(vec![]),
+            signal: Signal::Term,
+            signals,
+            sorted_by: None,
+        };
+        output.set_processes(processes);
+        output.processes.start();
+        output
+    }
+
+    pub fn selected(&self) -> Option<&Process> {
+        self.processes
+            .state
+            .selected()
+            .and_then(|i| self.processes.items.get(i))
+    }
+
+    /// Replace the processes, keeping the same pid selected if it still exists
+    pub fn set_processes(&mut self, processes: Vec<Process>) {
+        let selected = self.selected().map(|i| i.pid);
+        self.processes.items = processes;
+        self.sort();
+        self.select_pid(selected);
+        if self.processes.state.selected() >= Some(self.processes.items.len()) {
+            self.processes.end();
+        }
+    }
+
+    /// Sort by a given header, if already sorted ascending by the header then sort descending, and then remove sorting
+    pub fn set_sort_by_header(&mut self, header: ProcessHeader) {
+        let selected = self.selected().map(|i| i.pid);
+        self.sorted_by = match self.sorted_by {
+            Some((current, SortedOrder::Asc)) if current == header => {
+                Some((header, SortedOrder::Desc))
+            }
+            Some((current, SortedOrder::Desc)) if current == header => None,
+            _ => Some((header, SortedOrder::Asc)),
+        };
+        self.sort();
+        self.select_pid(selected);
+    }
+
+    /// Remove sorting, so that the processes are ordered by pid
+    pub fn reset_sorted(&mut self) {
+        let selected = self.selected().map(|i| i.pid);
+        self.sorted_by = None;
+        self.sort();
+        self.select_pid(selected);
+    }
+
+    /// Select the given pid, if it exists, used to keep the selected process after the list has been re-ordered
+    fn select_pid(&mut self, pid: Option<u64>) {
+        if let Some(index) =
+            pid.and_then(|pid| self.processes.items.iter().position(|i| i.pid == pid))
+        {
+            self.processes.state.select(Some(index));
+        }
+    }
+
+    /// Sort the processes by the sorted header, then by pid, if not sorted then just by pid
+    fn sort(&mut self) {
+        let sorted_by = self.sorted_by;
+        self.processes.items.sort_by(|a, b| {
+            let Some((header, order)) = sorted_by else {
+                return a.pid.cmp(&b.pid);
+            };
+            let (a, b) = match order {
+                SortedOrder::Asc => (a, b),
+                SortedOrder::Desc => (b, a),
+            };
+            match header {
+                ProcessHeader::Pid => a.pid.cmp(&b.pid),
+                ProcessHeader::User => a.user.cmp(&b.user),
+                ProcessHeader::Cpu => a.cpu.total_cmp(&b.cpu),
+                ProcessHeader::Rss => a.rss.cmp(&b.rss),
+                ProcessHeader::Command => a.command.cmp(&b.command),
+            }
+            .then_with(|| a.pid.cmp(&b.pid))
+        });
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{ContainerProcesses, Process, ProcessHeader, Signal};
+    use crate::app_data::{ContainerId, SortedOrder};
+
+    fn gen_process(pid: u64, cpu: f64, rss: u64) -> Process {
+        Process {
+            command: format!("command_{pid}"),
+            cpu,
+            pid,
+            rss,
+            user: "root".to_owned(),
+        }
+    }
+
+    fn pids(processes: &ContainerProcesses) -> Vec<u64> {
+        processes.processes.items.iter().map(|i| i.pid).collect()
+    }
+
+    #[test]
+    /// Sorting cycles through ascending, descending, & none, and the selected pid is kept
+    fn test_processes_sort() {
+        let mut processes = ContainerProcesses::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            vec![
+                gen_process(30, 0.5, 100),
+                gen_process(10, 2.0, 300),
+                gen_process(20, 1.0, 200),
+            ],
+            true,
+        );
+        assert_eq!(pids(&processes), [10, 20, 30]);
+        assert_eq!(processes.selected().unwrap().pid, 10);
+
+        processes.set_sort_by_header(ProcessHeader::Cpu);
+        assert_eq!(
+            processes.sorted_by,
+            Some((ProcessHeader::Cpu, SortedOrder::Asc))
+        );
+        assert_eq!(pids(&processes), [30, 20, 10]);
+        assert_eq!(processes.selected().unwrap().pid, 10);
+
+        processes.set_sort_by_header(ProcessHeader::Cpu);
+        assert_eq!(pids(&processes), [10, 20, 30]);
+
+        processes.set_sort_by_header(ProcessHeader::Cpu);
+        assert!(processes.sorted_by.is_none());
+
+        processes.set_sort_by_header(ProcessHeader::Rss);
+        processes.set_processes(vec![gen_process(40, 0.0, 50), gen_process(20, 0.0, 500)]);
+        assert_eq!(pids(&processes), [40, 20]);
+        assert_eq!(processes.selected().unwrap().pid, 20);
+    }
+
+    #[test]
+    /// Signals wrap in both directions
+    fn test_processes_signal_cycle() {
+        assert_eq!(Signal::Term.cycle(true), Signal::Int);
+        assert_eq!(Signal::Term.cycle(false), Signal::Cont);
+        assert_eq!(Signal::Cont.cycle(true), Signal::Term);
+        assert_eq!(Signal::Kill.to_string(), "KILL");
+    }
+}
@@ -0,0 +1,466 @@
+use std::fmt;
+
+use jiff::{
+    SignedDuration, Timestamp,
+    civil::{DateTime, Time},
+    tz::TimeZone,
+};
+
+use super::{
+    ContainerId,
+    form::{Field, Form},
+    new_container::valid_name,
+};
+
+/// The format used to prefill, and show, the times of the log time prompt
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Which prompt is open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptKind {
+    Commit,
+    LogTime,
+    Rename,
+}
+
+impl fmt::Display for PromptKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Commit => "commit",
+            Self::LogTime => "log time",
+            Self::Rename => "rename",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+impl PromptKind {
+    /// The action taken when the prompt is submitted
+    pub const fn action(self) -> &'static str {
+        match self {
+            Self::Commit => "commit",
+            Self::LogTime => "apply",
+            Self::Rename => "rename",
+        }
+    }
+}
+
+/// The fields of the rename, commit, & log time prompts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptField {
+    Name,
+    Image,
+    Author,
+    Message,
+    Jump,
+    Since,
+    Until,
+}
+
+impl PromptField {
+    /// Example input, shown below the prompt when the field is selected
+    pub const fn hint(self) -> &'static str {
+        match self {
+            Self::Name => "letters, numbers, _ . or -, e.g. postgres_old",
+            Self::Image => "repo[:tag], e.g. debug/postgres:before_migration",
+            Self::Author => "optional, e.g. Jane Doe <jane@example.com>",
+            Self::Message => "optional, e.g. state before running the migration",
+            Self::Jump => "optional, e.g. -15m, 12:30, or 2025-02-18 12:30:00",
+            Self::Since => "optional start of the range, e.g. -1h30m",
+            Self::Until => "optional end of the range, e.g. 2025-02-18 13:00",
+        }
+    }
+}
+
+impl Field for PromptField {}
+
+impl fmt::Display for PromptField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Name => "name",
+            Self::Image => "repo:tag",
+            Self::Author => "author",
+            Self::Message => "message",
+            Self::Jump => "jump to",
+            Self::Since => "since",
+            Self::Until => "until",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The validated commit prompt, ready to be sent to Docker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitSpec {
+    pub author: Option<String>,
+    pub message: Option<String>,
+    pub repo: String,
+    pub tag: Option<String>,
+}
+
+/// The validated log time prompt, the logs are limited to the since & until range, and then the first line at, or after, the jump time is selected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogTimeSpec {
+    pub jump: Option<Timestamp>,
+    pub since: Option<Timestamp>,
+    pub until: Option<Timestamp>,
+}
+
+/// The validated prompt, depending on which command opened it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptSpec {
+    Commit(CommitSpec),
+    LogTime(LogTimeSpec),
+    Rename(String),
+}
+
+/// Format a time in a given timezone, in the format accepted by the log time prompt
+pub fn format_time(timestamp: Timestamp, tz: &TimeZone) -> String {
+    timestamp
+        .to_zoned(tz.clone())
+        .strftime(TIME_FORMAT)
+        .to_string()
+}
+
+/// Parse a duration made of one, or more, number & unit pairs, e.g. `15m` or `1h30m`, units are s, m, h, & d
+fn parse_duration(value: &str) -> Option<SignedDuration> {
+    let mut output = SignedDuration::ZERO;
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            _ => return None,
+        };
+        let secs = number.parse::<i64>().ok()?.checked_mul(unit)?;
+        output = output.checked_add(SignedDuration::from_secs(secs))?;
+        number.clear();
+    }
+    number.is_empty().then_some(output)
+}
+
+/// Parse a time, relative to now, e.g. `-15m`, or absolute, either an RFC 3339 timestamp, a date & time, or a time of today, the last two in the given timezone
+/// An empty value is no time
+fn parse_time(value: &str, now: Timestamp, tz: &TimeZone) -> Result<Option<Timestamp>, String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let error = || "must be relative, e.g. -15m, or a time, e.g. 12:30".to_owned();
+    if let Some(relative) = value.strip_prefix('-') {
+        return parse_duration(relative)
+            .and_then(|i| now.checked_sub(i).ok())
+            .map(Some)
+            .ok_or_else(error);
+    }
+    if let Ok(timestamp) = value.parse::<Timestamp>() {
+        return Ok(Some(timestamp));
+    }
+    let datetime = value.parse::<DateTime>().or_else(|_| {
+        value
+            .parse::<Time>()
+            .map(|time| now.to_zoned(tz.clone()).date().to_datetime(time))
+    });
+    datetime
+        .and_then(|i| i.to_zoned(tz.clone()))
+        .map(|i| Some(i.timestamp()))
+        .map_err(|_| error())
+}
+
+/// Split `repo[:tag]` into its parts, a colon before the last slash is a registry port, rather than a tag
+fn parse_image(value: &str) -> Result<(String, Option<String>), String> {
+    let (repo, tag) = match value.rsplit_once(':') {
+        Some((repo, tag)) if !tag.contains('/') => (repo, Some(tag)),
+        _ => (value, None),
+    };
+    if repo.is_empty()
+        || !repo
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "._-/:".contains(c))
+    {
+        return Err("the repo must be lowercase letters, numbers, . _ - / or :".to_owned());
+    }
+    if let Some(tag) = tag
+        && (tag.is_empty()
+            || tag.len() > 128
+            || tag.starts_with(['.', '-'])
+            || !tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')))
+    {
+        return Err("the tag must be letters, numbers, and _ . or - only".to_owned());
+    }
+    Ok((repo.to_owned(), tag.map(str::to_owned)))
+}
+
+/// A small text input popup, for the commands that need some user input before being sent, i.e. rename & commit, or to choose the times of the logs
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    pub form: Form<PromptField>,
+    pub id: ContainerId,
+    pub kind: PromptKind,
+    pub name: String,
+    tz: TimeZone,
+}
+
+impl Prompt {
+    /// Rename prompt, prefilled with the current name
+    pub fn rename(id: ContainerId, name: String) -> Self {
+        Self {
+            form: Form::new(vec![(PromptField::Name, name.clone())], PromptField::Name),
+            id,
+            kind: PromptKind::Rename,
+            name,
+            tz: TimeZone::UTC,
+        }
+    }
+
+    /// Commit prompt, for the repo:tag, author, & message of the new image
+    pub fn commit(id: ContainerId, name: String) -> Self {
+        Self {
+            form: Form::new(
+                [
+                    PromptField::Image,
+                    PromptField::Author,
+                    PromptField::Message,
+                ]
+                .into_iter()
+                .map(|i| (i, String::new()))
+                .collect(),
+                PromptField::Image,
+            ),
+            id,
+            kind: PromptKind::Commit,
+            name,
+            tz: TimeZone::UTC,
+        }
+    }
+
+    /// Log time prompt, to jump to a time, and limit the logs to a range, prefilled with the current range
+    /// Times are shown, and parsed, in the given timezone, or UTC
+    pub fn log_time(
+        id: ContainerId,
+        name: String,
+        (since, until): (Option<Timestamp>, Option<Timestamp>),
+        tz: Option<TimeZone>,
+    ) -> Self {
+        let tz = tz.unwrap_or(TimeZone::UTC);
+        let format = |i: Option<Timestamp>| i.map(|i| format_time(i, &tz)).unwrap_or_default();
+        let values = vec![
+            (PromptField::Jump, String::new()),
+            (PromptField::Since, format(since)),
+            (PromptField::Until, format(until)),
+        ];
+        Self {
+            form: Form::new(values, PromptField::Jump),
+            id,
+            kind: PromptKind::LogTime,
+            name,
+            tz,
+        }
+    }
+
+    /// Parse every field, any errors are stored, to be shown next to the field, and None is returned
+    pub fn validate(&mut self) -> Option<PromptSpec> {
+        let (kind, tz) = (self.kind, &self.tz);
+        self.form.validate(|form, errors| {
+            let result = match kind {
+                PromptKind::Rename => {
+                    let name = form.value(PromptField::Name).trim();
+                    if valid_name(name) {
+                        Ok(PromptSpec::Rename(name.to_owned()))
+                    } else {
+                        Err((
+                            PromptField::Name,
+                            "must start with a letter or number, and only contain letters, numbers, _ . or -"
+                                .to_owned(),
+                        ))
+                    }
+                }
+                PromptKind::Commit => parse_image(form.value(PromptField::Image).trim())
+                    .map(|(repo, tag)| {
+                        PromptSpec::Commit(CommitSpec {
+                            author: form.optional(PromptField::Author).map(str::to_owned),
+                            message: form.optional(PromptField::Message).map(str::to_owned),
+                            repo,
+                            tag,
+                        })
+                    })
+                    .map_err(|e| (PromptField::Image, e)),
+                PromptKind::LogTime => log_time_spec(form, tz, Timestamp::now()),
+            };
+            result.map_err(|e| errors.push(e)).ok()
+        })
+    }
+}
+
+/// Parse the jump, since, & until times, relative times are relative to `now`
+fn log_time_spec(
+    form: &Form<PromptField>,
+    tz: &TimeZone,
+    now: Timestamp,
+) -> Result<PromptSpec, (PromptField, String)> {
+    let parse = |field: PromptField| parse_time(form.value(field), now, tz).map_err(|e| (field, e));
+    let spec = LogTimeSpec {
+        jump: parse(PromptField::Jump)?,
+        since: parse(PromptField::Since)?,
+        until: parse(PromptField::Until)?,
+    };
+    if let (Some(since), Some(until)) = (spec.since, spec.until)
+        && since > until
+    {
+        return Err((PromptField::Until, "must be after since".to_owned()));
+    }
+    Ok(PromptSpec::LogTime(spec))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use jiff::{Timestamp, tz::TimeZone};
+
+    use super::{
+        CommitSpec, LogTimeSpec, Prompt, PromptField, PromptSpec, parse_image, parse_time,
+    };
+    use crate::app_data::ContainerId;
+
+    fn type_into(prompt: &mut Prompt, field: PromptField, value: &str) {
+        prompt.form.selected = field;
+        for c in value.chars() {
+            prompt.form.push(c);
+        }
+    }
+
+    #[test]
+    /// Tags are split from the repo, but a registry port isn't a tag
+    fn test_prompt_parse_image() {
+        assert_eq!(
+            parse_image("debug/postgres:before").unwrap(),
+            ("debug/postgres".to_owned(), Some("before".to_owned()))
+        );
+        assert_eq!(
+            parse_image("localhost:5000/postgres").unwrap(),
+            ("localhost:5000/postgres".to_owned(), None)
+        );
+        assert!(parse_image("").is_err());
+        assert!(parse_image("Postgres").is_err());
+        assert!(parse_image("postgres:").is_err());
+        assert!(parse_image("postgres:-tag").is_err());
+    }
+
+    #[test]
+    /// The rename prompt is prefilled with the current name, and the new name is validated
+    fn test_prompt_rename() {
+        let mut prompt = Prompt::rename(ContainerId::from("1"), "container_1".to_owned());
+        assert_eq!(prompt.form.fields(), vec![PromptField::Name]);
+        assert_eq!(prompt.form.value(PromptField::Name), "container_1");
+        type_into(&mut prompt, PromptField::Name, " old");
+        assert!(prompt.validate().is_none());
+        assert!(prompt.form.error(PromptField::Name).is_some());
+
+        for _ in 0..4 {
+            prompt.form.pop();
+        }
+        type_into(&mut prompt, PromptField::Name, "_old");
+        assert_eq!(
+            prompt.validate(),
+            Some(PromptSpec::Rename("container_1_old".to_owned()))
+        );
+        assert!(prompt.form.errors.is_empty());
+    }
+
+    #[test]
+    /// The commit prompt needs a repo, the author & message are optional
+    fn test_prompt_commit() {
+        let mut prompt = Prompt::commit(ContainerId::from("1"), "container_1".to_owned());
+        assert!(prompt.validate().is_none());
+        assert!(prompt.form.error(PromptField::Image).is_some());
+
+        type_into(
+            &mut prompt,
+            PromptField::Image,
+            "debug/container_1:snapshot",
+        );
+        type_into(&mut prompt, PromptField::Message, "before migration");
+        prompt.form.change_field(true);
+        assert_eq!(prompt.form.selected, PromptField::Image);
+        prompt.form.change_field(false);
+        assert_eq!(prompt.form.selected, PromptField::Message);
+        assert_eq!(
+            prompt.validate(),
+            Some(PromptSpec::Commit(CommitSpec {
+                author: None,
+                message: Some("before migration".to_owned()),
+                repo: "debug/container_1".to_owned(),
+                tag: Some("snapshot".to_owned()),
+            }))
+        );
+    }
+
+    #[test]
+    /// Times can be relative to now, a full timestamp, a date & time, or a time of today, in the given timezone
+    fn test_prompt_parse_time() {
+        let now = "2025-02-18T12:00:00Z".parse::<Timestamp>().unwrap();
+        let tz = TimeZone::get("Asia/Tokyo").unwrap();
+        let parse = |value: &str| parse_time(value, now, &tz).map(|i| i.map(|i| i.to_string()));
+
+        assert_eq!(parse("  "), Ok(None));
+        assert_eq!(parse("-15m"), Ok(Some("2025-02-18T11:45:00Z".to_owned())));
+        assert_eq!(parse("-1h30m"), Ok(Some("2025-02-18T10:30:00Z".to_owned())));
+        assert_eq!(parse("-2d"), Ok(Some("2025-02-16T12:00:00Z".to_owned())));
+        assert_eq!(
+            parse("2025-02-18T08:00:00Z"),
+            Ok(Some("2025-02-18T08:00:00Z".to_owned()))
+        );
+        assert_eq!(
+            parse("2025-02-18 08:00"),
+            Ok(Some("2025-02-17T23:00:00Z".to_owned()))
+        );
+        assert_eq!(parse("20:30"), Ok(Some("2025-02-18T11:30:00Z".to_owned())));
+        assert!(parse("-15").is_err());
+        assert!(parse("-15w").is_err());
+        assert!(parse("yesterday").is_err());
+    }
+
+    #[test]
+    /// The log time prompt is prefilled with the current range, and since must be before until
+    fn test_prompt_log_time() {
+        let since = "2025-02-18T08:00:00Z".parse::<Timestamp>().unwrap();
+        let mut prompt = Prompt::log_time(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            (Some(since), None),
+            None,
+        );
+        assert_eq!(
+            prompt.form.fields(),
+            vec![PromptField::Jump, PromptField::Since, PromptField::Until]
+        );
+        assert_eq!(prompt.form.value(PromptField::Since), "2025-02-18 08:00:00");
+        assert_eq!(prompt.form.value(PromptField::Until), "");
+
+        type_into(&mut prompt, PromptField::Until, "2025-02-18 07:00");
+        assert!(prompt.validate().is_none());
+        assert!(prompt.form.error(PromptField::Until).is_some());
+
+        for _ in 0..5 {
+            prompt.form.pop();
+        }
+        type_into(&mut prompt, PromptField::Until, "09:00");
+        type_into(&mut prompt, PromptField::Jump, "2025-02-18 08:30:00");
+        assert_eq!(
+            prompt.validate(),
+            Some(PromptSpec::LogTime(LogTimeSpec {
+                jump: Some("2025-02-18T08:30:00Z".parse().unwrap()),
+                since: Some(since),
+                until: Some("2025-02-18T09:00:00Z".parse().unwrap()),
+            }))
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use super::{ByteStats, ScrollDirection, StatefulList};
+
+/// The kinds of unused Docker objects that can be pruned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneTarget {
+    Containers,
+    DanglingImages,
+    UnusedImages,
+    Networks,
+    Volumes,
+}
+
+impl PruneTarget {
+    pub const ALL: [Self; 5] = [
+        Self::Containers,
+        Self::DanglingImages,
+        Self::UnusedImages,
+        Self::Networks,
+        Self::Volumes,
+    ];
+}
+
+impl fmt::Display for PruneTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Containers => "stopped containers",
+            Self::DanglingImages => "dangling images",
+            Self::UnusedImages => "unused images",
+            Self::Networks => "unused networks",
+            Self::Volumes => "unused volumes",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// A single object that will be removed by a prune, size is None when Docker doesn't report it, e.g. for networks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PruneItem {
+    pub name: String,
+    pub size: Option<u64>,
+}
+
+impl PruneItem {
+    /// The size as a human readable string, or a dash if not known
+    pub fn size_text(&self) -> String {
+        self.size
+            .map_or_else(|| "-".to_owned(), |i| ByteStats::new(i).to_string())
+    }
+}
+
+/// The objects that will be removed by a prune of the given target, shown before the prune is confirmed
+#[derive(Debug, Clone)]
+pub struct PrunePreview {
+    pub items: StatefulList<PruneItem>,
+    pub target: PruneTarget,
+}
+
+impl PrunePreview {
+    pub fn new(target: PruneTarget, mut items: Vec<PruneItem>) -> Self {
+        items.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut items = StatefulList::new(items);
+        items.start();
+        Self { items, target }
+    }
+
+    /// The total space that will be reclaimed, only counts items with a known size
+    pub fn total(&self) -> u64 {
+        self.items.items.iter().filter_map(|i| i.size).sum()
+    }
+}
+
+/// The prune menu, a list of targets, and the preview of the chosen target, if one has been loaded
+#[derive(Debug, Clone)]
+pub struct Prune {
+    pub preview: Option<PrunePreview>,
+    pub targets: StatefulList<PruneTarget>,
+}
+
+impl Prune {
+    pub fn new() -> Self {
+        let mut targets = StatefulList::new(PruneTarget::ALL.to_vec());
+        targets.start();
+        Self {
+            preview: None,
+            targets,
+        }
+    }
+
+    pub fn selected(&self) -> Option<PruneTarget> {
+        self.targets
+            .state
+            .selected()
+            .and_then(|i| self.targets.items.get(i))
+            .copied()
+    }
+
+    /// Scroll the preview list if a preview is open, else the target list
+    pub fn scroll(&mut self, sd: &ScrollDirection) {
+        if let Some(preview) = self.preview.as_mut() {
+            preview.items.scroll(sd);
+        } else {
+            self.targets.scroll(sd);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prune, PruneItem, PrunePreview, PruneTarget};
+    use crate::app_data::ScrollDirection;
+
+    #[test]
+    /// Preview items sorted by name, and the total only includes known sizes
+    fn test_prune_preview_total() {
+        let preview = PrunePreview::new(
+            PruneTarget::Volumes,
+            vec![
+                PruneItem {
+                    name: "volume_b".to_owned(),
+                    size: Some(2048),
+                },
+                PruneItem {
+                    name: "volume_a".to_owned(),
+                    size: None,
+                },
+                PruneItem {
+                    name: "volume_c".to_owned(),
+                    size: Some(1024),
+                },
+            ],
+        );
+        assert_eq!(preview.total(), 3072);
+        assert_eq!(preview.items.items[0].name, "volume_a");
+        assert_eq!(preview.items.items[0].size_text(), "-");
+        assert_eq!(preview.items.items[1].size_text(), "2.05 kB");
+    }
+
+    #[test]
+    /// Scrolling moves the target selection, or the preview selection when a preview is open
+    fn test_prune_scroll() {
+        let mut prune = Prune::new();
+        assert_eq!(prune.selected(), Some(PruneTarget::Containers));
+        prune.scroll(&ScrollDirection::Down);
+        assert_eq!(prune.selected(), Some(PruneTarget::DanglingImages));
+
+        prune.preview = Some(PrunePreview::new(PruneTarget::DanglingImages, vec![]));
+        prune.scroll(&ScrollDirection::Down);
+        assert_eq!(prune.selected(), Some(PruneTarget::DanglingImages));
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+/// The steps of a pull & recreate, in the order they are run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecreateStage {
+    Pulling,
+    Stopping,
+    Creating,
+    Starting,
+    RollingBack,
+}
+
+impl fmt::Display for RecreateStage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Pulling => "pulling image",
+            Self::Stopping => "stopping old container",
+            Self::Creating => "creating new container",
+            Self::Starting => "starting new container",
+            Self::RollingBack => "rolling back",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The progress of a single image layer, as reported by the pull
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullLayer {
+    pub id: String,
+    pub status: String,
+    pub current: Option<u64>,
+    pub total: Option<u64>,
+}
+
+impl PullLayer {
+    /// Percentage of the current step, downloading or extracting, that is complete, None if the layer hasn't reported a size
+    pub fn percent(&self) -> Option<u64> {
+        match (self.current, self.total) {
+            (Some(current), Some(total)) if total > 0 => Some((current * 100 / total).min(100)),
+            _ => None,
+        }
+    }
+}
+
+/// A container being recreated, by name, with a freshly pulled image, the layers are listed in the order they were first reported
+#[derive(Debug, Clone)]
+pub struct Recreate {
+    pub image: String,
+    pub layers: Vec<PullLayer>,
+    pub name: String,
+    pub stage: RecreateStage,
+    pub status: Option<String>,
+}
+
+impl Recreate {
+    pub const fn new(name: String, image: String) -> Self {
+        Self {
+            image,
+            layers: vec![],
+            name,
+            stage: RecreateStage::Pulling,
+            status: None,
+        }
+    }
+
+    /// Update from a single message of the pull stream
+    /// Messages with an id, that aren't the "Pulling from" header, refer to a layer, all others are general status messages, e.g. the digest
+    pub fn pull_progress(
+        &mut self,
+        id: Option<String>,
+        status: Option<String>,
+        current: Option<i64>,
+        total: Option<i64>,
+    ) {
+        let Some(status) = status else {
+            return;
+        };
+        let Some(id) = id.filter(|_| !status.starts_with("Pulling from")) else {
+            self.status = Some(status);
+            return;
+        };
+        let (current, total) = (
+            current.and_then(|i| u64::try_from(i).ok()),
+            total.and_then(|i| u64::try_from(i).ok()),
+        );
+        if let Some(layer) = self.layers.iter_mut().find(|i| i.id == id) {
+            layer.status = status;
+            layer.current = current;
+            layer.total = total;
+        } else {
+            self.layers.push(PullLayer {
+                id,
+                status,
+                current,
+                total,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PullLayer, Recreate};
+
+    #[test]
+    /// Layers are updated in place, and messages without a layer id are kept as the status
+    fn test_recreate_pull_progress() {
+        let mut recreate = Recreate::new("container_1".to_owned(), "redis:latest".to_owned());
+        let status = |i: &str| Some(i.to_owned());
+        recreate.pull_progress(
+            status("latest"),
+            status("Pulling from library/redis"),
+            None,
+            None,
+        );
+        recreate.pull_progress(status("aaa"), status("Pulling fs layer"), None, None);
+        recreate.pull_progress(status("bbb"), status("Already exists"), None, None);
+        recreate.pull_progress(status("aaa"), status("Downloading"), Some(50), Some(200));
+        recreate.pull_progress(None, status("Digest: sha256:123"), None, None);
+        recreate.pull_progress(None, None, None, None);
+
+        assert_eq!(recreate.layers.len(), 2);
+        assert_eq!(recreate.layers[0].status, "Downloading");
+        assert_eq!(recreate.layers[0].percent(), Some(25));
+        assert_eq!(recreate.layers[1].percent(), None);
+        assert_eq!(recreate.status, status("Digest: sha256:123"));
+    }
+
+    #[test]
+    /// A zero total, or a current larger than the total, doesn't give an invalid percentage
+    fn test_recreate_layer_percent() {
+        let layer = |current, total| PullLayer {
+            id: "aaa".to_owned(),
+            status: "Extracting".to_owned(),
+            current,
+            total,
+        };
+        assert_eq!(layer(Some(10), Some(0)).percent(), None);
+        assert_eq!(layer(Some(300), Some(200)).percent(), Some(100));
+        assert_eq!(layer(None, Some(200)).percent(), None);
+    }
+}
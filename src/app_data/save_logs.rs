@@ -0,0 +1,296 @@
+use std::{collections::HashSet, fmt};
+
+use jiff::Timestamp;
+
+use super::{ContainerId, ScrollDirection, StatefulList};
+
+/// Which of the logs lines are saved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveScope {
+    All,
+    Results,
+    Range,
+}
+
+impl SaveScope {
+    const fn next(self, forward: bool) -> Self {
+        match (self, forward) {
+            (Self::All, true) | (Self::Range, false) => Self::Results,
+            (Self::Results, true) | (Self::All, false) => Self::Range,
+            (Self::Range, true) | (Self::Results, false) => Self::All,
+        }
+    }
+}
+
+impl fmt::Display for SaveScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::All => "everything",
+            Self::Results => "search & filter results",
+            Self::Range => "visible time range",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The format of the saved file, NDJSON is a JSON object per line, with the timestamp, stream, and container name of each line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveFormat {
+    Plain,
+    Ansi,
+    Ndjson,
+}
+
+impl SaveFormat {
+    const fn next(self, forward: bool) -> Self {
+        match (self, forward) {
+            (Self::Plain, true) | (Self::Ndjson, false) => Self::Ansi,
+            (Self::Ansi, true) | (Self::Plain, false) => Self::Ndjson,
+            (Self::Ndjson, true) | (Self::Ansi, false) => Self::Plain,
+        }
+    }
+
+    /// The file extension of each saved file
+    pub fn extension(self, gzip: bool) -> String {
+        let extension = match self {
+            Self::Plain | Self::Ansi => "log",
+            Self::Ndjson => "ndjson",
+        };
+        if gzip {
+            format!("{extension}.gz")
+        } else {
+            extension.to_owned()
+        }
+    }
+}
+
+impl fmt::Display for SaveFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Plain => "plain",
+            Self::Ansi => "ANSI colors preserved",
+            Self::Ndjson => "NDJSON",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The rows of the save logs popup
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveField {
+    Scope,
+    Format,
+    Gzip,
+    Containers,
+}
+
+impl SaveField {
+    pub const ALL: [Self; 4] = [Self::Scope, Self::Format, Self::Gzip, Self::Containers];
+}
+
+impl fmt::Display for SaveField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let disp = match self {
+            Self::Scope => "scope",
+            Self::Format => "format",
+            Self::Gzip => "gzip",
+            Self::Containers => "containers",
+        };
+        write!(f, "{disp}")
+    }
+}
+
+/// The lines of a single container to save, the timestamps of the results, or the since & until times of the range
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveLines {
+    All,
+    Results(HashSet<Timestamp>),
+    Range((Option<Timestamp>, Option<Timestamp>)),
+}
+
+impl SaveLines {
+    /// Check if a line, with the given timestamp, should be saved
+    pub fn keeps(&self, timestamp: Option<Timestamp>) -> bool {
+        match self {
+            Self::All => true,
+            Self::Results(results) => timestamp.is_some_and(|i| results.contains(&i)),
+            Self::Range((since, until)) => timestamp.is_none_or(|i| {
+                since.is_none_or(|since| i >= since) && until.is_none_or(|until| i <= until)
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveTarget {
+    pub id: ContainerId,
+    pub name: String,
+    pub lines: SaveLines,
+}
+
+/// Everything needed to save the logs, if `dir` is true each container is saved into a single timestamped directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveLogsSpec {
+    pub dir: bool,
+    pub format: SaveFormat,
+    pub gzip: bool,
+    pub targets: Vec<SaveTarget>,
+}
+
+/// The save logs popup, the options are changed with left & right, `filtered` is the number of containers in the current filter
+/// The results scope is only available for a single container, as the search & filter results are only known for containers whose logs have been loaded
+#[derive(Debug, Clone)]
+pub struct SaveLogs {
+    pub all: bool,
+    pub fields: StatefulList<SaveField>,
+    pub filtered: usize,
+    pub format: SaveFormat,
+    pub gzip: bool,
+    pub name: String,
+    pub scope: SaveScope,
+}
+
+impl SaveLogs {
+    pub fn new(name: String, filtered: usize) -> Self {
+        let mut fields = StatefulList::new(SaveField::ALL.to_vec());
+        fields.start();
+        Self {
+            all: false,
+            fields,
+            filtered,
+            format: SaveFormat::Plain,
+            gzip: false,
+            name,
+            scope: SaveScope::All,
+        }
+    }
+
+    pub fn selected(&self) -> Option<SaveField> {
+        self.fields
+            .state
+            .selected()
+            .and_then(|i| self.fields.items.get(i))
+            .copied()
+    }
+
+    pub fn scroll(&mut self, sd: &ScrollDirection) {
+        self.fields.scroll(sd);
+    }
+
+    /// Change the value of the selected row, forwards or backwards
+    pub fn cycle(&mut self, forward: bool) {
+        match self.selected() {
+            Some(SaveField::Scope) => {
+                self.scope = self.scope.next(forward);
+                if self.all && self.scope == SaveScope::Results {
+                    self.scope = self.scope.next(forward);
+                }
+            }
+            Some(SaveField::Format) => self.format = self.format.next(forward),
+            Some(SaveField::Gzip) => self.gzip = !self.gzip,
+            Some(SaveField::Containers) => {
+                self.all = !self.all;
+                if self.all && self.scope == SaveScope::Results {
+                    self.scope = SaveScope::All;
+                }
+            }
+            None => (),
+        }
+    }
+
+    pub fn value(&self, field: SaveField) -> String {
+        match field {
+            SaveField::Scope => self.scope.to_string(),
+            SaveField::Format => self.format.to_string(),
+            SaveField::Gzip => if self.gzip { "yes" } else { "no" }.to_owned(),
+            SaveField::Containers => {
+                if self.all {
+                    format!("all {} in the current filter", self.filtered)
+                } else {
+                    self.name.clone()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use jiff::Timestamp;
+
+    use super::{SaveField, SaveFormat, SaveLines, SaveLogs, SaveScope};
+    use crate::app_data::ScrollDirection;
+
+    #[test]
+    /// Left & right change the value of the selected row, and the extension follows the format & gzip options
+    fn test_save_logs_cycle() {
+        let mut save = SaveLogs::new("container_1".to_owned(), 3);
+        assert_eq!(save.selected(), Some(SaveField::Scope));
+        save.cycle(true);
+        assert_eq!(save.scope, SaveScope::Results);
+        save.cycle(false);
+        save.cycle(false);
+        assert_eq!(save.scope, SaveScope::Range);
+        assert_eq!(save.format.extension(save.gzip), "log");
+
+        save.scroll(&ScrollDirection::Down);
+        save.cycle(false);
+        assert_eq!(save.format, SaveFormat::Ndjson);
+        save.scroll(&ScrollDirection::Down);
+        save.cycle(true);
+        assert_eq!(save.format.extension(save.gzip), "ndjson.gz");
+
+        save.scroll(&ScrollDirection::Down);
+        assert_eq!(save.value(SaveField::Containers), "container_1");
+        save.cycle(true);
+        assert_eq!(
+            save.value(SaveField::Containers),
+            "all 3 in the current filter"
+        );
+    }
+
+    #[test]
+    /// Saving every container in the filter resets the results scope, and cycling the scope skips it
+    fn test_save_logs_cycle_all_skips_results() {
+        let mut save = SaveLogs::new("container_1".to_owned(), 3);
+        save.cycle(true);
+        assert_eq!(save.scope, SaveScope::Results);
+        for _ in 0..3 {
+            save.scroll(&ScrollDirection::Down);
+        }
+        save.cycle(true);
+        assert!(save.all);
+        assert_eq!(save.scope, SaveScope::All);
+
+        for _ in 0..3 {
+            save.scroll(&ScrollDirection::Up);
+        }
+        save.cycle(true);
+        assert_eq!(save.scope, SaveScope::Range);
+        save.cycle(false);
+        assert_eq!(save.scope, SaveScope::All);
+        save.cycle(false);
+        assert_eq!(save.scope, SaveScope::Range);
+    }
+
+    #[test]
+    /// Results only keep the given timestamps, a range keeps lines without a timestamp
+    fn test_save_logs_lines_keeps() {
+        let gen_ts = |s: &str| s.parse::<Timestamp>().ok();
+        let early = gen_ts("2025-02-18T08:00:00Z");
+        let late = gen_ts("2025-02-18T09:00:00Z");
+
+        let results = SaveLines::Results(early.into_iter().collect::<HashSet<_>>());
+        assert!(results.keeps(early));
+        assert!(!results.keeps(late));
+        assert!(!results.keeps(None));
+
+        let range = SaveLines::Range((None, gen_ts("2025-02-18T08:30:00Z")));
+        assert!(range.keeps(early));
+        assert!(!range.keeps(late));
+        assert!(range.keeps(None));
+        assert!(SaveLines::All.keeps(late));
+    }
+}
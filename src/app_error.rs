@@ -4,9 +4,20 @@ use std::fmt;
 /// app errors to set in global state
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AppError {
+    Clipboard,
+    ClipboardSize(usize),
     DockerCommand(DockerCommand),
+    DockerCreate(String),
+    DockerDebug(String),
+    DockerDelete(String),
     DockerExec,
-    DockerLogs,
+    DockerExecTimeout(u64),
+    DockerFiles(String),
+    DockerLimits,
+    DockerNetworks,
+    DockerProcesses(String),
+    DockerPrune(String),
+    DockerRecreate(String),
     DockerConnect,
     IO(String),
     MouseCapture(bool),
@@ -18,9 +29,25 @@ pub enum AppError {
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::Clipboard => write!(f, "Unable to copy to the clipboard"),
+            Self::ClipboardSize(max) => write!(
+                f,
+                "Unable to copy to the clipboard, the selection is larger than the {max} byte limit"
+            ),
             Self::DockerCommand(s) => write!(f, "Unable to {s} container"),
+            Self::DockerCreate(msg) => write!(f, "Unable to {msg}"),
+            Self::DockerDebug(image) => write!(f, "Unable to start debug container using {image}"),
+            Self::DockerDelete(msg) => write!(f, "Unable to delete container: {msg}"),
             Self::DockerExec => write!(f, "Unable to exec into container"),
-            Self::DockerLogs => write!(f, "Unable to save logs"),
+            Self::DockerExecTimeout(secs) => {
+                write!(f, "Command didn't exit within {secs} seconds")
+            }
+            Self::DockerFiles(msg) => write!(f, "Unable to {msg}"),
+            Self::DockerLimits => write!(f, "Unable to get the current limits of the container"),
+            Self::DockerNetworks => write!(f, "Unable to list networks"),
+            Self::DockerProcesses(msg) => write!(f, "Unable to {msg}"),
+            Self::DockerPrune(msg) => write!(f, "Unable to {msg}"),
+            Self::DockerRecreate(msg) => write!(f, "Unable to recreate container, {msg}"),
             Self::DockerConnect => write!(f, "Unable to access docker daemon"),
             Self::IO(msg) => write!(f, "IO error with: {msg}"),
             Self::MouseCapture(x) => {
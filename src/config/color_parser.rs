@@ -207,12 +207,22 @@ impl From<Option<ConfigColors>> for AppColors {
                 Self::map_color(cc.delete.as_deref(), &mut app_colors.commands.start);
                 Self::map_color(cc.resume.as_deref(), &mut app_colors.commands.resume);
                 Self::map_color(cc.start.as_deref(), &mut app_colors.commands.start);
+                Self::map_color(cc.kill.as_deref(), &mut app_colors.commands.kill);
+                Self::map_color(cc.recreate.as_deref(), &mut app_colors.commands.recreate);
+                Self::map_color(cc.rename.as_deref(), &mut app_colors.commands.rename);
+                Self::map_color(cc.commit.as_deref(), &mut app_colors.commands.commit);
             }
 
             // Logs panel
             if let Some(cl) = config_colors.logs {
                 Self::map_color(cl.background.as_deref(), &mut app_colors.logs.background);
                 Self::map_color(cl.text.as_deref(), &mut app_colors.logs.text);
+                Self::map_color(cl.error.as_deref(), &mut app_colors.logs.error);
+                Self::map_color(cl.warn.as_deref(), &mut app_colors.logs.warn);
+                Self::map_color(cl.info.as_deref(), &mut app_colors.logs.info);
+                Self::map_color(cl.debug.as_deref(), &mut app_colors.logs.debug);
+                Self::map_color(cl.trace.as_deref(), &mut app_colors.logs.trace);
+                Self::map_color(cl.stderr.as_deref(), &mut app_colors.logs.stderr);
             }
 
             // Container State
@@ -257,13 +267,13 @@ optional_config_struct!(
     ConfigChartCpu, background, border, order, title, max, points,y_axis;
     ConfigChartMemory, background, border, title, max, points, y_axis;
     ConfigChartPorts, background, border, title, headings, text;
-    ConfigCommands, background, pause, restart, stop, delete, resume, start;
+    ConfigCommands, background, pause, restart, stop, delete, resume, start, kill, recreate, rename, commit;
     ConfigContainers, background, icon, text, text_rx, text_tx;
     ConfigContainerState, background, dead, exited, paused, removing, restarting, running_healthy, running_unhealthy, unknown;
     ConfigFilter, background, text, selected_filter_background, selected_filter_text, highlight;
      ConfigLogSearch, background, text, button_text, highlight;
     ConfigHeadersBar, background, loading_spinner, text, text_selected;
-    ConfigLogs, background, text
+    ConfigLogs, background, text, error, warn, info, debug, trace, stderr
 );
 
 config_struct!(
@@ -273,13 +283,13 @@ config_struct!(
     ChartBandwidth, background, border, max_rx, max_tx, title_rx, title_tx, points_rx, points_tx, y_axis;
 
     ChartPorts, background, border, title, headings, text;
-    Commands, background, pause, restart, stop, delete, resume, start;
+    Commands, background, pause, restart, stop, delete, resume, start, kill, recreate, rename, commit;
     Containers, background, icon, text, text_rx, text_tx;
     ContainerState, dead, exited, paused, removing, restarting, running_healthy, running_unhealthy, unknown;
     Filter, background, text, selected_filter_background, selected_filter_text, highlight;
     LogSearch, background, text, button_text, highlight;
     HeadersBar, background, text_selected, loading_spinner, text;
-    Logs, background, text;
+    Logs, background, text, error, warn, info, debug, trace, stderr;
     PopupDelete, background, text, text_highlight;
     PopupError, background, text;
     PopupHelp, background, text, text_highlight;
@@ -339,6 +349,10 @@ impl Commands {
             delete: Color::Gray,
             resume: Color::Blue,
             start: Color::Green,
+            kill: Color::LightRed,
+            recreate: Color::Magenta,
+            rename: Color::Cyan,
+            commit: Color::LightGreen,
         }
     }
 }
@@ -455,12 +469,18 @@ impl LogSearch {
     }
 }
 
-/// Default colours for the logs panel, only applied if color_logs is false
+/// Default colours for the logs panel, the background & text are only applied if color_logs is false, the level & stderr colours are always applied
 impl Logs {
     const fn new() -> Self {
         Self {
             background: Color::Reset,
             text: Color::Reset,
+            error: Color::Red,
+            warn: Color::Yellow,
+            info: Color::Green,
+            debug: Color::Cyan,
+            trace: Color::DarkGray,
+            stderr: Color::LightRed,
         }
     }
 }
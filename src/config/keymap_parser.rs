@@ -30,6 +30,19 @@ macro_rules! config_struct {
                 )*
                 pub scroll_many: KeyModifiers,
             }
+
+            #[cfg(test)]
+            impl $struct_name {
+                /// Every key, primary & secondary, used by the keymap
+                pub fn keys(&self) -> Vec<KeyCode> {
+                    let mut output = vec![];
+                    $(
+                        output.push(self.$key_name.0);
+                        output.extend(self.$key_name.1);
+                    )*
+                    output
+                }
+            }
         )*
     };
 }
@@ -40,6 +53,29 @@ optional_config_struct!(
     delete_confirm,
     delete_deny,
     exec,
+    exec_command,
+    exec_debug,
+    exec_close,
+    file_browser,
+    file_save_tar,
+    file_upload,
+    filesystem_diff,
+    processes,
+    prune,
+    new_container,
+    update_limits,
+    networks,
+    log_level_filter,
+    log_stream_filter,
+    log_wrap,
+    log_collapse,
+    log_group,
+    log_follow,
+    log_live,
+    log_mark,
+    yank,
+    log_time,
+    log_time_column,
     filter_mode,
     force_redraw,
     inspect,
@@ -77,6 +113,29 @@ config_struct!(
     delete_confirm,
     delete_deny,
     exec,
+    exec_command,
+    exec_debug,
+    exec_close,
+    file_browser,
+    file_save_tar,
+    file_upload,
+    filesystem_diff,
+    processes,
+    prune,
+    new_container,
+    update_limits,
+    networks,
+    log_level_filter,
+    log_stream_filter,
+    log_wrap,
+    log_collapse,
+    log_group,
+    log_follow,
+    log_live,
+    log_mark,
+    yank,
+    log_time,
+    log_time_column,
     filter_mode,
     inspect,
     force_redraw,
@@ -115,6 +174,29 @@ impl Keymap {
             delete_confirm: (KeyCode::Char('y'), None),
             delete_deny: (KeyCode::Char('n'), None),
             exec: (KeyCode::Char('e'), None),
+            exec_command: (KeyCode::Char('x'), None),
+            exec_debug: (KeyCode::Char('d'), None),
+            exec_close: (KeyCode::F(10), None),
+            file_browser: (KeyCode::Char('b'), None),
+            file_save_tar: (KeyCode::Char('t'), None),
+            file_upload: (KeyCode::Char('U'), None),
+            filesystem_diff: (KeyCode::Char('w'), None),
+            processes: (KeyCode::Char('p'), None),
+            prune: (KeyCode::Char('P'), None),
+            new_container: (KeyCode::Char('N'), None),
+            update_limits: (KeyCode::Char('u'), None),
+            networks: (KeyCode::Char('a'), None),
+            log_level_filter: (KeyCode::Char('l'), None),
+            log_stream_filter: (KeyCode::Char('o'), None),
+            log_wrap: (KeyCode::Char('W'), None),
+            log_collapse: (KeyCode::Char('z'), None),
+            log_group: (KeyCode::Char('G'), None),
+            log_follow: (KeyCode::Char('F'), None),
+            log_live: (KeyCode::Char('L'), None),
+            log_mark: (KeyCode::Char('v'), None),
+            yank: (KeyCode::Char('Y'), None),
+            log_time: (KeyCode::Char('g'), None),
+            log_time_column: (KeyCode::Char('T'), None),
             inspect: (KeyCode::Char('i'), None),
             filter_mode: (KeyCode::Char('/'), Some(KeyCode::F(1))),
             force_redraw: (KeyCode::Char('f'), None),
@@ -200,6 +282,37 @@ impl From<Option<ConfigKeymap>> for Keymap {
             );
 
             update_keymap(ck.exec, &mut keymap.exec, &mut clash);
+            update_keymap(ck.exec_command, &mut keymap.exec_command, &mut clash);
+            update_keymap(ck.exec_debug, &mut keymap.exec_debug, &mut clash);
+            update_keymap(ck.exec_close, &mut keymap.exec_close, &mut clash);
+            update_keymap(ck.file_browser, &mut keymap.file_browser, &mut clash);
+            update_keymap(ck.file_save_tar, &mut keymap.file_save_tar, &mut clash);
+            update_keymap(ck.file_upload, &mut keymap.file_upload, &mut clash);
+            update_keymap(ck.filesystem_diff, &mut keymap.filesystem_diff, &mut clash);
+            update_keymap(ck.processes, &mut keymap.processes, &mut clash);
+            update_keymap(ck.prune, &mut keymap.prune, &mut clash);
+            update_keymap(ck.new_container, &mut keymap.new_container, &mut clash);
+            update_keymap(ck.update_limits, &mut keymap.update_limits, &mut clash);
+            update_keymap(ck.networks, &mut keymap.networks, &mut clash);
+            update_keymap(
+                ck.log_level_filter,
+                &mut keymap.log_level_filter,
+                &mut clash,
+            );
+            update_keymap(
+                ck.log_stream_filter,
+                &mut keymap.log_stream_filter,
+                &mut clash,
+            );
+            update_keymap(ck.log_wrap, &mut keymap.log_wrap, &mut clash);
+            update_keymap(ck.log_collapse, &mut keymap.log_collapse, &mut clash);
+            update_keymap(ck.log_group, &mut keymap.log_group, &mut clash);
+            update_keymap(ck.log_follow, &mut keymap.log_follow, &mut clash);
+            update_keymap(ck.log_live, &mut keymap.log_live, &mut clash);
+            update_keymap(ck.log_mark, &mut keymap.log_mark, &mut clash);
+            update_keymap(ck.yank, &mut keymap.yank, &mut clash);
+            update_keymap(ck.log_time, &mut keymap.log_time, &mut clash);
+            update_keymap(ck.log_time_column, &mut keymap.log_time_column, &mut clash);
             update_keymap(ck.filter_mode, &mut keymap.filter_mode, &mut clash);
             update_keymap(ck.force_redraw, &mut keymap.force_redraw, &mut clash);
             update_keymap(ck.quit, &mut keymap.quit, &mut clash);
@@ -338,6 +451,8 @@ impl Keymap {
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
+    use std::collections::HashSet;
+
     use crossterm::event::{KeyCode, KeyModifiers};
 
     use crate::config::keymap_parser::ConfigKeymap;
@@ -384,6 +499,13 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    /// The default keymap doesn't use any key more than once
+    fn test_default_keymap_no_clash() {
+        let keys = Keymap::new().keys();
+        assert_eq!(keys.iter().collect::<HashSet<_>>().len(), keys.len());
+    }
+
     #[test]
     /// If any key definitions clash, just return the default keymap
     fn test_clash_returns_default() {
@@ -392,6 +514,29 @@ mod tests {
             delete_deny: Some(vec!["s".to_owned()]),
             delete_confirm: None,
             exec: None,
+            exec_command: None,
+            exec_debug: None,
+            exec_close: None,
+            file_browser: None,
+            file_save_tar: None,
+            file_upload: None,
+            filesystem_diff: None,
+            processes: None,
+            prune: None,
+            new_container: None,
+            update_limits: None,
+            networks: None,
+            log_level_filter: None,
+            log_stream_filter: None,
+            log_wrap: None,
+            log_collapse: None,
+            log_group: None,
+            log_follow: None,
+            log_live: None,
+            log_mark: None,
+            yank: None,
+            log_time: None,
+            log_time_column: None,
             filter_mode: None,
             force_redraw: None,
             inspect: None,
@@ -439,6 +584,29 @@ mod tests {
             delete_confirm: gen_v(("c", "d")),
             delete_deny: gen_v(("e", "f")),
             exec: gen_v(("g", "h")),
+            exec_command: gen_v(("A", "B")),
+            exec_debug: gen_v(("C", "D")),
+            exec_close: None,
+            file_browser: gen_v(("E", "F")),
+            file_save_tar: None,
+            file_upload: None,
+            filesystem_diff: gen_v(("G", "H")),
+            processes: gen_v(("I", "J")),
+            prune: gen_v(("K", "L")),
+            new_container: gen_v(("M", "N")),
+            update_limits: gen_v(("O", "P")),
+            networks: gen_v(("Q", "R")),
+            log_level_filter: gen_v(("S", "T")),
+            log_stream_filter: gen_v(("U", "V")),
+            log_wrap: gen_v(("W", "X")),
+            log_collapse: gen_v(("BACKSPACE", "DELETE")),
+            log_group: gen_v(("BACKTAB", "INSERT")),
+            log_follow: None,
+            log_live: None,
+            log_mark: gen_v(("Y", "Z")),
+            yank: gen_v(("9", "0")),
+            log_time: gen_v((";", "'")),
+            log_time_column: gen_v(("#", "ESC")),
             filter_mode: gen_v(("i", "j")),
             force_redraw: gen_v(("k", "l")),
             inspect: gen_v(("m", "n")),
@@ -478,6 +646,29 @@ mod tests {
             delete_confirm: (KeyCode::Char('c'), Some(KeyCode::Char('d'))),
             delete_deny: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
             exec: (KeyCode::Char('g'), Some(KeyCode::Char('h'))),
+            exec_command: (KeyCode::Char('A'), Some(KeyCode::Char('B'))),
+            exec_debug: (KeyCode::Char('C'), Some(KeyCode::Char('D'))),
+            exec_close: (KeyCode::F(10), None),
+            file_browser: (KeyCode::Char('E'), Some(KeyCode::Char('F'))),
+            file_save_tar: (KeyCode::Char('t'), None),
+            file_upload: (KeyCode::Char('U'), None),
+            filesystem_diff: (KeyCode::Char('G'), Some(KeyCode::Char('H'))),
+            processes: (KeyCode::Char('I'), Some(KeyCode::Char('J'))),
+            prune: (KeyCode::Char('K'), Some(KeyCode::Char('L'))),
+            new_container: (KeyCode::Char('M'), Some(KeyCode::Char('N'))),
+            update_limits: (KeyCode::Char('O'), Some(KeyCode::Char('P'))),
+            networks: (KeyCode::Char('Q'), Some(KeyCode::Char('R'))),
+            log_level_filter: (KeyCode::Char('S'), Some(KeyCode::Char('T'))),
+            log_stream_filter: (KeyCode::Char('U'), Some(KeyCode::Char('V'))),
+            log_wrap: (KeyCode::Char('W'), Some(KeyCode::Char('X'))),
+            log_collapse: (KeyCode::Backspace, Some(KeyCode::Delete)),
+            log_group: (KeyCode::BackTab, Some(KeyCode::Insert)),
+            log_follow: (KeyCode::Char('F'), None),
+            log_live: (KeyCode::Char('L'), None),
+            log_mark: (KeyCode::Char('Y'), Some(KeyCode::Char('Z'))),
+            yank: (KeyCode::Char('9'), Some(KeyCode::Char('0'))),
+            log_time: (KeyCode::Char(';'), Some(KeyCode::Char('\''))),
+            log_time_column: (KeyCode::Char('#'), Some(KeyCode::Esc)),
             filter_mode: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             force_redraw: (KeyCode::Char('k'), Some(KeyCode::Char('l'))),
             inspect: (KeyCode::Char('i'), None),
@@ -18,42 +18,67 @@ mod parse_config_file;
 pub struct Config {
     pub app_colors: AppColors,
     pub color_logs: bool,
+    pub debug_image: String,
+    pub delete_force: bool,
+    pub delete_link: bool,
+    pub delete_volumes: bool,
     pub docker_interval_ms: u32,
+    pub embedded_exec: bool,
     pub gui: bool,
     pub host: Option<String>,
     pub in_container: bool,
     pub keymap: Keymap,
     pub log_search_case_sensitive: bool,
+    pub log_json_columns: Vec<String>,
+    pub log_stderr_marker: String,
+    pub log_tail: u32,
     pub raw_logs: bool,
+    pub restart_timeout: Option<u16>,
     pub dir_config: Option<PathBuf>,
     pub dir_save: Option<PathBuf>,
     pub show_logs: bool,
     pub show_self: bool,
     pub show_std_err: bool,
     pub show_timestamp: bool,
+    pub stop_timeout: Option<u16>,
     pub timestamp_format: String,
     pub timezone: Option<TimeZone>,
     pub use_cli: bool,
 }
 
+/// The keys of JSON log lines shown as columns, when not set in the config file
+fn default_json_columns() -> Vec<String> {
+    ["level", "msg", "caller"].map(str::to_owned).to_vec()
+}
+
 impl From<&Args> for Config {
     fn from(args: &Args) -> Self {
         Self {
             app_colors: AppColors::new(),
             color_logs: args.color,
+            debug_image: Self::parse_debug_image(None),
+            delete_force: true,
+            delete_link: false,
+            delete_volumes: false,
             docker_interval_ms: args.docker_interval,
+            embedded_exec: false,
             gui: !args.gui,
             host: args.host.clone(),
             in_container: Self::check_if_in_container(),
             keymap: Keymap::new(),
             log_search_case_sensitive: true,
+            log_json_columns: default_json_columns(),
+            log_stderr_marker: String::from("!"),
+            log_tail: 1000,
             raw_logs: args.raw,
+            restart_timeout: None,
             dir_save: Self::try_get_logs_dir(args.save_dir.as_ref()),
             dir_config: args.config_file.as_ref().map(|i| PathBuf::from(&i)),
             show_logs: true,
             show_self: !args.show_self,
             show_std_err: !args.no_std_err,
             show_timestamp: !args.timestamp,
+            stop_timeout: None,
             timestamp_format: Self::parse_timestamp_format(None),
             timezone: Self::parse_timezone(args.timezone.clone()),
             use_cli: args.use_cli,
@@ -66,19 +91,33 @@ impl From<(ConfigFile, Option<PathBuf>)> for Config {
         Self {
             app_colors: AppColors::from(config_file.colors),
             color_logs: config_file.color_logs.unwrap_or(false),
+            debug_image: Self::parse_debug_image(config_file.debug_image),
+            delete_force: config_file.delete_force.unwrap_or(true),
+            delete_link: config_file.delete_link.unwrap_or(false),
+            delete_volumes: config_file.delete_volumes.unwrap_or(false),
             docker_interval_ms: config_file.docker_interval.unwrap_or(1000),
             dir_config: dir,
+            embedded_exec: config_file.embedded_exec.unwrap_or(false),
             gui: config_file.gui.unwrap_or(true),
             host: config_file.host,
             in_container: Self::check_if_in_container(),
             keymap: Keymap::from(config_file.keymap),
             log_search_case_sensitive: config_file.log_search_case_sensitive.unwrap_or(true),
+            log_json_columns: config_file
+                .log_json_columns
+                .unwrap_or_else(default_json_columns),
+            log_stderr_marker: config_file
+                .log_stderr_marker
+                .unwrap_or_else(|| String::from("!")),
+            log_tail: config_file.log_tail.unwrap_or(1000),
             raw_logs: config_file.raw_logs.unwrap_or(false),
+            restart_timeout: config_file.restart_timeout,
             dir_save: Self::try_get_logs_dir(config_file.save_dir.as_ref()),
             show_logs: config_file.show_logs.unwrap_or(true),
             show_self: config_file.show_self.unwrap_or(false),
             show_std_err: config_file.show_std_err.unwrap_or(true),
             show_timestamp: config_file.show_timestamp.unwrap_or(true),
+            stop_timeout: config_file.stop_timeout,
             timestamp_format: Self::parse_timestamp_format(config_file.timestamp_format),
             timezone: Self::parse_timezone(config_file.timezone),
             use_cli: config_file.use_cli.unwrap_or(false),
@@ -101,6 +140,14 @@ impl Config {
         })
     }
 
+    /// The image used for debug containers, defaults to busybox if not set or empty
+    fn parse_debug_image(input: Option<String>) -> String {
+        input
+            .map(|i| i.trim().to_owned())
+            .filter(|i| !i.is_empty())
+            .unwrap_or_else(|| "busybox".to_owned())
+    }
+
     /// Attempt to parse a timezone into a jiff::tz::TimeZone
     /// Also return a format to display the timesampt in
     fn parse_timezone(input: Option<String>) -> Option<TimeZone> {
@@ -231,6 +278,20 @@ impl Config {
 mod tests {
     use jiff::tz::TimeZone;
 
+    /// Debug image defaults to busybox when missing or empty
+    #[test]
+    fn test_config_parse_debug_image() {
+        assert_eq!(super::Config::parse_debug_image(None), "busybox");
+        assert_eq!(
+            super::Config::parse_debug_image(Some("  ".to_owned())),
+            "busybox"
+        );
+        assert_eq!(
+            super::Config::parse_debug_image(Some(" nicolaka/netshoot ".to_owned())),
+            "nicolaka/netshoot"
+        );
+    }
+
     /// Test the basic timestamp_format parsing/checker function
     #[test]
     fn test_config_parse_timestamp_format() {
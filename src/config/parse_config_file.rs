@@ -63,17 +63,27 @@ impl ConfigFileFormat {
 pub struct ConfigFile {
     pub color_logs: Option<bool>,
     pub colors: Option<ConfigColors>,
+    pub debug_image: Option<String>,
+    pub delete_force: Option<bool>,
+    pub delete_link: Option<bool>,
+    pub delete_volumes: Option<bool>,
     pub docker_interval: Option<u32>,
+    pub embedded_exec: Option<bool>,
     pub gui: Option<bool>,
     pub host: Option<String>,
     pub keymap: Option<ConfigKeymap>,
     pub log_search_case_sensitive: Option<bool>,
+    pub log_json_columns: Option<Vec<String>>,
+    pub log_stderr_marker: Option<String>,
+    pub log_tail: Option<u32>,
     pub raw_logs: Option<bool>,
+    pub restart_timeout: Option<u16>,
     pub save_dir: Option<String>,
     pub show_logs: Option<bool>,
     pub show_self: Option<bool>,
     pub show_std_err: Option<bool>,
     pub show_timestamp: Option<bool>,
+    pub stop_timeout: Option<u16>,
     pub timestamp_format: Option<String>,
     pub timezone: Option<String>,
     pub use_cli: Option<bool>,
@@ -173,6 +183,8 @@ impl ConfigFile {
 #[allow(clippy::unwrap_used)]
 mod tests {
 
+    use crossterm::event::KeyCode;
+
     use crate::config::{AppColors, Keymap};
 
     use super::ConfigFile;
@@ -221,6 +233,34 @@ mod tests {
         assert_eq!(result_toml, result_jsonc);
     }
 
+    #[test]
+    /// The example configs don't contain any key clashes, a clash would silently revert every key to the default keymap,
+    /// so a single key is changed to an unused key, which is only kept if the rest of the keymap is valid
+    fn test_parse_config_keymap_no_clash() {
+        let configs = [
+            (
+                super::ConfigFileFormat::Toml,
+                include_str!("./config.toml").replace(r#"quit = ["q"]"#, r#"quit = ["Q"]"#),
+            ),
+            (
+                super::ConfigFileFormat::Toml,
+                include_str!("../../example_config/example.config.toml")
+                    .replace(r#"quit = ["q"]"#, r#"quit = ["Q"]"#),
+            ),
+            (
+                super::ConfigFileFormat::Jsonc,
+                include_str!("../../example_config/example.config.jsonc")
+                    .replace("\"quit\": [\n\t\t\t\"q\"", "\"quit\": [\n\t\t\t\"Q\""),
+            ),
+        ];
+        for (format, input) in configs {
+            let result = ConfigFile::parse(format, &input).unwrap();
+            let keymap = Keymap::from(result.keymap);
+            assert_ne!(keymap, Keymap::new());
+            assert_eq!(keymap.quit, (KeyCode::Char('Q'), None));
+        }
+    }
+
     #[test]
     /// make sure config.toml matches the default app colors
     fn test_parse_config_colors_toml() {
@@ -0,0 +1,365 @@
+use std::io::{self, Read, Take};
+
+/// Size of a tar header, and of every block in a tar archive
+const BLOCK: usize = 512;
+
+/// Maximum size of a GNU long name, or PAX header, read when streaming an archive
+const LONG_NAME_MAX: u64 = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryKind {
+    Dir,
+    File,
+    Symlink,
+    Other,
+}
+
+/// A single entry of a tar archive, data is only non-empty for regular files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveEntry<'a> {
+    pub path: String,
+    pub kind: EntryKind,
+    pub size: usize,
+    pub data: &'a [u8],
+}
+
+/// Read a nul terminated string from a header field
+fn read_str(field: &[u8]) -> String {
+    let end = field.iter().position(|i| *i == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+/// Read a number from a header field, either octal, or base-256 if the high bit is set
+fn read_number(field: &[u8]) -> usize {
+    if field.first().is_some_and(|i| i & 0x80 != 0) {
+        field.iter().skip(1).fold(0usize, |acc, i| {
+            acc.saturating_mul(256).saturating_add(usize::from(*i))
+        })
+    } else {
+        let text = read_str(field);
+        usize::from_str_radix(text.trim_matches([' ', '\0']), 8).unwrap_or_default()
+    }
+}
+
+/// Get the path value from a PAX extended header, each record is in the format `<length> <key>=<value>\n`
+fn pax_path(data: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(data);
+    text.lines()
+        .filter_map(|line| line.split_once(' ').map(|(_, record)| record))
+        .find_map(|record| record.strip_prefix("path=").map(ToOwned::to_owned))
+}
+
+/// Round up to the nearest block size
+const fn padded(size: usize) -> usize {
+    size.div_ceil(BLOCK) * BLOCK
+}
+
+/// The path of an entry, the long name, from a previous GNU long name or PAX entry, is used if set, else the name & ustar prefix of the header
+fn header_path(header: &[u8], long_name: Option<String>) -> String {
+    long_name.unwrap_or_else(|| {
+        let name = read_str(&header[0..100]);
+        let prefix = if &header[257..262] == b"ustar" {
+            read_str(&header[345..500])
+        } else {
+            String::new()
+        };
+        if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}/{name}")
+        }
+    })
+}
+
+const fn header_kind(header: &[u8]) -> EntryKind {
+    match header[156] {
+        b'0' | b'\0' | b'7' => EntryKind::File,
+        b'5' => EntryKind::Dir,
+        b'2' => EntryKind::Symlink,
+        _ => EntryKind::Other,
+    }
+}
+
+/// Parse every entry from a tar archive, as produced by the Docker archive API
+/// Handles ustar prefixes, GNU long names, and PAX path records, a truncated archive will return all the complete entries
+pub fn entries(bytes: &[u8]) -> Vec<ArchiveEntry<'_>> {
+    let mut output = vec![];
+    let mut offset = 0;
+    let mut long_name = None;
+
+    while let Some(header) = bytes.get(offset..offset + BLOCK) {
+        if header.iter().all(|i| *i == 0) {
+            break;
+        }
+        let size = read_number(&header[124..136]);
+        let data_start = offset + BLOCK;
+        let Some(data) = data_start
+            .checked_add(size)
+            .and_then(|data_end| bytes.get(data_start..data_end))
+        else {
+            break;
+        };
+        offset = data_start + padded(size);
+
+        match header[156] {
+            b'x' => {
+                long_name = pax_path(data);
+                continue;
+            }
+            b'L' => {
+                long_name = Some(read_str(data));
+                continue;
+            }
+            b'g' => continue,
+            _ => (),
+        }
+
+        let kind = header_kind(header);
+        output.push(ArchiveEntry {
+            path: header_path(header, long_name.take()),
+            kind,
+            size,
+            data: if kind == EntryKind::File { data } else { &[] },
+        });
+    }
+    output
+}
+
+/// Read every entry of a tar archive from a reader, without holding the whole archive in memory
+/// `f` is called with the path & kind of each entry, and a reader limited to the data of the entry, any data `f` doesn't read is skipped
+/// A truncated archive stops at the first incomplete header
+pub fn read_entries<R: Read>(
+    mut reader: R,
+    mut f: impl FnMut(&str, EntryKind, &mut Take<&mut R>) -> io::Result<()>,
+) -> io::Result<()> {
+    let mut header = [0u8; BLOCK];
+    let mut long_name = None;
+    loop {
+        match reader.read_exact(&mut header) {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(e),
+        }
+        if header.iter().all(|i| *i == 0) {
+            break;
+        }
+        let size = u64::try_from(read_number(&header[124..136])).unwrap_or(u64::MAX);
+        let mut data = reader.by_ref().take(size);
+        match header[156] {
+            b'x' | b'L' => {
+                let mut buf = vec![];
+                data.by_ref().take(LONG_NAME_MAX).read_to_end(&mut buf)?;
+                long_name = if header[156] == b'x' {
+                    pax_path(&buf)
+                } else {
+                    Some(read_str(&buf))
+                };
+            }
+            b'g' => (),
+            _ => f(
+                &header_path(&header, long_name.take()),
+                header_kind(&header),
+                &mut data,
+            )?,
+        }
+        io::copy(&mut data, &mut io::sink())?;
+        let padding = (BLOCK as u64 - size % BLOCK as u64) % BLOCK as u64;
+        io::copy(&mut reader.by_ref().take(padding), &mut io::sink())?;
+    }
+    Ok(())
+}
+
+/// Create the header of a single file, the name must be less than 100 bytes, and the size must fit in the octal size field
+pub fn file_header(name: &str, size: u64, mode: u32) -> Option<[u8; BLOCK]> {
+    if name.is_empty() || name.len() >= 100 || size >= 8u64.pow(11) {
+        return None;
+    }
+    let mut header = [0u8; BLOCK];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    let mut write_octal = |start: usize, len: usize, value: u64| {
+        let text = format!("{value:0width$o}\0", width = len - 1);
+        header[start..start + len].copy_from_slice(text.as_bytes());
+    };
+    write_octal(100, 8, u64::from(mode & 0o7777));
+    write_octal(108, 8, 0);
+    write_octal(116, 8, 0);
+    write_octal(124, 12, size);
+    write_octal(
+        136,
+        12,
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs()),
+    );
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    // The checksum is calculated with the checksum field set to spaces
+    header[148..156].copy_from_slice(b"        ");
+    let checksum = header.iter().map(|i| usize::from(*i)).sum::<usize>();
+    header[148..156].copy_from_slice(format!("{checksum:06o}\0 ").as_bytes());
+    Some(header)
+}
+
+/// The padding after the data of a single file, up to a whole block, followed by the two empty blocks that end an archive
+pub fn file_trailer(size: u64) -> Vec<u8> {
+    let remainder = usize::try_from(size % BLOCK as u64).unwrap_or_default();
+    vec![0; (BLOCK - remainder) % BLOCK + BLOCK * 2]
+}
+
+/// Create a tar archive containing a single file
+#[cfg(test)]
+pub fn single_file(name: &str, data: &[u8], mode: u32) -> Option<Vec<u8>> {
+    let size = u64::try_from(data.len()).ok()?;
+    let mut output = file_header(name, size, mode)?.to_vec();
+    output.extend_from_slice(data);
+    output.extend(file_trailer(size));
+    Some(output)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::io::Read;
+
+    use super::{ArchiveEntry, BLOCK, EntryKind, entries, read_entries, single_file};
+
+    /// Generate a basic header, with a valid size & typeflag, checksum isn't checked when reading
+    fn gen_header(name: &str, size: usize, typeflag: u8) -> Vec<u8> {
+        let mut header = vec![0u8; BLOCK];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        header[124..136].copy_from_slice(format!("{size:011o}\0").as_bytes());
+        header[156] = typeflag;
+        header
+    }
+
+    fn gen_entry(name: &str, data: &[u8], typeflag: u8) -> Vec<u8> {
+        let mut output = gen_header(name, data.len(), typeflag);
+        output.extend_from_slice(data);
+        output.resize(output.len().div_ceil(BLOCK) * BLOCK, 0);
+        output
+    }
+
+    #[test]
+    /// A single file archive can be read back, and has a valid checksum
+    fn test_archive_single_file() {
+        let archive = single_file("hello.txt", b"hello world", 0o644).unwrap();
+        assert_eq!(archive.len(), BLOCK * 4);
+
+        let header = &archive[..BLOCK];
+        let stored =
+            usize::from_str_radix(std::str::from_utf8(&header[148..154]).unwrap().trim(), 8)
+                .unwrap();
+        let mut blank = header.to_vec();
+        blank[148..156].copy_from_slice(b"        ");
+        assert_eq!(stored, blank.iter().map(|i| usize::from(*i)).sum::<usize>());
+
+        assert_eq!(
+            entries(&archive),
+            vec![ArchiveEntry {
+                path: "hello.txt".to_owned(),
+                kind: EntryKind::File,
+                size: 11,
+                data: b"hello world",
+            }]
+        );
+        assert!(single_file("", b"", 0o644).is_none());
+        assert!(single_file(&"a".repeat(100), b"", 0o644).is_none());
+    }
+
+    #[test]
+    /// Directories, symlinks, GNU long names, & PAX paths, are all parsed
+    fn test_archive_entries() {
+        let long = format!("app/{}", "b".repeat(120));
+        let mut archive = gen_entry("app/", b"", b'5');
+        archive.extend(gen_entry("app/link", b"", b'2'));
+        archive.extend(gen_entry("././@LongLink", long.as_bytes(), b'L'));
+        archive.extend(gen_entry("ignored", b"long", b'0'));
+        let record = "path=app/pax.txt\n";
+        let pax = format!("{} {record}", record.len() + 3);
+        archive.extend(gen_entry("PaxHeaders/pax", pax.as_bytes(), b'x'));
+        archive.extend(gen_entry("ignored", b"pax", b'0'));
+        archive.extend(vec![0; BLOCK * 2]);
+
+        let result = entries(&archive);
+        assert_eq!(
+            result
+                .iter()
+                .map(|i| (i.path.as_str(), i.kind, i.data))
+                .collect::<Vec<_>>(),
+            vec![
+                ("app/", EntryKind::Dir, &b""[..]),
+                ("app/link", EntryKind::Symlink, &b""[..]),
+                (long.as_str(), EntryKind::File, &b"long"[..]),
+                ("app/pax.txt", EntryKind::File, &b"pax"[..]),
+            ]
+        );
+    }
+
+    #[test]
+    /// A truncated archive returns only the complete entries
+    fn test_archive_truncated() {
+        let mut archive = gen_entry("a.txt", b"a", b'0');
+        archive.extend(gen_entry("b.txt", &[1; 1000], b'0'));
+        archive.truncate(BLOCK * 3);
+        let result = entries(&archive);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].path, "a.txt");
+    }
+
+    #[test]
+    /// A huge base-256 size, as found in a corrupt or hostile archive, doesn't overflow
+    fn test_archive_size_overflow() {
+        let mut archive = gen_header("huge", 0, b'0');
+        archive[124] = 0x80;
+        archive[125..136].fill(0xff);
+        archive.extend(vec![0; BLOCK]);
+        assert!(entries(&archive).is_empty());
+
+        let mut result = vec![];
+        read_entries(archive.as_slice(), |path, _, _| {
+            result.push(path.to_owned());
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(result, ["huge"]);
+    }
+
+    #[test]
+    /// Streaming an archive gives the same entries as parsing it in memory, data that isn't read is skipped
+    fn test_archive_read_entries() {
+        let long = format!("app/{}", "b".repeat(120));
+        let mut archive = gen_entry("app/", b"", b'5');
+        archive.extend(gen_entry("app/skipped", &[1; 1000], b'0'));
+        archive.extend(gen_entry("././@LongLink", long.as_bytes(), b'L'));
+        archive.extend(gen_entry("ignored", b"long", b'0'));
+        archive.extend(gen_entry("app/partial", b"partial", b'0'));
+        archive.extend(vec![0; BLOCK * 2]);
+
+        let mut result = vec![];
+        read_entries(archive.as_slice(), |path, kind, data| {
+            let mut buf = vec![];
+            match path {
+                "app/skipped" => (),
+                "app/partial" => {
+                    data.take(4).read_to_end(&mut buf)?;
+                }
+                _ => {
+                    data.read_to_end(&mut buf)?;
+                }
+            }
+            result.push((path.to_owned(), kind, buf));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            result,
+            vec![
+                ("app/".to_owned(), EntryKind::Dir, vec![]),
+                ("app/skipped".to_owned(), EntryKind::File, vec![]),
+                (long, EntryKind::File, b"long".to_vec()),
+                ("app/partial".to_owned(), EntryKind::File, b"part".to_vec()),
+            ]
+        );
+    }
+}
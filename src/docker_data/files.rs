@@ -0,0 +1,399 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufReader, BufWriter, Read, Write},
+    path::{Component, Path, PathBuf},
+};
+
+use bollard::{
+    Docker,
+    exec::{CreateExecOptions, StartExecResults},
+    query_parameters::{DownloadFromContainerOptions, UploadToContainerOptions},
+};
+use bytes::Bytes;
+use futures_util::{StreamExt, stream};
+use tokio::io::AsyncReadExt;
+use tokio_util::io::ReaderStream;
+
+use super::archive::{self, ArchiveEntry, EntryKind};
+use crate::{
+    app_data::{ContainerId, FileEntry, FileKind},
+    app_error::AppError,
+};
+
+/// Maximum archive size read when listing a directory without `ls`, as the archive contains every descendant of the directory
+const LIST_MAX: usize = 64 * 1024 * 1024;
+
+/// Maximum file size that will be previewed
+const PREVIEW_MAX: usize = 64 * 1024;
+
+/// Exit codes returned when the command can't be found, or can't be executed
+pub(super) const NOT_FOUND: [i64; 2] = [126, 127];
+
+/// Current time as unix timestamp, used in the names of saved files
+pub(super) fn now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |i| i.as_secs())
+}
+
+/// The final component of a path, or `root` for `/`
+fn base_name(path: &str) -> &str {
+    path.trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .filter(|i| !i.is_empty())
+        .unwrap_or("root")
+}
+
+/// Download the archive of a path, stopping once the limit has been exceeded
+/// Returns the bytes, and true if the limit was exceeded
+async fn download(
+    docker: &Docker,
+    id: &ContainerId,
+    path: &str,
+    limit: usize,
+) -> Result<(Vec<u8>, bool), AppError> {
+    let mut stream = docker.download_from_container(
+        id.get(),
+        Some(DownloadFromContainerOptions {
+            path: path.to_owned(),
+        }),
+    );
+    let mut output = vec![];
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|_| AppError::DockerFiles(format!("read {path}")))?;
+        output.extend_from_slice(&chunk);
+        if output.len() > limit {
+            return Ok((output, true));
+        }
+    }
+    Ok((output, false))
+}
+
+/// Download the archive of a path, writing each chunk to the output as it arrives, rather than holding the whole archive in memory
+async fn download_to(
+    docker: &Docker,
+    id: &ContainerId,
+    path: &str,
+    output: &mut impl Write,
+) -> Result<(), AppError> {
+    let mut stream = docker.download_from_container(
+        id.get(),
+        Some(DownloadFromContainerOptions {
+            path: path.to_owned(),
+        }),
+    );
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|_| AppError::DockerFiles(format!("read {path}")))?;
+        output
+            .write_all(&chunk)
+            .map_err(|e| AppError::IO(e.to_string()))?;
+    }
+    output.flush().map_err(|e| AppError::IO(e.to_string()))
+}
+
+/// List a directory using `ls`, returns None if `ls` isn't available, or the container isn't running
+async fn exec_ls(
+    docker: &Docker,
+    id: &ContainerId,
+    path: &str,
+) -> Result<Option<Vec<FileEntry>>, AppError> {
+    let Ok(exec) = docker
+        .create_exec(
+            id.get(),
+            CreateExecOptions {
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                tty: Some(false),
+                cmd: Some(vec!["ls", "-1Ap", "--", path]),
+                ..Default::default()
+            },
+        )
+        .await
+    else {
+        return Ok(None);
+    };
+    let mut output = String::new();
+    if let Ok(StartExecResults::Attached {
+        output: mut stream, ..
+    }) = docker.start_exec(&exec.id, None).await
+    {
+        while let Some(Ok(msg)) = stream.next().await {
+            output.push_str(&msg.to_string());
+        }
+    } else {
+        return Ok(None);
+    }
+    match docker.inspect_exec(&exec.id).await.map(|i| i.exit_code) {
+        Ok(Some(0)) => Ok(Some(crate::app_data::FileBrowser::parse_ls(&output))),
+        Ok(Some(code)) if !NOT_FOUND.contains(&code) => Err(AppError::DockerFiles(format!(
+            "list {path}: {}",
+            output.lines().next().unwrap_or_default()
+        ))),
+        _ => Ok(None),
+    }
+}
+
+/// Calculate the direct children of a directory from its archive, the entries are all prefixed with the directory name
+fn archive_children(path: &str, entries: &[ArchiveEntry]) -> Vec<FileEntry> {
+    let base = path
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or_default();
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let entry_path = entry.path.trim_start_matches("./");
+            let rest = if base.is_empty() {
+                entry_path
+            } else {
+                entry_path.strip_prefix(base)?.strip_prefix('/')?
+            };
+            let rest = rest.trim_end_matches('/');
+            if rest.is_empty() {
+                return None;
+            }
+            Some(match rest.split_once('/') {
+                Some((dir, _)) => FileEntry::new(dir, FileKind::Dir),
+                None => FileEntry::new(
+                    rest,
+                    match entry.kind {
+                        EntryKind::Dir => FileKind::Dir,
+                        EntryKind::Symlink => FileKind::Symlink,
+                        EntryKind::File | EntryKind::Other => FileKind::File,
+                    },
+                ),
+            })
+        })
+        .collect()
+}
+
+/// List the contents of a directory, first by using `ls`, and if unavailable, via the archive api
+/// The archive api works on stopped containers, and on images without a shell or `ls`
+/// Returns true if the archive was larger than the max list size, in which case only some of the entries are listed
+pub async fn list(
+    docker: &Docker,
+    id: &ContainerId,
+    path: &str,
+) -> Result<(Vec<FileEntry>, bool), AppError> {
+    if let Some(entries) = exec_ls(docker, id, path).await? {
+        return Ok((entries, false));
+    }
+    let (bytes, truncated) = download(docker, id, path, LIST_MAX).await?;
+    Ok((archive_children(path, &archive::entries(&bytes)), truncated))
+}
+
+/// Generate the preview text of a file, binary & large files aren't shown
+pub async fn preview(docker: &Docker, id: &ContainerId, path: &str) -> Result<String, AppError> {
+    let (bytes, exceeded) = download(docker, id, path, PREVIEW_MAX + 4096).await?;
+    let entries = archive::entries(&bytes);
+    let file = entries.iter().find(|i| i.kind == EntryKind::File);
+    Ok(match file {
+        Some(file) if file.size <= PREVIEW_MAX => {
+            if file.data.contains(&0) {
+                format!("binary file, {} bytes", file.size)
+            } else {
+                String::from_utf8_lossy(file.data).into_owned()
+            }
+        }
+        _ if exceeded => format!("file larger than {PREVIEW_MAX} bytes, unable to preview"),
+        _ => "not a regular file".to_owned(),
+    })
+}
+
+/// Convert an archive path into a relative path, rejecting any absolute or parent components
+fn safe_path(path: &str) -> Option<PathBuf> {
+    let path = Path::new(path);
+    path.components()
+        .all(|i| matches!(i, Component::Normal(_) | Component::CurDir))
+        .then(|| path.to_owned())
+}
+
+/// Extract every directory & regular file of an archive into the given directory, symlinks & other entry types are skipped
+fn extract(reader: impl Read, dir: &Path) -> Result<(), std::io::Error> {
+    std::fs::create_dir_all(dir)?;
+    archive::read_entries(reader, |entry_path, kind, data| {
+        let Some(path) = safe_path(entry_path).map(|i| dir.join(i)) else {
+            return Ok(());
+        };
+        match kind {
+            EntryKind::Dir => std::fs::create_dir_all(&path)?,
+            EntryKind::File => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::io::copy(data, &mut BufWriter::new(File::create(&path)?))?;
+            }
+            EntryKind::Symlink | EntryKind::Other => (),
+        }
+        Ok(())
+    })
+}
+
+/// Create, or truncate, a file for writing
+fn create(path: &Path) -> Result<BufWriter<File>, AppError> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+        .map(BufWriter::new)
+        .map_err(|e| AppError::IO(e.to_string()))
+}
+
+/// Save a file or directory into the save directory, either as a `[container_name]_[file_name]_[timestamp].tar` archive,
+/// or extracted into a `[container_name]_[file_name]_[timestamp]` directory
+/// The archive is streamed to disk, when extracting it is first written to a hidden temporary archive, which is removed once extracted
+pub async fn save(
+    docker: &Docker,
+    id: &ContainerId,
+    path: &str,
+    dir_save: &Path,
+    name: &str,
+    as_tar: bool,
+) -> Result<PathBuf, AppError> {
+    let io_err = |e: std::io::Error| AppError::IO(e.to_string());
+    let stem = format!("{name}_{}_{}", base_name(path), now());
+    if as_tar {
+        let output = dir_save.join(format!("{stem}.tar"));
+        download_to(docker, id, path, &mut create(&output)?).await?;
+        Ok(output)
+    } else {
+        let output = dir_save.join(&stem);
+        let temp = dir_save.join(format!(".{stem}.tar"));
+        let result = match download_to(docker, id, path, &mut create(&temp)?).await {
+            Ok(()) => File::open(&temp)
+                .and_then(|file| extract(BufReader::new(file), &output))
+                .map_err(io_err),
+            Err(e) => Err(e),
+        };
+        std::fs::remove_file(&temp).ok();
+        result.map(|()| output)
+    }
+}
+
+/// The permissions of a local file, systems without unix permissions always use 0o644
+#[cfg(unix)]
+fn file_mode(metadata: &std::fs::Metadata) -> u32 {
+    std::os::unix::fs::PermissionsExt::mode(&metadata.permissions())
+}
+
+#[cfg(not(unix))]
+const fn file_mode(_: &std::fs::Metadata) -> u32 {
+    0o644
+}
+
+/// Upload a single local file into a directory in the container, keeping the permissions of the local file
+/// The file is streamed into the archive, rather than read into memory, capped at the size written in the header
+pub async fn upload(
+    docker: &Docker,
+    id: &ContainerId,
+    dir: &str,
+    local: &Path,
+) -> Result<(), AppError> {
+    let io_err = |e: std::io::Error| AppError::IO(e.to_string());
+    let file = tokio::fs::File::open(local).await.map_err(io_err)?;
+    let metadata = file.metadata().await.map_err(io_err)?;
+    let file_name = local
+        .file_name()
+        .map(|i| i.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let size = metadata.len();
+    let header = archive::file_header(&file_name, size, file_mode(&metadata))
+        .ok_or_else(|| AppError::DockerFiles(format!("upload {}", local.display())))?;
+    let body = stream::iter([Ok(Bytes::copy_from_slice(&header))])
+        .chain(ReaderStream::new(file.take(size)))
+        .chain(stream::iter([Ok(Bytes::from(archive::file_trailer(size)))]));
+    docker
+        .upload_to_container(
+            id.get(),
+            Some(UploadToContainerOptions {
+                path: dir.to_owned(),
+                ..Default::default()
+            }),
+            bollard::body_try_stream(body),
+        )
+        .await
+        .map_err(|_| AppError::DockerFiles(format!("upload {}", local.display())))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{archive_children, base_name, extract, safe_path};
+    use crate::{
+        app_data::{FileEntry, FileKind},
+        docker_data::archive::{ArchiveEntry, EntryKind},
+    };
+
+    fn gen_entry(path: &str, kind: EntryKind) -> ArchiveEntry<'static> {
+        ArchiveEntry {
+            path: path.to_owned(),
+            kind,
+            size: 0,
+            data: &[],
+        }
+    }
+
+    #[test]
+    /// Only the direct children of the directory are returned, with implied directories
+    fn test_files_archive_children() {
+        let entries = [
+            gen_entry("etc/", EntryKind::Dir),
+            gen_entry("etc/hosts", EntryKind::File),
+            gen_entry("etc/ssl/certs/ca.pem", EntryKind::File),
+            gen_entry("etc/localtime", EntryKind::Symlink),
+            gen_entry("other/file", EntryKind::File),
+        ];
+        assert_eq!(
+            archive_children("/etc", &entries),
+            vec![
+                FileEntry::new("hosts", FileKind::File),
+                FileEntry::new("ssl", FileKind::Dir),
+                FileEntry::new("localtime", FileKind::Symlink),
+            ]
+        );
+
+        let entries = [
+            gen_entry("./", EntryKind::Dir),
+            gen_entry("./bin/sh", EntryKind::File),
+            gen_entry(".dockerenv", EntryKind::File),
+        ];
+        assert_eq!(
+            archive_children("/", &entries),
+            vec![
+                FileEntry::new("bin", FileKind::Dir),
+                FileEntry::new(".dockerenv", FileKind::File),
+            ]
+        );
+    }
+
+    #[test]
+    /// Absolute & parent paths are rejected
+    fn test_files_safe_path() {
+        assert_eq!(safe_path("etc/hosts"), Some(PathBuf::from("etc/hosts")));
+        assert_eq!(safe_path("./etc"), Some(PathBuf::from("./etc")));
+        assert!(safe_path("/etc/hosts").is_none());
+        assert!(safe_path("etc/../../hosts").is_none());
+        assert_eq!(base_name("/etc/ssl/"), "ssl");
+        assert_eq!(base_name("/"), "root");
+    }
+
+    #[test]
+    /// Directories & files are extracted, unsafe paths are skipped
+    fn test_files_extract() {
+        let dir = std::env::temp_dir().join(format!("oxker_extract_{}", uuid::Uuid::new_v4()));
+        let mut archive =
+            crate::docker_data::archive::single_file("hosts", b"127.0.0.1", 0o644).unwrap();
+        archive.truncate(1024);
+        archive.extend(crate::docker_data::archive::single_file("../evil", b"x", 0o644).unwrap());
+        extract(archive.as_slice(), &dir).unwrap();
+        assert_eq!(std::fs::read(dir.join("hosts")).unwrap(), b"127.0.0.1");
+        assert!(!dir.with_file_name("evil").exists());
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
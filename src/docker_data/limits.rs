@@ -0,0 +1,105 @@
+use bollard::{
+    Docker,
+    models::{ContainerUpdateBody, HostConfig, RestartPolicy},
+    query_parameters::InspectContainerOptions,
+};
+
+use super::new_container::{daemon_message, restart_policy};
+use crate::{
+    app_data::{CPU_PERIOD, ContainerId, LimitsSpec},
+    app_error::AppError,
+};
+
+/// Build the update config from the validated form, a container that already uses a cpu quota keeps using one, as Docker won't accept both a quota and nano cpus
+/// The maximum retry count is sent back, as Docker resets it whenever the restart policy is updated
+fn update_body(spec: &LimitsSpec) -> ContainerUpdateBody {
+    let (nano_cpus, cpu_period, cpu_quota) = match spec.nano_cpus {
+        Some(nano_cpus) if spec.cpu_quota => (
+            None,
+            Some(CPU_PERIOD),
+            Some(nano_cpus * CPU_PERIOD / 1_000_000_000),
+        ),
+        nano_cpus => (nano_cpus, None, None),
+    };
+    ContainerUpdateBody {
+        cpu_period,
+        cpu_quota,
+        cpu_shares: spec.cpu_shares,
+        cpuset_cpus: spec.cpuset.clone(),
+        memory: spec.memory,
+        memory_swap: spec.memory_swap,
+        nano_cpus,
+        pids_limit: spec.pids_limit,
+        restart_policy: Some(RestartPolicy {
+            name: Some(restart_policy(&spec.restart)),
+            maximum_retry_count: spec.max_retry,
+        }),
+        ..Default::default()
+    }
+}
+
+/// The host config of a container, which contains its current limits
+pub async fn host_config(docker: &Docker, id: &ContainerId) -> Result<HostConfig, AppError> {
+    docker
+        .inspect_container(id.get(), None::<InspectContainerOptions>)
+        .await
+        .map_err(|_| AppError::DockerLimits)
+        .map(|i| i.host_config.unwrap_or_default())
+}
+
+/// Apply the new limits to a container
+/// Returns the daemon's message on error, so that it can be shown in the form
+pub async fn update(docker: &Docker, id: &ContainerId, spec: &LimitsSpec) -> Result<(), String> {
+    docker
+        .update_container(id.get(), update_body(spec))
+        .await
+        .map_err(daemon_message)
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::models::{RestartPolicy, RestartPolicyNameEnum};
+
+    use super::update_body;
+    use crate::app_data::LimitsSpec;
+
+    fn gen_spec(cpu_quota: bool) -> LimitsSpec {
+        LimitsSpec {
+            cpu_quota,
+            cpu_shares: None,
+            cpuset: Some("0-1".to_owned()),
+            max_retry: Some(3),
+            memory: Some(1024 * 1024 * 1024),
+            memory_swap: Some(-1),
+            nano_cpus: Some(1_500_000_000),
+            pids_limit: Some(-1),
+            restart: "on-failure".to_owned(),
+        }
+    }
+
+    #[test]
+    /// Cpus are sent as nano cpus, unless the container already uses a cpu quota, and empty fields are left unset
+    fn test_limits_update_body() {
+        let result = update_body(&gen_spec(false));
+        assert_eq!(result.nano_cpus, Some(1_500_000_000));
+        assert_eq!(result.cpu_quota, None);
+        assert_eq!(result.cpu_period, None);
+        assert_eq!(result.cpu_shares, None);
+        assert_eq!(result.cpuset_cpus, Some("0-1".to_owned()));
+        assert_eq!(result.memory, Some(1024 * 1024 * 1024));
+        assert_eq!(result.memory_swap, Some(-1));
+        assert_eq!(result.pids_limit, Some(-1));
+        assert_eq!(
+            result.restart_policy,
+            Some(RestartPolicy {
+                name: Some(RestartPolicyNameEnum::ON_FAILURE),
+                maximum_retry_count: Some(3),
+            })
+        );
+
+        let result = update_body(&gen_spec(true));
+        assert_eq!(result.nano_cpus, None);
+        assert_eq!(result.cpu_quota, Some(150_000));
+        assert_eq!(result.cpu_period, Some(100_000));
+    }
+}
@@ -1,14 +1,41 @@
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 
-use crate::app_data::{ContainerId, DockerCommand};
+use crate::app_data::{
+    CommitSpec, ContainerId, DockerCommand, LimitsSpec, NetworkConnectSpec, NewContainerSpec,
+    PruneTarget, SaveLogsSpec, Signal,
+};
 use bollard::Docker;
+use jiff::Timestamp;
 use tokio::sync::oneshot::Sender;
 
 #[derive(Debug)]
 pub enum DockerMessage {
+    Changes((ContainerId, String)),
+    Commit((ContainerId, CommitSpec)),
     ConfirmDelete(ContainerId),
-    Control((DockerCommand, ContainerId)),
+    Control((DockerCommand, ContainerId, Option<u16>)),
     Exec(Sender<Arc<Docker>>),
+    FilesList((ContainerId, String)),
+    FilesPreview((ContainerId, String)),
+    FilesSave((ContainerId, String, bool)),
+    FilesUpload((ContainerId, String, PathBuf)),
     Inspect(ContainerId),
+    Kill((ContainerId, Signal)),
+    Limits((ContainerId, String)),
+    LimitsUpdate((ContainerId, LimitsSpec)),
+    LogsFrom((ContainerId, Timestamp)),
+    LogsOlder(ContainerId),
+    NetworkConnect((ContainerId, NetworkConnectSpec)),
+    NetworkDisconnect((ContainerId, String)),
+    Networks((ContainerId, String)),
+    NewContainer(NewContainerSpec),
+    NewContainerLists,
+    Processes((ContainerId, String)),
+    Prune(PruneTarget),
+    PrunePreview(PruneTarget),
+    Rename((ContainerId, String)),
+    RunCommand((ContainerId, String)),
+    SaveLogs(SaveLogsSpec),
+    Signal((ContainerId, u64, Signal)),
     Update,
 }
@@ -1,46 +1,86 @@
 use bollard::{
     Docker,
+    container::LogOutput,
+    exec::{CreateExecOptions, StartExecResults},
     models::ContainerStatsResponse,
     models::ContainerSummary,
     query_parameters::{
-        InspectContainerOptions, ListContainersOptions, LogsOptions, RemoveContainerOptions,
-        RestartContainerOptions, StartContainerOptions, StatsOptions, StopContainerOptions,
+        InspectContainerOptions, KillContainerOptions, ListContainersOptions, LogsOptions,
+        RemoveContainerOptions, RestartContainerOptions, StartContainerOptions, StatsOptions,
+        StopContainerOptions,
     },
 };
 use futures_util::StreamExt;
+use jiff::Timestamp;
 use parking_lot::Mutex;
 use std::{
-    collections::HashSet,
-    sync::{Arc, atomic::AtomicUsize},
+    collections::{HashMap, HashSet, VecDeque},
+    sync::Arc,
 };
 use tokio::sync::mpsc::{Receiver, Sender};
 use uuid::Uuid;
 
 use crate::{
     ENTRY_POINT,
-    app_data::{AppData, ContainerId, DockerCommand, State},
+    app_data::{
+        AppData, ByteStats, CommandOutput, ContainerChanges, ContainerId, ContainerNetworks,
+        ContainerProcesses, DeleteOptions, DockerCommand, Limits, LogStream, LogsTz, PrunePreview,
+        Signal, State,
+    },
     app_error::AppError,
     config::Config,
+    exec::debug,
     ui::{GuiState, Status},
 };
+mod archive;
+mod files;
+mod limits;
 mod message;
+mod networks;
+mod new_container;
+mod processes;
+mod prompt;
+mod prune;
+mod recreate;
+mod save_logs;
 pub use message::DockerMessage;
+pub use recreate::pull_if_missing;
+
+/// The length, in seconds, of the first window of time searched for lines older than the oldest held line
+const LOGS_WINDOW: i64 = 60;
+
+/// How long a one-off command can run before it's reported as an error, else a command that never exits, such as `tail -f`, would leave the loading animation running forever
+const RUN_COMMAND_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 enum SpawnId {
     Stats((ContainerId, Binate)),
     Log(ContainerId),
+    Top(ContainerId),
 }
 
 impl SpawnId {
     /// Extract the &ContainerId out of self
     const fn get_id(&self) -> &ContainerId {
         match self {
-            Self::Log(id) | Self::Stats((id, _)) => id,
+            Self::Log(id) | Self::Stats((id, _)) | Self::Top(id) => id,
         }
     }
 }
 
+/// Which lines of a container's logs to fetch
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum LogsRange {
+    /// Every line since the last update, in seconds
+    Since(u64),
+    /// The most recent x lines, 0 for every line, used the first time a container is selected
+    Tail(u32),
+    /// Up to x lines older than the oldest currently held line, but not before the container was created, in seconds, used when scrolling to the top of the logs
+    Before((LogsTz, u32, u64)),
+    /// Every line from a given time up to the oldest currently held line, used when jumping to, or limiting the logs to, a time before the oldest line
+    From((Timestamp, LogsTz)),
+}
+
 /// Cpu & Mem stats take twice as long as the update interval to get a value, so will have two being executed at the same time
 /// SpawnId::Stats takes container_id and binate value to enable both cycles of the same container_id to be inserted into the hashmap
 /// Binate value is toggled when all handles have been spawned off
@@ -66,7 +106,10 @@ pub struct DockerData {
     config: Config,
     docker: Arc<Docker>,
     gui_state: Arc<Mutex<GuiState>>,
+    /// Requests to fetch logs from a given time, made while a log update was running, retried on the next update
+    pending_logs: HashMap<ContainerId, Timestamp>,
     receiver: Receiver<DockerMessage>,
+    sender: Sender<DockerMessage>,
     spawns: Arc<Mutex<HashSet<SpawnId>>>,
 }
 
@@ -238,82 +281,247 @@ impl DockerData {
         self.app_data.lock().update_containers(output);
     }
 
+    /// Stream a container's logs, alongside the stream each line was written to, skipping empty lines
+    async fn stream_logs(
+        docker: &Docker,
+        id: &ContainerId,
+        options: LogsOptions,
+        mut keep: impl FnMut((LogStream, String)),
+    ) {
+        let mut logs = docker.logs(id.get(), Some(options));
+        while let Some(Ok(value)) = logs.next().await {
+            let stream = match value {
+                LogOutput::StdErr { .. } => LogStream::Stderr,
+                _ => LogStream::Stdout,
+            };
+            let data = value.to_string();
+            if !data.trim().is_empty() {
+                keep((stream, data));
+            }
+        }
+    }
+
+    /// Fetch up to x lines older than the oldest held line, returns the lines, and true if there are no older lines
+    /// Docker applies the tail before the until filter, so rather than streaming every line up to the oldest line, windows of time before the oldest line are fetched,
+    /// each twice the length of the previous, until enough lines have been found, or the window reaches the time the container was created
+    async fn logs_before(
+        docker: &Docker,
+        id: &ContainerId,
+        options: LogsOptions,
+        (oldest, tail, created): (LogsTz, u32, u64),
+    ) -> (Vec<(LogStream, String)>, bool) {
+        let tail = usize::try_from(tail).unwrap_or(usize::MAX);
+        let created = i64::try_from(created).unwrap_or_default();
+        // Lines after the upper bound have already been fetched, either held, or in a newer window, lines at the bound may not have been, so are refetched and skipped if already seen
+        let mut upper = oldest.timestamp().unwrap_or_default();
+        let mut seen = HashSet::from([oldest]);
+        let mut window = LOGS_WINDOW;
+        let mut page = vec![];
+        loop {
+            let until = upper.as_second().saturating_add(1);
+            let since = until.saturating_sub(window);
+            let reached_start = since <= created || since <= 0;
+            let options = LogsOptions {
+                since: if reached_start {
+                    0
+                } else {
+                    i32::try_from(since).unwrap_or_default()
+                },
+                until: i32::try_from(until).unwrap_or_default(),
+                ..options.clone()
+            };
+            // Only the newest lines of a window can be returned, so no more than tail lines are held at once
+            let mut lines = VecDeque::new();
+            let mut trimmed = false;
+            Self::stream_logs(docker, id, options, |i| {
+                let tz = LogsTz::splitter(&i.1).0;
+                if tz.timestamp().is_some_and(|t| t <= upper) && seen.insert(tz) {
+                    lines.push_back(i);
+                    if lines.len() > tail {
+                        lines.pop_front();
+                        trimmed = true;
+                    }
+                }
+            })
+            .await;
+            let mut lines = Vec::from(lines);
+            lines.append(&mut page);
+            page = lines;
+            if reached_start || page.len() >= tail {
+                let complete = reached_start && !trimmed && page.len() <= tail;
+                let older = page.len().saturating_sub(tail);
+                page.drain(..older);
+                return (page, complete);
+            }
+            upper = Timestamp::from_second(since).unwrap_or_default();
+            window = window.saturating_mul(2);
+        }
+    }
+
     /// Update single container logs
     /// remove it from spawns hashmap when complete
     async fn update_log(
         app_data: Arc<Mutex<AppData>>,
         docker: Arc<Docker>,
         id: ContainerId,
-        since: u64,
+        range: LogsRange,
         spawns: Arc<Mutex<HashSet<SpawnId>>>,
         stderr: bool,
     ) {
-        let options = Some(LogsOptions {
+        let options = LogsOptions {
             stdout: true,
             stderr,
             timestamps: true,
-            since: i32::try_from(since).unwrap_or_default(),
             ..Default::default()
-        });
-
-        let mut logs = docker.logs(id.get(), options);
+        };
         let mut output = vec![];
-
-        while let Some(Ok(value)) = logs.next().await {
-            let data = value.to_string();
-            if !data.trim().is_empty() {
-                output.push(data);
+        match range {
+            LogsRange::Since(since) => {
+                let options = LogsOptions {
+                    since: i32::try_from(since).unwrap_or_default(),
+                    ..options
+                };
+                Self::stream_logs(&docker, &id, options, |i| output.push(i)).await;
+                app_data.lock().update_log_by_id(output, &id);
+            }
+            LogsRange::Tail(tail) => {
+                let options = LogsOptions {
+                    tail: if tail == 0 {
+                        "all".to_owned()
+                    } else {
+                        tail.to_string()
+                    },
+                    ..options
+                };
+                Self::stream_logs(&docker, &id, options, |i| output.push(i)).await;
+                let complete =
+                    tail == 0 || output.len() < usize::try_from(tail).unwrap_or(usize::MAX);
+                let mut app_data = app_data.lock();
+                app_data.update_log_by_id(output, &id);
+                if complete {
+                    app_data.set_logs_complete(&id);
+                }
+            }
+            LogsRange::Before((oldest, tail, created)) => {
+                let (page, complete) =
+                    Self::logs_before(&docker, &id, options, (oldest, tail, created)).await;
+                app_data.lock().update_older_log_by_id(page, &id, complete);
+            }
+            LogsRange::From((from, oldest)) => {
+                // Every line up to the oldest line is kept, so that there's no gap between these & the held lines
+                let oldest = oldest.timestamp().unwrap_or_default();
+                let options = LogsOptions {
+                    since: i32::try_from(from.as_second()).unwrap_or_default(),
+                    until: i32::try_from(oldest.as_second().saturating_add(1)).unwrap_or_default(),
+                    ..options
+                };
+                Self::stream_logs(&docker, &id, options, |i| {
+                    if LogsTz::splitter(&i.1)
+                        .0
+                        .timestamp()
+                        .is_some_and(|t| t < oldest)
+                    {
+                        output.push(i);
+                    }
+                })
+                .await;
+                app_data.lock().update_older_log_by_id(output, &id, false);
             }
         }
-        app_data.lock().update_log_by_id(output, &id);
         spawns.lock().remove(&SpawnId::Log(id));
     }
 
-    /// Update all logs, spawn each container into own tokio::spawn thread
-    fn init_all_logs(&self, all_ids: Vec<(State, ContainerId)>) -> Arc<AtomicUsize> {
-        let init = Arc::new(AtomicUsize::new(0));
-        for (_, id) in all_ids {
-            let app_data: Arc<parking_lot::lock_api::Mutex<parking_lot::RawMutex, AppData>> =
-                Arc::clone(&self.app_data);
-            let docker = Arc::clone(&self.docker);
-            let spawns = Arc::clone(&self.spawns);
-            let std_err = self.config.show_std_err;
-            let init = Arc::clone(&init);
-
-            self.spawns.lock().insert(SpawnId::Log(id.clone()));
-
-            tokio::spawn(async move {
-                Self::update_log(app_data, docker, id, 0, spawns, std_err).await;
-                init.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
-            });
-        }
-        init
-    }
-
     /// Initialize docker container data, before any messages are received
+    /// Only the most recent logs of the selected container are fetched, the rest are fetched when each container is first selected
+    /// Any debug containers left behind by a previous oxker session are removed first
     async fn initialise_container_data(&mut self) {
         self.gui_state.lock().status_push(Status::Init);
         let loading_uuid = Uuid::new_v4();
         GuiState::start_loading_animation(&self.gui_state, loading_uuid);
+        debug::remove_all(&self.docker).await;
         self.update_all_containers().await;
-        let all_ids = self.app_data.lock().get_all_id_state();
-        let all_ids_len = all_ids.len();
-        let init = self.init_all_logs(all_ids);
         self.update_all_container_stats();
+        self.app_data.lock().sort_containers();
 
-        while init.load(std::sync::atomic::Ordering::SeqCst) != all_ids_len {
-            self.app_data.lock().sort_containers();
-            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        let id = self.app_data.lock().get_selected_container_id();
+        if let Some(id) = id {
+            self.spawns.lock().insert(SpawnId::Log(id.clone()));
+            Self::update_log(
+                Arc::clone(&self.app_data),
+                Arc::clone(&self.docker),
+                id,
+                LogsRange::Tail(self.config.log_tail),
+                Arc::clone(&self.spawns),
+                self.config.show_std_err,
+            )
+            .await;
         }
         self.gui_state.lock().stop_loading_animation(loading_uuid);
         self.gui_state.lock().status_del(Status::Init);
     }
 
-    /// Update all cpu_mem, and selected container log (if a log update join_handle isn't currently being executed)
+    /// Fetch a page of older logs for a container, if a log update join_handle isn't currently being executed
+    fn update_older_logs(&self, id: ContainerId) {
+        let spawn_id = SpawnId::Log(id.clone());
+        let oldest = self.app_data.lock().get_logs_oldest(&id);
+        let created = self
+            .app_data
+            .lock()
+            .get_container_created(&id)
+            .unwrap_or_default();
+        if let Some(oldest) = oldest
+            && !self.spawns.lock().contains(&spawn_id)
+        {
+            self.spawns.lock().insert(spawn_id);
+            tokio::spawn(Self::update_log(
+                Arc::clone(&self.app_data),
+                Arc::clone(&self.docker),
+                id,
+                LogsRange::Before((oldest, self.config.log_tail, created)),
+                Arc::clone(&self.spawns),
+                self.config.show_std_err,
+            ));
+        }
+    }
+
+    /// Fetch every line of a container's logs from a given time, up to the oldest held line
+    /// If a log update join_handle is currently being executed the request is kept, with the earliest time, and retried on the next update
+    fn update_logs_from(&mut self, id: ContainerId, from: Timestamp) {
+        let spawn_id = SpawnId::Log(id.clone());
+        if self.spawns.lock().contains(&spawn_id) {
+            self.pending_logs
+                .entry(id)
+                .and_modify(|i| *i = (*i).min(from))
+                .or_insert(from);
+            return;
+        }
+        let oldest = self.app_data.lock().get_logs_oldest(&id);
+        if let Some(oldest) = oldest {
+            self.spawns.lock().insert(spawn_id);
+            tokio::spawn(Self::update_log(
+                Arc::clone(&self.app_data),
+                Arc::clone(&self.docker),
+                id,
+                LogsRange::From((from, oldest)),
+                Arc::clone(&self.spawns),
+                self.config.show_std_err,
+            ));
+        }
+    }
+
+    /// Update all cpu_mem, retry any pending requests for logs from a given time, and selected container log (if a log update join_handle isn't currently being executed)
     async fn update_everything(&mut self) {
         self.update_all_containers().await;
+        for (id, from) in std::mem::take(&mut self.pending_logs) {
+            self.update_logs_from(id, from);
+        }
         if let Some(container) = self.app_data.lock().get_selected_container() {
-            let last_updated = container.last_updated;
+            // Only the most recent lines are fetched the first time a container is selected
+            let range = if container.last_updated == 0 {
+                LogsRange::Tail(self.config.log_tail)
+            } else {
+                LogsRange::Since(container.last_updated)
+            };
             let spawn_id = SpawnId::Log(container.id.clone());
             // Only spawn if not already spawned with a given id/binate pair
             if !self.spawns.lock().contains(&spawn_id) {
@@ -322,77 +530,552 @@ impl DockerData {
                     Arc::clone(&self.app_data),
                     Arc::clone(&self.docker),
                     container.id.clone(),
-                    last_updated,
+                    range,
                     Arc::clone(&self.spawns),
                     self.config.show_std_err,
                 ));
             }
         }
         self.update_all_container_stats();
+        self.update_processes();
         self.app_data.lock().sort_containers();
     }
 
-    /// Set the global error as the docker error, and set gui_state to error
-    fn set_error(
-        app_data: &Arc<Mutex<AppData>>,
-        error: DockerCommand,
-        gui_state: &Arc<Mutex<GuiState>>,
-    ) {
-        app_data
-            .lock()
-            .set_error(AppError::DockerCommand(error), gui_state, Status::Error);
+    /// Refresh the process list, if it is open, on it's own tokio thread
+    /// Errors are ignored, as the list is refreshed on every docker interval, and the container may have stopped
+    fn update_processes(&self) {
+        let id = self.app_data.lock().get_processes_id();
+        if let Some(id) = id {
+            let spawn_id = SpawnId::Top(id.clone());
+            if !self.spawns.lock().contains(&spawn_id) {
+                self.spawns.lock().insert(spawn_id.clone());
+                let (app_data, docker, spawns) = (
+                    Arc::clone(&self.app_data),
+                    Arc::clone(&self.docker),
+                    Arc::clone(&self.spawns),
+                );
+                tokio::spawn(async move {
+                    if let Ok(output) = processes::top(&docker, &id).await {
+                        app_data
+                            .lock()
+                            .update_processes(Some(&id), |i| i.set_processes(output));
+                    }
+                    spawns.lock().remove(&spawn_id);
+                });
+            }
+        }
     }
 
     /// Execute docker commands (start, stop etc) on it's own tokio thread
-    async fn execute_command(&mut self, control: DockerCommand, id: ContainerId) {
+    /// The timeout, in seconds, is only used by stop, restart, & recreate, if None then the config timeout is used, and if that is None then the Docker default
+    async fn execute_command(
+        &mut self,
+        control: DockerCommand,
+        id: ContainerId,
+        timeout: Option<u16>,
+    ) {
         let (app_data, docker, gui_state) = (
             Arc::clone(&self.app_data),
             Arc::clone(&self.docker),
             Arc::clone(&self.gui_state),
         );
+        let timeout = |config: Option<u16>| timeout.or(config).map(i32::from);
+        let (stop_timeout, restart_timeout) = (
+            timeout(self.config.stop_timeout),
+            timeout(self.config.restart_timeout),
+        );
         tokio::spawn(async move {
             let uuid = Uuid::new_v4();
             GuiState::start_loading_animation(&gui_state, uuid);
-            if match control {
+            let err = |_| AppError::DockerCommand(control);
+            let result = match control {
                 DockerCommand::Delete => {
+                    let options = gui_state.lock().get_delete_options();
                     gui_state.lock().set_delete_container(None);
                     docker
                         .remove_container(
                             id.get(),
                             Some(RemoveContainerOptions {
-                                v: false,
-                                force: true,
-                                link: false,
+                                v: options.volumes,
+                                force: options.force,
+                                link: options.link,
                             }),
                         )
                         .await
+                        // The daemon refuses to delete a running container without force, so show the reason it gives
+                        .map_err(|e| match e {
+                            bollard::errors::Error::DockerResponseServerError {
+                                message, ..
+                            } => AppError::DockerDelete(message),
+                            _ => AppError::DockerCommand(control),
+                        })
                 }
-                DockerCommand::Pause => docker.pause_container(id.get()).await,
-                DockerCommand::Restart => {
-                    docker
-                        .restart_container(id.get(), None::<RestartContainerOptions>)
-                        .await
+                DockerCommand::Pause => docker.pause_container(id.get()).await.map_err(err),
+                DockerCommand::Recreate => {
+                    let result = recreate::recreate(&docker, &app_data, &id, stop_timeout).await;
+                    if result.is_ok() {
+                        let name = app_data.lock().get_recreate().map(|i| i.name);
+                        gui_state.lock().set_info_box(&format!(
+                            "recreated {}",
+                            name.unwrap_or_else(|| id.get_short())
+                        ));
+                    }
+                    app_data.lock().clear_recreate();
+                    result
                 }
-                DockerCommand::Resume => docker.unpause_container(id.get()).await,
-                DockerCommand::Start => {
-                    docker
-                        .start_container(id.get(), None::<StartContainerOptions>)
-                        .await
-                }
-                DockerCommand::Stop => {
-                    docker
-                        .stop_container(id.get(), None::<StopContainerOptions>)
-                        .await
+                // Kill, rename, & commit first open a popup, and are sent as their own messages once it's been submitted, so can't be run directly
+                DockerCommand::Kill | DockerCommand::Rename | DockerCommand::Commit => {
+                    Err(AppError::DockerCommand(control))
                 }
+                DockerCommand::Restart => docker
+                    .restart_container(
+                        id.get(),
+                        Some(RestartContainerOptions {
+                            t: restart_timeout,
+                            signal: None,
+                        }),
+                    )
+                    .await
+                    .map_err(err),
+                DockerCommand::Resume => docker.unpause_container(id.get()).await.map_err(err),
+                DockerCommand::Start => docker
+                    .start_container(id.get(), None::<StartContainerOptions>)
+                    .await
+                    .map_err(err),
+                DockerCommand::Stop => docker
+                    .stop_container(
+                        id.get(),
+                        Some(StopContainerOptions {
+                            t: stop_timeout,
+                            signal: None,
+                        }),
+                    )
+                    .await
+                    .map_err(err),
+            };
+            if let Err(error) = result {
+                app_data.lock().set_error(error, &gui_state, Status::Error);
+            }
+            gui_state.lock().stop_loading_animation(uuid);
+        });
+
+        self.update_everything().await;
+    }
+
+    /// Run a single command, without a tty, in a container, collecting both stdout & stderr
+    async fn run_command(
+        docker: &Docker,
+        id: &ContainerId,
+        command: &str,
+    ) -> Result<(String, Option<i64>), bollard::errors::Error> {
+        let exec = docker
+            .create_exec(
+                id.get(),
+                CreateExecOptions {
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    tty: Some(false),
+                    cmd: Some(vec!["sh", "-c", command]),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        let mut output = String::new();
+        if let StartExecResults::Attached {
+            output: mut stream, ..
+        } = docker.start_exec(&exec.id, None).await?
+        {
+            while let Some(Ok(msg)) = stream.next().await {
+                output.push_str(&msg.to_string());
             }
-            .is_err()
+        }
+        let exit_code = docker.inspect_exec(&exec.id).await?.exit_code;
+        Ok((output, exit_code))
+    }
+
+    /// Run a one-off command on it's own tokio thread, and display the output in a popup once it has completed
+    /// A command that hasn't completed within the timeout is shown as an error, the process itself can't be stopped via the Docker API, so is left running
+    fn execute_run_command(&self, id: ContainerId, command: String) {
+        let (app_data, docker, gui_state) = (
+            Arc::clone(&self.app_data),
+            Arc::clone(&self.docker),
+            Arc::clone(&self.gui_state),
+        );
+        tokio::spawn(async move {
+            let uuid = Uuid::new_v4();
+            GuiState::start_loading_animation(&gui_state, uuid);
+            match tokio::time::timeout(
+                RUN_COMMAND_TIMEOUT,
+                Self::run_command(&docker, &id, &command),
+            )
+            .await
             {
-                Self::set_error(&app_data, control, &gui_state);
+                Ok(Ok((output, exit_code))) => {
+                    let name = app_data
+                        .lock()
+                        .get_container_name_by_id(&id)
+                        .map_or_else(|| id.get().to_owned(), |i| i.get().to_owned());
+                    app_data
+                        .lock()
+                        .set_command_output(CommandOutput::new(name, command, &output, exit_code));
+                    gui_state.lock().status_push(Status::CommandOutput);
+                }
+                Ok(Err(_)) => {
+                    app_data
+                        .lock()
+                        .set_error(AppError::DockerExec, &gui_state, Status::Error);
+                }
+                Err(_) => app_data.lock().set_error(
+                    AppError::DockerExecTimeout(RUN_COMMAND_TIMEOUT.as_secs()),
+                    &gui_state,
+                    Status::Error,
+                ),
             }
             gui_state.lock().stop_loading_animation(uuid);
         });
+    }
 
-        self.update_everything().await;
+    /// Run a docker operation on it's own tokio thread, with the loading animation, any error is set as the global error
+    fn spawn_task<F, Fut>(&self, f: F)
+    where
+        F: FnOnce(Arc<Docker>, Arc<Mutex<AppData>>, Arc<Mutex<GuiState>>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), AppError>> + Send,
+    {
+        let (app_data, docker, gui_state) = (
+            Arc::clone(&self.app_data),
+            Arc::clone(&self.docker),
+            Arc::clone(&self.gui_state),
+        );
+        tokio::spawn(async move {
+            let uuid = Uuid::new_v4();
+            GuiState::start_loading_animation(&gui_state, uuid);
+            if let Err(e) = f(docker, Arc::clone(&app_data), Arc::clone(&gui_state)).await {
+                app_data.lock().set_error(e, &gui_state, Status::Error);
+            }
+            gui_state.lock().stop_loading_animation(uuid);
+        });
+    }
+
+    /// Send a chosen signal to a container, chosen in the signal picker opened by the Kill command in the commands panel
+    /// The containers are updated once the signal has been sent, rather than racing the kill
+    fn execute_kill(&self, id: ContainerId, signal: Signal) {
+        let sender = self.sender.clone();
+        self.spawn_task(move |docker, _, _| async move {
+            let result = docker
+                .kill_container(
+                    id.get(),
+                    Some(KillContainerOptions {
+                        signal: format!("SIG{signal}"),
+                    }),
+                )
+                .await
+                .map_err(|_| AppError::DockerCommand(DockerCommand::Kill));
+            sender.send(DockerMessage::Update).await.ok();
+            result
+        });
+    }
+
+    /// Handle the new container messages, listing the images & networks to choose from, and creating the container once the form is submitted
+    /// Errors from the daemon are shown in the form, so that they can be corrected without re-entering every field
+    fn new_container_message(&self, message: DockerMessage) {
+        match message {
+            DockerMessage::NewContainerLists => {
+                self.spawn_task(|docker, app_data, _| async move {
+                    let (images, networks) = new_container::lists(&docker).await?;
+                    app_data
+                        .lock()
+                        .update_new_container(|i| i.set_lists(images, networks));
+                    Ok(())
+                });
+            }
+            DockerMessage::NewContainer(spec) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    match new_container::create(&docker, &spec).await {
+                        Ok(name) => {
+                            app_data.lock().clear_new_container();
+                            let mut gui_state = gui_state.lock();
+                            gui_state.status_del(Status::NewContainer);
+                            gui_state.set_info_box(&format!("started {name}"));
+                        }
+                        Err(e) => {
+                            app_data
+                                .lock()
+                                .update_new_container(|i| i.form.status = Some(e));
+                        }
+                    }
+                    Ok(())
+                });
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle the limits messages, opening the form with the current limits, and applying the new limits once the form is submitted
+    /// Errors from the daemon are shown in the form, and a changed memory limit is shown straight away
+    fn limits_message(&self, message: DockerMessage) {
+        match message {
+            DockerMessage::Limits((id, name)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let host_config = limits::host_config(&docker, &id).await?;
+                    app_data
+                        .lock()
+                        .set_limits(Limits::new(id, name, Some(&host_config)));
+                    gui_state.lock().status_push(Status::Limits);
+                    Ok(())
+                });
+            }
+            DockerMessage::LimitsUpdate((id, spec)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    match limits::update(&docker, &id, &spec).await {
+                        Ok(()) => {
+                            let mut app_data = app_data.lock();
+                            if let Some(memory) = spec.memory.and_then(|i| u64::try_from(i).ok()) {
+                                app_data.set_mem_limit(&id, memory);
+                            }
+                            let name = app_data.get_limits().map(|i| i.name).unwrap_or_default();
+                            app_data.clear_limits();
+                            let mut gui_state = gui_state.lock();
+                            gui_state.status_del(Status::Limits);
+                            gui_state.set_info_box(&format!("updated limits of {name}"));
+                        }
+                        Err(e) => {
+                            app_data.lock().update_limits(|i| i.form.status = Some(e));
+                        }
+                    }
+                    Ok(())
+                });
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle the networks messages, opening the popup with every network, and connecting or disconnecting the container
+    /// Errors from the daemon are shown in the popup, and the networks are listed again after each change
+    fn networks_message(&self, message: DockerMessage) {
+        match message {
+            DockerMessage::Networks((id, name)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let items = networks::list(&docker, &id).await?;
+                    app_data
+                        .lock()
+                        .set_networks(ContainerNetworks::new(id, name, items));
+                    gui_state.lock().status_push(Status::Networks);
+                    Ok(())
+                });
+            }
+            DockerMessage::NetworkConnect((id, spec)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let result = networks::connect(&docker, &id, &spec)
+                        .await
+                        .map(|()| format!("connected to {}", spec.network));
+                    Self::networks_changed(&docker, &app_data, &gui_state, &id, result).await
+                });
+            }
+            DockerMessage::NetworkDisconnect((id, network)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let result = networks::disconnect(&docker, &id, &network)
+                        .await
+                        .map(|()| format!("disconnected from {network}"));
+                    Self::networks_changed(&docker, &app_data, &gui_state, &id, result).await
+                });
+            }
+            _ => (),
+        }
+    }
+
+    /// After a connect or disconnect, either show the daemon's error in the popup, or close the connect form and list the networks again
+    async fn networks_changed(
+        docker: &Docker,
+        app_data: &Arc<Mutex<AppData>>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        id: &ContainerId,
+        result: Result<String, String>,
+    ) -> Result<(), AppError> {
+        match result {
+            Ok(info) => {
+                let items = networks::list(docker, id).await?;
+                app_data.lock().update_networks(|i| {
+                    i.connect = None;
+                    i.status = None;
+                    i.set_items(items);
+                });
+                gui_state.lock().set_info_box(&info);
+            }
+            Err(e) => {
+                app_data.lock().update_networks(|i| i.status = Some(e));
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle the rename & commit messages, sent once the prompt has been submitted
+    /// Errors from the daemon are shown in the prompt, so that they can be corrected
+    fn prompt_message(&self, message: DockerMessage) {
+        self.spawn_task(|docker, app_data, gui_state| async move {
+            let result = match message {
+                DockerMessage::Rename((id, name)) => prompt::rename(&docker, &id, &name)
+                    .await
+                    .map(|()| format!("renamed to {name}")),
+                DockerMessage::Commit((id, spec)) => {
+                    prompt::commit(&docker, &id, &spec).await.map(|image_id| {
+                        let tag = spec.tag.map_or_else(String::new, |i| format!(":{i}"));
+                        format!("committed {}{tag} - image {image_id}", spec.repo)
+                    })
+                }
+                _ => return Ok(()),
+            };
+            match result {
+                Ok(info) => {
+                    app_data.lock().clear_prompt();
+                    let mut gui_state = gui_state.lock();
+                    gui_state.status_del(Status::Prompt);
+                    gui_state.set_info_box(&info);
+                }
+                Err(e) => {
+                    app_data.lock().update_prompt(|i| i.form.status = Some(e));
+                }
+            }
+            Ok(())
+        });
+    }
+
+    /// Handle the prune messages, first listing what would be removed, and then, once confirmed, actually removing it
+    fn prune_message(&self, message: DockerMessage) {
+        match message {
+            DockerMessage::PrunePreview(target) => {
+                self.spawn_task(move |docker, app_data, _| async move {
+                    let items = prune::preview(&docker, target).await?;
+                    app_data
+                        .lock()
+                        .update_prune(|i| i.preview = Some(PrunePreview::new(target, items)));
+                    Ok(())
+                });
+            }
+            DockerMessage::Prune(target) => {
+                self.spawn_task(move |docker, _, gui_state| async move {
+                    let (removed, reclaimed) = prune::prune(&docker, target).await?;
+                    gui_state.lock().set_info_box(&format!(
+                        "removed {removed} {target}, reclaimed {}",
+                        ByteStats::new(reclaimed)
+                    ));
+                    Ok(())
+                });
+            }
+            _ => (),
+        }
+    }
+
+    /// Handle the file browser & filesystem changes messages, listing, previewing, saving, & uploading files, and saving logs
+    fn files_message(&self, message: DockerMessage) {
+        match message {
+            DockerMessage::Processes((id, name)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let output = processes::top(&docker, &id).await?;
+                    let signals = processes::signals_available(&app_data.lock().config);
+                    app_data
+                        .lock()
+                        .set_processes(ContainerProcesses::new(id, name, output, signals));
+                    gui_state.lock().status_push(Status::Processes);
+                    Ok(())
+                });
+            }
+            DockerMessage::Signal((id, host_pid, signal)) => {
+                self.spawn_task(move |docker, _, gui_state| async move {
+                    let pid = processes::signal(&docker, &id, host_pid, signal).await?;
+                    gui_state
+                        .lock()
+                        .set_info_box(&format!("sent SIG{signal} to process {pid}"));
+                    Ok(())
+                });
+            }
+            DockerMessage::Changes((id, name)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let changes = docker
+                        .container_changes(id.get())
+                        .await
+                        .map_err(|_| AppError::DockerFiles("get filesystem changes".to_owned()))?
+                        .unwrap_or_default();
+                    app_data
+                        .lock()
+                        .set_container_changes(ContainerChanges::new(id, name, changes));
+                    gui_state.lock().status_push(Status::Changes);
+                    Ok(())
+                });
+            }
+            DockerMessage::FilesList((id, path)) => {
+                self.spawn_task(|docker, app_data, _| async move {
+                    let (entries, truncated) = files::list(&docker, &id, &path).await?;
+                    app_data
+                        .lock()
+                        .update_file_browser(&id, |i| i.set_entries(path, entries, truncated));
+                    Ok(())
+                });
+            }
+            DockerMessage::FilesPreview((id, path)) => {
+                self.spawn_task(|docker, app_data, _| async move {
+                    let text = files::preview(&docker, &id, &path).await?;
+                    app_data
+                        .lock()
+                        .update_file_browser(&id, |i| i.preview = Some((path, text)));
+                    Ok(())
+                });
+            }
+            DockerMessage::FilesSave((id, path, as_tar)) => {
+                self.spawn_task(move |docker, app_data, gui_state| async move {
+                    let (dir_save, name) = {
+                        let app_data = app_data.lock();
+                        (
+                            app_data.config.dir_save.clone(),
+                            app_data.get_file_browser().map(|i| i.name),
+                        )
+                    };
+                    let dir_save =
+                        dir_save.ok_or_else(|| AppError::IO("no save directory".to_owned()))?;
+                    let name = name.unwrap_or_else(|| id.get_short());
+                    let output = files::save(&docker, &id, &path, &dir_save, &name, as_tar).await?;
+                    gui_state
+                        .lock()
+                        .set_info_box(&format!("saved to {}", output.display()));
+                    Ok(())
+                });
+            }
+            DockerMessage::SaveLogs(spec) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    let (dir_save, timestamp) = {
+                        let app_data = app_data.lock();
+                        (
+                            app_data.config.dir_save.clone(),
+                            app_data.config.show_timestamp,
+                        )
+                    };
+                    let dir_save =
+                        dir_save.ok_or_else(|| AppError::IO("no save directory".to_owned()))?;
+                    let (output, count) =
+                        save_logs::save(&docker, spec, &dir_save, timestamp).await?;
+                    let info = match count {
+                        0 => "no logs to save".to_owned(),
+                        1 => format!("saved to {}", output.display()),
+                        _ => format!("saved {count} logs to {}", output.display()),
+                    };
+                    gui_state.lock().set_info_box(&info);
+                    Ok(())
+                });
+            }
+            DockerMessage::FilesUpload((id, dir, local)) => {
+                self.spawn_task(|docker, app_data, gui_state| async move {
+                    files::upload(&docker, &id, &dir, &local).await?;
+                    gui_state
+                        .lock()
+                        .set_info_box(&format!("uploaded {} to {dir}", local.display()));
+                    let (entries, truncated) = files::list(&docker, &id, &dir).await?;
+                    app_data
+                        .lock()
+                        .update_file_browser(&id, |i| i.set_entries(dir, entries, truncated));
+                    Ok(())
+                });
+            }
+            _ => (),
+        }
     }
 
     /// Handle incoming messages, container controls & all container information update
@@ -401,12 +1084,45 @@ impl DockerData {
         while let Some(message) = self.receiver.recv().await {
             match message {
                 DockerMessage::ConfirmDelete(id) => {
-                    self.gui_state.lock().set_delete_container(Some(id));
+                    let mut gui_state = self.gui_state.lock();
+                    gui_state.set_delete_options(DeleteOptions::from(&self.config));
+                    gui_state.set_delete_container(Some(id));
                 }
-                DockerMessage::Control((command, id)) => self.execute_command(command, id).await,
+                DockerMessage::Control((command, id, timeout)) => {
+                    self.execute_command(command, id, timeout).await;
+                }
+                DockerMessage::Kill((id, signal)) => self.execute_kill(id, signal),
                 DockerMessage::Exec(docker_tx) => {
                     docker_tx.send(Arc::clone(&self.docker)).ok();
                 }
+                DockerMessage::Changes(_)
+                | DockerMessage::FilesList(_)
+                | DockerMessage::FilesPreview(_)
+                | DockerMessage::FilesSave(_)
+                | DockerMessage::FilesUpload(_)
+                | DockerMessage::Processes(_)
+                | DockerMessage::SaveLogs(_)
+                | DockerMessage::Signal(_) => self.files_message(message),
+                DockerMessage::Limits(_) | DockerMessage::LimitsUpdate(_) => {
+                    self.limits_message(message);
+                }
+                DockerMessage::NewContainer(_) | DockerMessage::NewContainerLists => {
+                    self.new_container_message(message);
+                }
+                DockerMessage::Networks(_)
+                | DockerMessage::NetworkConnect(_)
+                | DockerMessage::NetworkDisconnect(_) => self.networks_message(message),
+                DockerMessage::Commit(_) | DockerMessage::Rename(_) => {
+                    self.prompt_message(message);
+                }
+                DockerMessage::Prune(_) | DockerMessage::PrunePreview(_) => {
+                    self.prune_message(message);
+                }
+                DockerMessage::RunCommand((id, command)) => {
+                    self.execute_run_command(id, command);
+                }
+                DockerMessage::LogsOlder(id) => self.update_older_logs(id),
+                DockerMessage::LogsFrom((id, from)) => self.update_logs_from(id, from),
                 DockerMessage::Update => self.update_everything().await,
                 DockerMessage::Inspect(id) => {
                     let t = self
@@ -456,7 +1172,9 @@ impl DockerData {
                 binate: Binate::One,
                 docker: Arc::new(docker),
                 gui_state,
+                pending_logs: HashMap::new(),
                 receiver: docker_rx,
+                sender: docker_tx.clone(),
                 spawns: Arc::new(Mutex::new(HashSet::new())),
             };
             inner.initialise_container_data().await;
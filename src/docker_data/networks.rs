@@ -0,0 +1,165 @@
+use std::{collections::HashMap, net::IpAddr};
+
+use bollard::{
+    Docker,
+    models::{
+        EndpointIpamConfig, EndpointSettings, Network, NetworkConnectRequest,
+        NetworkDisconnectRequest,
+    },
+    query_parameters::{InspectContainerOptions, ListNetworksOptions},
+};
+
+use super::new_container::daemon_message;
+use crate::{
+    app_data::{ContainerId, NetworkConnectSpec, NetworkItem},
+    app_error::AppError,
+};
+
+/// Combine every network with the endpoints of the container, to show which networks it's attached to
+fn items(
+    networks: Vec<Network>,
+    endpoints: &HashMap<String, EndpointSettings>,
+) -> Vec<NetworkItem> {
+    networks
+        .into_iter()
+        .filter_map(|network| {
+            let name = network.name?;
+            let endpoint = endpoints.get(&name);
+            Some(NetworkItem {
+                aliases: endpoint.and_then(|i| i.aliases.clone()).unwrap_or_default(),
+                connected: endpoint.is_some(),
+                driver: network.driver.unwrap_or_default(),
+                ip: endpoint
+                    .and_then(|i| i.ip_address.clone())
+                    .filter(|i| !i.is_empty()),
+                name,
+            })
+        })
+        .collect()
+}
+
+/// The endpoint config for a connect, only set when an alias, or a static ip, has been given
+fn endpoint(spec: &NetworkConnectSpec) -> Option<EndpointSettings> {
+    if spec.alias.is_none() && spec.ip.is_none() {
+        return None;
+    }
+    Some(EndpointSettings {
+        aliases: spec.alias.clone().map(|i| vec![i]),
+        ipam_config: spec.ip.map(|ip| match ip {
+            IpAddr::V4(ip) => EndpointIpamConfig {
+                ipv4_address: Some(ip.to_string()),
+                ..Default::default()
+            },
+            IpAddr::V6(ip) => EndpointIpamConfig {
+                ipv6_address: Some(ip.to_string()),
+                ..Default::default()
+            },
+        }),
+        ..Default::default()
+    })
+}
+
+/// Every network, and whether the container is attached to it
+pub async fn list(docker: &Docker, id: &ContainerId) -> Result<Vec<NetworkItem>, AppError> {
+    let networks = docker
+        .list_networks(None::<ListNetworksOptions>)
+        .await
+        .map_err(|_| AppError::DockerNetworks)?;
+    let endpoints = docker
+        .inspect_container(id.get(), None::<InspectContainerOptions>)
+        .await
+        .map_err(|_| AppError::DockerNetworks)?
+        .network_settings
+        .and_then(|i| i.networks)
+        .unwrap_or_default();
+    Ok(items(networks, &endpoints))
+}
+
+/// Returns the daemon's message on error, so that it can be shown in the popup
+pub async fn connect(
+    docker: &Docker,
+    id: &ContainerId,
+    spec: &NetworkConnectSpec,
+) -> Result<(), String> {
+    docker
+        .connect_network(
+            &spec.network,
+            NetworkConnectRequest {
+                container: id.get().to_owned(),
+                endpoint_config: endpoint(spec),
+            },
+        )
+        .await
+        .map_err(daemon_message)
+}
+
+/// Returns the daemon's message on error, so that it can be shown in the popup
+pub async fn disconnect(docker: &Docker, id: &ContainerId, network: &str) -> Result<(), String> {
+    docker
+        .disconnect_network(
+            network,
+            NetworkDisconnectRequest {
+                container: id.get().to_owned(),
+                force: None,
+            },
+        )
+        .await
+        .map_err(daemon_message)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        net::{IpAddr, Ipv4Addr},
+    };
+
+    use bollard::models::{EndpointSettings, Network};
+
+    use super::{endpoint, items};
+    use crate::app_data::NetworkConnectSpec;
+
+    #[test]
+    /// Attached networks have the aliases & ip of the container's endpoint
+    fn test_networks_items() {
+        let network = |name: &str| Network {
+            name: Some(name.to_owned()),
+            driver: Some("bridge".to_owned()),
+            ..Default::default()
+        };
+        let endpoints = HashMap::from([(
+            "network_1".to_owned(),
+            EndpointSettings {
+                aliases: Some(vec!["db".to_owned()]),
+                ip_address: Some("172.20.0.2".to_owned()),
+                ..Default::default()
+            },
+        )]);
+        let result = items(vec![network("network_1"), network("network_2")], &endpoints);
+        assert!(result[0].connected);
+        assert_eq!(result[0].aliases, vec!["db"]);
+        assert_eq!(result[0].ip, Some("172.20.0.2".to_owned()));
+        assert!(!result[1].connected);
+        assert_eq!(result[1].ip, None);
+    }
+
+    #[test]
+    /// The endpoint config is only set when an alias, or an ip, is given
+    fn test_networks_endpoint() {
+        let mut spec = NetworkConnectSpec {
+            alias: None,
+            ip: None,
+            network: "network_1".to_owned(),
+        };
+        assert!(endpoint(&spec).is_none());
+
+        spec.alias = Some("db".to_owned());
+        spec.ip = Some(IpAddr::V4(Ipv4Addr::new(172, 20, 0, 10)));
+        let result = endpoint(&spec).unwrap();
+        assert_eq!(result.aliases, Some(vec!["db".to_owned()]));
+        let ipam = result.ipam_config.unwrap();
+        assert_eq!(ipam.ipv4_address, Some("172.20.0.10".to_owned()));
+        assert_eq!(ipam.ipv6_address, None);
+    }
+}
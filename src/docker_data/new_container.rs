@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use bollard::{
+    Docker,
+    models::{
+        ContainerCreateBody, EndpointSettings, HostConfig, NetworkingConfig, PortBinding,
+        RestartPolicy, RestartPolicyNameEnum,
+    },
+    query_parameters::{
+        CreateContainerOptions, ListImagesOptions, ListNetworksOptions, StartContainerOptions,
+    },
+};
+
+use super::recreate::pull_if_missing;
+use crate::{
+    app_data::{NewContainerSpec, PortMapping},
+    app_error::AppError,
+};
+
+/// Convert an error into the message the Docker daemon gave, as these explain what was wrong with the config
+pub(super) fn daemon_message(error: bollard::errors::Error) -> String {
+    match error {
+        bollard::errors::Error::DockerResponseServerError { message, .. } => message,
+        e => e.to_string(),
+    }
+}
+
+pub(super) const fn restart_policy(name: &str) -> RestartPolicyNameEnum {
+    match name.as_bytes() {
+        b"always" => RestartPolicyNameEnum::ALWAYS,
+        b"unless-stopped" => RestartPolicyNameEnum::UNLESS_STOPPED,
+        b"on-failure" => RestartPolicyNameEnum::ON_FAILURE,
+        _ => RestartPolicyNameEnum::NO,
+    }
+}
+
+/// Group the port bindings by container port & protocol, as Docker expects
+fn port_bindings(ports: &[PortMapping]) -> HashMap<String, Option<Vec<PortBinding>>> {
+    let mut output = HashMap::<String, Option<Vec<PortBinding>>>::new();
+    for port in ports {
+        output
+            .entry(format!("{}/{}", port.container_port, port.protocol))
+            .or_default()
+            .get_or_insert_with(Vec::new)
+            .push(PortBinding {
+                host_ip: port.host_ip.clone(),
+                host_port: Some(port.host_port.map_or_else(String::new, |i| i.to_string())),
+            });
+    }
+    output
+}
+
+/// Build the create config from the validated form
+fn create_body(spec: &NewContainerSpec) -> ContainerCreateBody {
+    let bindings = port_bindings(&spec.ports);
+    let mut exposed_ports = bindings.keys().cloned().collect::<Vec<_>>();
+    exposed_ports.sort();
+    ContainerCreateBody {
+        image: Some(spec.image.clone()),
+        cmd: spec.command.clone(),
+        env: Some(spec.env.clone()).filter(|i| !i.is_empty()),
+        exposed_ports: Some(exposed_ports).filter(|i| !i.is_empty()),
+        host_config: Some(HostConfig {
+            binds: Some(spec.volumes.clone()).filter(|i| !i.is_empty()),
+            memory: spec.memory,
+            nano_cpus: spec.nano_cpus,
+            network_mode: spec.network.clone(),
+            port_bindings: Some(bindings).filter(|i| !i.is_empty()),
+            restart_policy: Some(RestartPolicy {
+                name: Some(restart_policy(&spec.restart)),
+                maximum_retry_count: None,
+            }),
+            ..Default::default()
+        }),
+        networking_config: spec.network.as_ref().map(|network| NetworkingConfig {
+            endpoints_config: Some(HashMap::from([(
+                network.clone(),
+                EndpointSettings::default(),
+            )])),
+        }),
+        ..Default::default()
+    }
+}
+
+/// The tagged local images, and the networks, that can be chosen in the form
+pub async fn lists(docker: &Docker) -> Result<(Vec<String>, Vec<String>), AppError> {
+    let err = |_| AppError::DockerCreate("list images & networks".to_owned());
+    let images = docker
+        .list_images(None::<ListImagesOptions>)
+        .await
+        .map_err(err)?
+        .into_iter()
+        .flat_map(|i| i.repo_tags)
+        .filter(|i| i != "<none>:<none>")
+        .collect();
+    let networks = docker
+        .list_networks(None::<ListNetworksOptions>)
+        .await
+        .map_err(err)?
+        .into_iter()
+        .filter_map(|i| i.name)
+        .collect();
+    Ok((images, networks))
+}
+
+/// Create, & start, a container from the validated form, pulling the image first if needed
+/// Returns the daemon's message on error, so that it can be shown in the form
+pub async fn create(docker: &Docker, spec: &NewContainerSpec) -> Result<String, String> {
+    pull_if_missing(docker, &spec.image)
+        .await
+        .map_err(|e| format!("unable to pull {}: {e}", spec.image))?;
+    let response = docker
+        .create_container(
+            Some(CreateContainerOptions {
+                name: spec.name.clone(),
+                ..Default::default()
+            }),
+            create_body(spec),
+        )
+        .await
+        .map_err(daemon_message)?;
+    let id = response.id.chars().take(12).collect::<String>();
+    docker
+        .start_container(&response.id, None::<StartContainerOptions>)
+        .await
+        .map_err(|e| format!("created {id}, but unable to start: {}", daemon_message(e)))?;
+    Ok(spec.name.clone().unwrap_or(id))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::models::RestartPolicyNameEnum;
+
+    use super::create_body;
+    use crate::app_data::{NewContainerSpec, PortMapping};
+
+    #[test]
+    /// Ports are exposed & bound, empty lists are left unset, and limits & restart policy are in the host config
+    fn test_new_container_create_body() {
+        let port = |container_port, host_port| PortMapping {
+            container_port,
+            host_ip: None,
+            host_port,
+            protocol: "tcp".to_owned(),
+        };
+        let spec = NewContainerSpec {
+            command: None,
+            env: vec![],
+            image: "redis:7".to_owned(),
+            memory: Some(1024 * 1024 * 512),
+            name: None,
+            nano_cpus: Some(500_000_000),
+            network: Some("network_1".to_owned()),
+            ports: vec![
+                port(6379, Some(6379)),
+                port(6379, Some(6380)),
+                port(80, None),
+            ],
+            restart: "unless-stopped".to_owned(),
+            volumes: vec![],
+        };
+        let result = create_body(&spec);
+        assert!(result.env.is_none());
+        assert_eq!(
+            result.exposed_ports,
+            Some(vec!["6379/tcp".to_owned(), "80/tcp".to_owned()])
+        );
+        let host_config = result.host_config.unwrap();
+        assert!(host_config.binds.is_none());
+        assert_eq!(host_config.memory, Some(1024 * 1024 * 512));
+        assert_eq!(host_config.nano_cpus, Some(500_000_000));
+        assert_eq!(host_config.network_mode, Some("network_1".to_owned()));
+        assert_eq!(
+            host_config.restart_policy.unwrap().name,
+            Some(RestartPolicyNameEnum::UNLESS_STOPPED)
+        );
+        let bindings = host_config.port_bindings.unwrap();
+        assert_eq!(bindings["6379/tcp"].as_ref().unwrap().len(), 2);
+        assert_eq!(
+            bindings["80/tcp"].as_ref().unwrap()[0].host_port,
+            Some(String::new())
+        );
+        assert!(
+            result
+                .networking_config
+                .unwrap()
+                .endpoints_config
+                .unwrap()
+                .contains_key("network_1")
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use bollard::{
+    Docker,
+    exec::{CreateExecOptions, StartExecResults},
+    models::ContainerTopResponse,
+    query_parameters::TopOptions,
+};
+use futures_util::StreamExt;
+
+use crate::{
+    app_data::{ContainerId, Process, Signal},
+    app_error::AppError,
+    config::Config,
+};
+
+use super::files::NOT_FOUND;
+
+/// Arguments given to `ps` on the Docker host, the pid column is required by Docker
+const PS_ARGS: &str = "-o pid,user,pcpu,rss,args";
+
+/// Find the index of the first matching column title
+fn column(titles: &[String], names: &[&str]) -> Option<usize> {
+    titles
+        .iter()
+        .position(|i| names.iter().any(|n| i.eq_ignore_ascii_case(n)))
+}
+
+/// Convert the `docker top` response into processes, the columns are found by title, as the default `ps -ef` output has neither cpu nor rss
+fn parse(response: ContainerTopResponse) -> Vec<Process> {
+    let titles = response.titles.unwrap_or_default();
+    let pid = column(&titles, &["PID"]);
+    let user = column(&titles, &["USER", "UID"]);
+    let cpu = column(&titles, &["%CPU", "C"]);
+    let rss = column(&titles, &["RSS"]);
+    let command = column(&titles, &["COMMAND", "CMD", "ARGS"]);
+
+    response
+        .processes
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|row| {
+            let get = |index: Option<usize>| {
+                index
+                    .and_then(|i| row.get(i))
+                    .map(|i| i.trim().to_owned())
+                    .unwrap_or_default()
+            };
+            Some(Process {
+                command: get(command),
+                cpu: get(cpu).parse().unwrap_or_default(),
+                pid: get(pid).parse().ok()?,
+                rss: get(rss).parse().unwrap_or_default(),
+                user: get(user),
+            })
+        })
+        .collect()
+}
+
+/// Get the processes running in a container, if the host `ps` doesn't accept the custom arguments, the Docker default is used instead
+pub async fn top(docker: &Docker, id: &ContainerId) -> Result<Vec<Process>, AppError> {
+    let response = match docker
+        .top_processes(
+            id.get(),
+            Some(TopOptions {
+                ps_args: PS_ARGS.to_owned(),
+            }),
+        )
+        .await
+    {
+        Ok(response) => response,
+        Err(_) => docker
+            .top_processes(id.get(), None)
+            .await
+            .map_err(|_| AppError::DockerProcesses("list processes".to_owned()))?,
+    };
+    Ok(parse(response))
+}
+
+/// Signals need the host pid to be converted into the pid inside the container, which is only possible by reading the host `/proc`
+/// So they're only available when oxker isn't running in a container, and the Docker daemon is on the same host
+pub fn signals_available(config: &Config) -> bool {
+    !config.in_container
+        && crate::read_docker_host(config)
+            .is_none_or(|host| host.starts_with("unix://") || host.starts_with('/'))
+}
+
+/// Convert a host pid into the pid inside the container, using the last value of `NSpid` in `/proc/[pid]/status`
+/// The cgroup of the process must contain the container id, else the pid may belong to an unrelated local process, and None is returned
+fn container_pid(id: &ContainerId, host_pid: u64) -> Option<u64> {
+    std::fs::read_to_string(format!("/proc/{host_pid}/cgroup"))
+        .ok()
+        .filter(|cgroup| cgroup.contains(id.get()))?;
+    std::fs::read_to_string(format!("/proc/{host_pid}/status"))
+        .ok()
+        .and_then(|status| {
+            status
+                .lines()
+                .find_map(|i| i.strip_prefix("NSpid:"))
+                .and_then(|i| i.split_whitespace().last())
+                .and_then(|i| i.parse().ok())
+        })
+}
+
+/// Run a one-off command in the container, returns the exit code, and the first line of the output, or None if the exec couldn't be run
+async fn exec_code(docker: &Docker, id: &ContainerId, cmd: Vec<&str>) -> Option<(i64, String)> {
+    let exec = docker
+        .create_exec(
+            id.get(),
+            CreateExecOptions {
+                attach_stdout: Some(true),
+                attach_stderr: Some(true),
+                tty: Some(false),
+                cmd: Some(cmd),
+                ..Default::default()
+            },
+        )
+        .await
+        .ok()?;
+    let mut output = String::new();
+    if let Ok(StartExecResults::Attached {
+        output: mut stream, ..
+    }) = docker.start_exec(&exec.id, None).await
+    {
+        while let Some(Ok(msg)) = stream.next().await {
+            output.push_str(&msg.to_string());
+        }
+    }
+    let code = docker.inspect_exec(&exec.id).await.ok()?.exit_code?;
+    Some((
+        code,
+        output.lines().next().unwrap_or_default().trim().to_owned(),
+    ))
+}
+
+/// Send a signal to a process, via a one-off `kill` exec in the container
+/// Images without a `kill` binary fall back to the shell builtin, via `sh -c`, a non-zero exit code is returned as an error
+pub async fn signal(
+    docker: &Docker,
+    id: &ContainerId,
+    host_pid: u64,
+    signal: Signal,
+) -> Result<u64, AppError> {
+    let pid = container_pid(id, host_pid).ok_or_else(|| {
+        AppError::DockerProcesses(format!("find process {host_pid} in the container"))
+    })?;
+    let signal = signal.to_string();
+    let pid_str = pid.to_string();
+    let direct = exec_code(docker, id, vec!["kill", "-s", &signal, &pid_str])
+        .await
+        .filter(|(code, _)| !NOT_FOUND.contains(code));
+    let result = match direct {
+        Some(result) => Some(result),
+        None => {
+            let cmd = format!("kill -s {signal} {pid}");
+            exec_code(docker, id, vec!["sh", "-c", &cmd]).await
+        }
+    };
+    let err = |reason: String| {
+        AppError::DockerProcesses(format!("send SIG{signal} to process {pid}, {reason}"))
+    };
+    match result {
+        Some((0, _)) => Ok(pid),
+        Some((code, output)) if output.is_empty() => Err(err(format!("exit code {code}"))),
+        Some((code, output)) => Err(err(format!("exit code {code}: {output}"))),
+        None => Err(err("unable to run kill".to_owned())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bollard::models::ContainerTopResponse;
+
+    use super::{container_pid, parse, signals_available};
+    use crate::{app_data::ContainerId, tests::gen_config};
+
+    fn gen_row(row: &[&str]) -> Vec<String> {
+        row.iter().map(|i| (*i).to_owned()).collect()
+    }
+
+    #[test]
+    /// Columns are found by title, rows without a valid pid are skipped
+    fn test_processes_parse() {
+        let result = parse(ContainerTopResponse {
+            titles: Some(gen_row(&["PID", "USER", "%CPU", "RSS", "COMMAND"])),
+            processes: Some(vec![
+                gen_row(&["1234", "root", "1.5", "2048", "nginx: master process"]),
+                gen_row(&["invalid", "root", "0", "0", "sh"]),
+            ]),
+        });
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].pid, 1234);
+        assert_eq!(result[0].user, "root");
+        assert!((result[0].cpu - 1.5).abs() < f64::EPSILON);
+        assert_eq!(result[0].rss, 2048);
+        assert_eq!(result[0].command, "nginx: master process");
+
+        // The default docker top output
+        let result = parse(ContainerTopResponse {
+            titles: Some(gen_row(&[
+                "UID", "PID", "PPID", "C", "STIME", "TTY", "TIME", "CMD",
+            ])),
+            processes: Some(vec![gen_row(&[
+                "root", "99", "1", "0", "10:00", "?", "00:00:00", "sleep 10",
+            ])]),
+        });
+        assert_eq!(result[0].pid, 99);
+        assert_eq!(result[0].user, "root");
+        assert_eq!(result[0].rss, 0);
+        assert_eq!(result[0].command, "sleep 10");
+    }
+
+    #[test]
+    /// A process that isn't in the container's cgroup isn't converted, as it may be an unrelated local process
+    fn test_processes_container_pid() {
+        let pid = u64::from(std::process::id());
+        let id = ContainerId::from("oxker_not_a_container_id");
+        assert!(container_pid(&id, pid).is_none());
+        assert!(container_pid(&id, u64::MAX).is_none());
+    }
+
+    #[test]
+    /// Signals are unavailable when oxker is in a container, or the Docker daemon is remote
+    fn test_processes_signals_available() {
+        let mut config = gen_config();
+        config.host = Some("unix:///var/run/docker.sock".to_owned());
+        assert!(signals_available(&config));
+        config.host = Some("tcp://10.0.0.1:2375".to_owned());
+        assert!(!signals_available(&config));
+        config.host = Some("/var/run/docker.sock".to_owned());
+        config.in_container = true;
+        assert!(!signals_available(&config));
+    }
+}
@@ -0,0 +1,79 @@
+use bollard::{
+    Docker,
+    models::ContainerConfig,
+    query_parameters::{CommitContainerOptions, RenameContainerOptions},
+};
+
+use super::new_container::daemon_message;
+use crate::app_data::{CommitSpec, ContainerId};
+
+/// The commit options, the container is paused while being committed, as the Docker cli does
+fn commit_options(id: &ContainerId, spec: &CommitSpec) -> CommitContainerOptions {
+    CommitContainerOptions {
+        container: Some(id.get().to_owned()),
+        repo: Some(spec.repo.clone()),
+        tag: spec.tag.clone(),
+        comment: spec.message.clone(),
+        author: spec.author.clone(),
+        pause: true,
+        changes: None,
+    }
+}
+
+/// Returns the daemon's message on error, so that it can be shown in the prompt
+pub async fn rename(docker: &Docker, id: &ContainerId, name: &str) -> Result<(), String> {
+    docker
+        .rename_container(
+            id.get(),
+            RenameContainerOptions {
+                name: name.to_owned(),
+            },
+        )
+        .await
+        .map_err(daemon_message)
+}
+
+/// Commit a container to a new image, returns the short id of the image
+/// Returns the daemon's message on error, so that it can be shown in the prompt
+pub async fn commit(
+    docker: &Docker,
+    id: &ContainerId,
+    spec: &CommitSpec,
+) -> Result<String, String> {
+    let response = docker
+        .commit_container(commit_options(id, spec), ContainerConfig::default())
+        .await
+        .map_err(daemon_message)?;
+    Ok(response
+        .id
+        .trim_start_matches("sha256:")
+        .chars()
+        .take(12)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::commit_options;
+    use crate::app_data::{CommitSpec, ContainerId};
+
+    #[test]
+    /// The repo, tag, author, & message are all passed on, and the container is paused
+    fn test_prompt_commit_options() {
+        let result = commit_options(
+            &ContainerId::from("1"),
+            &CommitSpec {
+                author: Some("oxker".to_owned()),
+                message: None,
+                repo: "debug/container_1".to_owned(),
+                tag: Some("snapshot".to_owned()),
+            },
+        );
+        assert_eq!(result.container, Some("1".to_owned()));
+        assert_eq!(result.repo, Some("debug/container_1".to_owned()));
+        assert_eq!(result.tag, Some("snapshot".to_owned()));
+        assert_eq!(result.author, Some("oxker".to_owned()));
+        assert_eq!(result.comment, None);
+        assert!(result.pause);
+    }
+}
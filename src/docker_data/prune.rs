@@ -0,0 +1,399 @@
+use std::collections::{HashMap, HashSet};
+
+use bollard::{
+    Docker,
+    models::{ContainerSummary, ImageSummary, Network, Volume},
+    query_parameters::{
+        DataUsageOptions, ListContainersOptions, ListImagesOptions, ListVolumesOptions,
+        PruneContainersOptions, PruneImagesOptions, PruneNetworksOptions, PruneVolumesOptions,
+    },
+};
+
+use crate::{
+    app_data::{PruneItem, PruneTarget},
+    app_error::AppError,
+};
+
+/// Networks created by Docker itself, these are never pruned
+const PREDEFINED_NETWORKS: [&str; 3] = ["bridge", "host", "none"];
+
+/// The images, networks, & volumes used by any container, running or not
+#[derive(Debug, Default)]
+struct InUse {
+    images: HashSet<String>,
+    networks: HashSet<String>,
+    volumes: HashSet<String>,
+}
+
+impl From<&[ContainerSummary]> for InUse {
+    fn from(containers: &[ContainerSummary]) -> Self {
+        let mut output = Self::default();
+        for container in containers {
+            if let Some(image_id) = container.image_id.as_ref() {
+                output.images.insert(image_id.clone());
+            }
+            if let Some(networks) = container
+                .network_settings
+                .as_ref()
+                .and_then(|i| i.networks.as_ref())
+            {
+                output.networks.extend(networks.keys().cloned());
+            }
+            for mount in container.mounts.iter().flatten() {
+                if let Some(name) = mount.name.as_ref() {
+                    output.volumes.insert(name.clone());
+                }
+            }
+        }
+        output
+    }
+}
+
+/// Convert a size, which Docker reports as -1 when unknown, into an Option
+fn size(value: Option<i64>) -> Option<u64> {
+    value.and_then(|i| u64::try_from(i).ok())
+}
+
+/// The first name of a container, without the leading slash, or the short id if it has no name
+fn container_item(container: ContainerSummary) -> PruneItem {
+    let name = container
+        .names
+        .and_then(|i| i.into_iter().next())
+        .map(|i| i.trim_start_matches('/').to_owned())
+        .or_else(|| container.id.map(|i| i.chars().take(12).collect()))
+        .unwrap_or_default();
+    PruneItem {
+        name,
+        size: size(container.size_rw),
+    }
+}
+
+/// The tags of an image, or the short id for an untagged image
+/// The size excludes layers shared with other images, as those aren't reclaimed
+fn image_item(image: ImageSummary) -> PruneItem {
+    let tags = image
+        .repo_tags
+        .into_iter()
+        .filter(|i| i != "<none>:<none>")
+        .collect::<Vec<_>>();
+    let name = if tags.is_empty() {
+        image
+            .id
+            .trim_start_matches("sha256:")
+            .chars()
+            .take(12)
+            .collect()
+    } else {
+        tags.join(", ")
+    };
+    let size = if image.shared_size >= 0 {
+        image.size.saturating_sub(image.shared_size)
+    } else {
+        image.size
+    };
+    PruneItem {
+        name,
+        size: u64::try_from(size).ok(),
+    }
+}
+
+/// Images that aren't used by any container
+fn unused_images(images: Vec<ImageSummary>, in_use: &InUse) -> Vec<PruneItem> {
+    images
+        .into_iter()
+        .filter(|i| !in_use.images.contains(&i.id))
+        .map(image_item)
+        .collect()
+}
+
+/// Custom networks that aren't used by any container
+fn unused_networks(networks: Vec<Network>, in_use: &InUse) -> Vec<PruneItem> {
+    networks
+        .into_iter()
+        .filter_map(|i| i.name)
+        .filter(|i| !PREDEFINED_NETWORKS.contains(&i.as_str()) && !in_use.networks.contains(i))
+        .map(|name| PruneItem { name, size: None })
+        .collect()
+}
+
+/// Volumes that aren't used by any container, with the size taken from the Docker disk usage, if known
+fn unused_volumes(
+    volumes: Vec<Volume>,
+    sizes: &HashMap<String, u64>,
+    in_use: &InUse,
+) -> Vec<PruneItem> {
+    volumes
+        .into_iter()
+        .filter(|i| !in_use.volumes.contains(&i.name))
+        .map(|i| PruneItem {
+            size: sizes.get(&i.name).copied(),
+            name: i.name,
+        })
+        .collect()
+}
+
+/// Filters, in the format Docker expects, of a single key & value
+fn filter(key: &str, value: &str) -> HashMap<String, Vec<String>> {
+    HashMap::from([(key.to_owned(), vec![value.to_owned()])])
+}
+
+/// Every container, used to work out which images, networks, & volumes are unused
+async fn all_containers(docker: &Docker) -> Result<Vec<ContainerSummary>, bollard::errors::Error> {
+    docker
+        .list_containers(Some(ListContainersOptions {
+            all: true,
+            ..Default::default()
+        }))
+        .await
+}
+
+/// The size of each volume, calculating these can be slow, and older Docker versions may not report them, so any error is ignored
+async fn volume_sizes(docker: &Docker) -> HashMap<String, u64> {
+    docker
+        .df(Some(DataUsageOptions {
+            _type: Some(vec!["volume".to_owned()]),
+            verbose: true,
+        }))
+        .await
+        .ok()
+        .and_then(|i| i.volumes_disk_usage)
+        .and_then(|i| i.items)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|i| serde_json::from_value::<Volume>(i).ok())
+        .filter_map(|i| Some((i.name, size(i.usage_data.map(|u| u.size))?)))
+        .collect()
+}
+
+/// List exactly what will be removed by a prune, without removing anything
+pub async fn preview(docker: &Docker, target: PruneTarget) -> Result<Vec<PruneItem>, AppError> {
+    let err = |_| AppError::DockerPrune(format!("list {target}"));
+    Ok(match target {
+        PruneTarget::Containers => docker
+            .list_containers(Some(ListContainersOptions {
+                all: true,
+                size: true,
+                filters: Some(HashMap::from([(
+                    "status".to_owned(),
+                    vec!["created".to_owned(), "exited".to_owned(), "dead".to_owned()],
+                )])),
+                ..Default::default()
+            }))
+            .await
+            .map_err(err)?
+            .into_iter()
+            .map(container_item)
+            .collect(),
+        PruneTarget::DanglingImages => docker
+            .list_images(Some(ListImagesOptions {
+                filters: Some(filter("dangling", "true")),
+                shared_size: true,
+                ..Default::default()
+            }))
+            .await
+            .map_err(err)?
+            .into_iter()
+            .map(image_item)
+            .collect(),
+        PruneTarget::UnusedImages => {
+            let in_use = InUse::from(all_containers(docker).await.map_err(err)?.as_slice());
+            let images = docker
+                .list_images(Some(ListImagesOptions {
+                    shared_size: true,
+                    ..Default::default()
+                }))
+                .await
+                .map_err(err)?;
+            unused_images(images, &in_use)
+        }
+        PruneTarget::Networks => {
+            let in_use = InUse::from(all_containers(docker).await.map_err(err)?.as_slice());
+            let networks = docker.list_networks(None).await.map_err(err)?;
+            unused_networks(networks, &in_use)
+        }
+        PruneTarget::Volumes => {
+            let in_use = InUse::from(all_containers(docker).await.map_err(err)?.as_slice());
+            let volumes = docker
+                .list_volumes(None::<ListVolumesOptions>)
+                .await
+                .map_err(err)?
+                .volumes
+                .unwrap_or_default();
+            unused_volumes(volumes, &volume_sizes(docker).await, &in_use)
+        }
+    })
+}
+
+/// Remove the unused objects, returns the number of objects removed, and the space reclaimed in bytes
+pub async fn prune(docker: &Docker, target: PruneTarget) -> Result<(usize, u64), AppError> {
+    let err = |_| AppError::DockerPrune(format!("prune {target}"));
+    let (removed, reclaimed) = match target {
+        PruneTarget::Containers => {
+            let response = docker
+                .prune_containers(None::<PruneContainersOptions>)
+                .await
+                .map_err(err)?;
+            (
+                response.containers_deleted.map(|i| i.len()),
+                response.space_reclaimed,
+            )
+        }
+        PruneTarget::DanglingImages | PruneTarget::UnusedImages => {
+            let dangling = if target == PruneTarget::DanglingImages {
+                "true"
+            } else {
+                "false"
+            };
+            let response = docker
+                .prune_images(Some(PruneImagesOptions {
+                    filters: Some(filter("dangling", dangling)),
+                }))
+                .await
+                .map_err(err)?;
+            (
+                response.images_deleted.map(|i| i.len()),
+                response.space_reclaimed,
+            )
+        }
+        PruneTarget::Networks => {
+            let response = docker
+                .prune_networks(None::<PruneNetworksOptions>)
+                .await
+                .map_err(err)?;
+            (response.networks_deleted.map(|i| i.len()), None)
+        }
+        PruneTarget::Volumes => {
+            // Without the all filter, only anonymous volumes are removed
+            let response = docker
+                .prune_volumes(Some(PruneVolumesOptions {
+                    filters: Some(filter("all", "true")),
+                }))
+                .await
+                .map_err(err)?;
+            (
+                response.volumes_deleted.map(|i| i.len()),
+                response.space_reclaimed,
+            )
+        }
+    };
+    Ok((
+        removed.unwrap_or_default(),
+        size(reclaimed).unwrap_or_default(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::models::{
+        ContainerSummary, ContainerSummaryNetworkSettings, ImageSummary, MountPoint, Network,
+        Volume,
+    };
+
+    use super::{InUse, container_item, unused_images, unused_networks, unused_volumes};
+
+    fn gen_image(id: &str, tags: &[&str], size: i64, shared_size: i64) -> ImageSummary {
+        ImageSummary {
+            id: id.to_owned(),
+            repo_tags: tags.iter().map(|i| (*i).to_owned()).collect(),
+            size,
+            shared_size,
+            ..Default::default()
+        }
+    }
+
+    fn gen_in_use() -> InUse {
+        InUse::from(
+            [ContainerSummary {
+                image_id: Some("sha256:used".to_owned()),
+                network_settings: Some(ContainerSummaryNetworkSettings {
+                    networks: Some(HashMap::from([(
+                        "used_network".to_owned(),
+                        Default::default(),
+                    )])),
+                }),
+                mounts: Some(vec![MountPoint {
+                    name: Some("used_volume".to_owned()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }]
+            .as_slice(),
+        )
+    }
+
+    #[test]
+    /// Images used by a container are excluded, untagged images use the short id, and shared layers aren't counted
+    fn test_prune_unused_images() {
+        let result = unused_images(
+            vec![
+                gen_image("sha256:used", &["used:latest"], 100, 0),
+                gen_image("sha256:0123456789abcdef", &["<none>:<none>"], 100, -1),
+                gen_image("sha256:tagged", &["a:1", "a:latest"], 100, 40),
+            ],
+            &gen_in_use(),
+        );
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "0123456789ab");
+        assert_eq!(result[0].size, Some(100));
+        assert_eq!(result[1].name, "a:1, a:latest");
+        assert_eq!(result[1].size, Some(60));
+    }
+
+    #[test]
+    /// Predefined networks, and networks used by a container, are excluded
+    fn test_prune_unused_networks() {
+        let network = |name: &str| Network {
+            name: Some(name.to_owned()),
+            ..Default::default()
+        };
+        let result = unused_networks(
+            vec![
+                network("bridge"),
+                network("host"),
+                network("none"),
+                network("used_network"),
+                network("unused_network"),
+            ],
+            &gen_in_use(),
+        );
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "unused_network");
+        assert!(result[0].size.is_none());
+    }
+
+    #[test]
+    /// Volumes used by a container are excluded, and sizes are only set if known
+    fn test_prune_unused_volumes() {
+        let volume = |name: &str| Volume {
+            name: name.to_owned(),
+            ..Default::default()
+        };
+        let sizes = HashMap::from([("unused_a".to_owned(), 2048)]);
+        let result = unused_volumes(
+            vec![
+                volume("used_volume"),
+                volume("unused_a"),
+                volume("unused_b"),
+            ],
+            &sizes,
+            &gen_in_use(),
+        );
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].size, Some(2048));
+        assert!(result[1].size.is_none());
+    }
+
+    #[test]
+    /// Container names have the leading slash removed, and an unknown size is None
+    fn test_prune_container_item() {
+        let result = container_item(ContainerSummary {
+            names: Some(vec!["/container_1".to_owned()]),
+            size_rw: Some(-1),
+            ..Default::default()
+        });
+        assert_eq!(result.name, "container_1");
+        assert!(result.size.is_none());
+    }
+}
@@ -0,0 +1,606 @@
+use std::{collections::HashMap, sync::Arc};
+
+use bollard::{
+    Docker,
+    models::{
+        ContainerCreateBody, ContainerInspectResponse, CreateImageInfo, EndpointSettings,
+        HostConfig, ImageConfig, MountPointTypeEnum, NetworkingConfig,
+    },
+    query_parameters::{
+        CreateContainerOptions, CreateImageOptions, RemoveContainerOptions, RenameContainerOptions,
+        StartContainerOptions, StopContainerOptions,
+    },
+};
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+
+use super::new_container::daemon_message;
+use crate::{
+    app_data::{AppData, ContainerId, Recreate, RecreateStage},
+    app_error::AppError,
+};
+
+/// Appended to the name of the old container while the new one is created, so that it can be restored if anything fails
+const BACKUP_SUFFIX: &str = "_oxker_old";
+
+/// Split an image into the reference & tag used to pull it
+/// Without a tag Docker would pull every tag of the image, so latest is used, and an image pinned to a digest is pulled as is
+pub(super) fn pull_reference(image: &str) -> (String, Option<String>) {
+    if image.contains('@') {
+        return (image.to_owned(), None);
+    }
+    let name_start = image.rfind('/').map_or(0, |i| i + 1);
+    image[name_start..].rfind(':').map_or_else(
+        || (image.to_owned(), Some("latest".to_owned())),
+        |i| {
+            let (reference, tag) = image.split_at(name_start + i);
+            (reference.to_owned(), Some(tag[1..].to_owned()))
+        },
+    )
+}
+
+/// The image the container was created from, None if it was created from an image id, as that can't be pulled
+fn image_name(inspect: &ContainerInspectResponse) -> Option<String> {
+    inspect
+        .config
+        .as_ref()
+        .and_then(|i| i.image.clone())
+        .filter(|i| {
+            let is_id = i.starts_with("sha256:")
+                || (i.len() >= 12 && i.chars().all(|c| c.is_ascii_hexdigit()));
+            !is_id
+        })
+}
+
+/// The destination of a bind, in the format `source:destination[:options]`
+fn bind_destination(bind: &str) -> Option<&str> {
+    bind.split(':').nth(1)
+}
+
+/// Anonymous volumes aren't in the host config, so bind them by name, else the new container would be given new, empty, volumes
+fn anonymous_volume_binds(
+    inspect: &ContainerInspectResponse,
+    host_config: &HostConfig,
+) -> Vec<String> {
+    let used = host_config
+        .binds
+        .iter()
+        .flatten()
+        .filter_map(|i| bind_destination(i))
+        .chain(
+            host_config
+                .mounts
+                .iter()
+                .flatten()
+                .filter_map(|i| i.target.as_deref()),
+        )
+        .collect::<Vec<_>>();
+    inspect
+        .mounts
+        .iter()
+        .flatten()
+        .filter(|i| i.typ == Some(MountPointTypeEnum::VOLUME))
+        .filter_map(|i| Some((i.name.as_deref()?, i.destination.as_deref()?)))
+        .filter(|(_, destination)| !used.contains(destination))
+        .map(|(name, destination)| format!("{name}:{destination}"))
+        .collect()
+}
+
+/// Only keep the user supplied network settings, the addresses & ids are assigned by Docker when the new container joins the network
+fn endpoint(settings: EndpointSettings, short_id: &str) -> EndpointSettings {
+    EndpointSettings {
+        aliases: settings.aliases.map(|i| {
+            i.into_iter()
+                .filter(|alias| alias != short_id)
+                .collect::<Vec<_>>()
+        }),
+        driver_opts: settings.driver_opts,
+        gw_priority: settings.gw_priority,
+        ipam_config: settings.ipam_config,
+        links: settings.links,
+        ..Default::default()
+    }
+}
+
+/// Remove a value if it's the same as the image default
+fn not_default<T: PartialEq>(value: Option<T>, default: Option<&T>) -> Option<T> {
+    value.filter(|i| Some(i) != default)
+}
+
+/// Remove every item that is also in the image defaults, None if no items remain
+fn without_defaults(
+    values: Option<Vec<String>>,
+    defaults: Option<&Vec<String>>,
+) -> Option<Vec<String>> {
+    let output = values?
+        .into_iter()
+        .filter(|i| defaults.is_none_or(|defaults| !defaults.contains(i)))
+        .collect::<Vec<_>>();
+    (!output.is_empty()).then_some(output)
+}
+
+/// Remove every label that has the same value as the image label, None if no labels remain
+fn labels_without_defaults(
+    labels: Option<HashMap<String, String>>,
+    defaults: Option<&HashMap<String, String>>,
+) -> Option<HashMap<String, String>> {
+    let output = labels?
+        .into_iter()
+        .filter(|(key, value)| defaults.and_then(|i| i.get(key)) != Some(value))
+        .collect::<HashMap<_, _>>();
+    (!output.is_empty()).then_some(output)
+}
+
+/// Rebuild the create config from the inspect data of the old container, using the given image
+/// The container config includes the defaults of the old image, so any value that is the same as the old image default is removed, else the new image's defaults would be overridden
+/// The cmd is only removed if the entrypoint is also the default, as Docker clears the image cmd when the entrypoint is set
+/// The hostname is only kept if it was set by the user, as Docker defaults it to the short container id
+fn create_body(
+    inspect: ContainerInspectResponse,
+    image_config: &ImageConfig,
+    image: &str,
+) -> ContainerCreateBody {
+    let short_id = inspect
+        .id
+        .as_deref()
+        .map(|i| i.chars().take(12).collect::<String>())
+        .unwrap_or_default();
+    let mut host_config = inspect.host_config.clone().unwrap_or_default();
+    let anonymous = anonymous_volume_binds(&inspect, &host_config);
+    if !anonymous.is_empty() {
+        host_config
+            .binds
+            .get_or_insert_with(Vec::new)
+            .extend(anonymous);
+    }
+    let networking_config = inspect
+        .network_settings
+        .and_then(|i| i.networks)
+        .map(|networks| NetworkingConfig {
+            endpoints_config: Some(
+                networks
+                    .into_iter()
+                    .map(|(name, settings)| (name, endpoint(settings, &short_id)))
+                    .collect(),
+            ),
+        });
+    let config = inspect.config.unwrap_or_default();
+    let entrypoint = not_default(config.entrypoint, image_config.entrypoint.as_ref());
+    let cmd = if entrypoint.is_some() {
+        config.cmd
+    } else {
+        not_default(config.cmd, image_config.cmd.as_ref())
+    };
+    ContainerCreateBody {
+        hostname: config.hostname.filter(|i| i != &short_id),
+        domainname: config.domainname,
+        user: not_default(config.user, image_config.user.as_ref()),
+        attach_stdin: config.attach_stdin,
+        attach_stdout: config.attach_stdout,
+        attach_stderr: config.attach_stderr,
+        exposed_ports: without_defaults(config.exposed_ports, image_config.exposed_ports.as_ref()),
+        tty: config.tty,
+        open_stdin: config.open_stdin,
+        stdin_once: config.stdin_once,
+        env: without_defaults(config.env, image_config.env.as_ref()),
+        cmd,
+        healthcheck: not_default(config.healthcheck, image_config.healthcheck.as_ref()),
+        args_escaped: not_default(config.args_escaped, image_config.args_escaped.as_ref()),
+        image: Some(image.to_owned()),
+        volumes: without_defaults(config.volumes, image_config.volumes.as_ref()),
+        working_dir: not_default(config.working_dir, image_config.working_dir.as_ref()),
+        entrypoint,
+        network_disabled: config.network_disabled,
+        on_build: not_default(config.on_build, image_config.on_build.as_ref()),
+        labels: labels_without_defaults(config.labels, image_config.labels.as_ref()),
+        stop_signal: not_default(config.stop_signal, image_config.stop_signal.as_ref()),
+        stop_timeout: config.stop_timeout,
+        shell: not_default(config.shell, image_config.shell.as_ref()),
+        host_config: Some(host_config),
+        networking_config,
+    }
+}
+
+/// Pull an image, calling `progress` with each message received, returns the daemon's message on error
+pub async fn pull_image(
+    docker: &Docker,
+    image: &str,
+    mut progress: impl FnMut(CreateImageInfo),
+) -> Result<(), String> {
+    let (reference, tag) = pull_reference(image);
+    let mut stream = docker.create_image(
+        Some(CreateImageOptions {
+            from_image: Some(reference),
+            tag,
+            ..Default::default()
+        }),
+        None,
+        None,
+    );
+    while let Some(message) = stream.next().await {
+        let message = message.map_err(daemon_message)?;
+        if let Some(error) = message.error_detail.as_ref() {
+            return Err(error.message.clone().unwrap_or_default());
+        }
+        progress(message);
+    }
+    Ok(())
+}
+
+/// Pull an image, only if it isn't already available locally
+pub async fn pull_if_missing(docker: &Docker, image: &str) -> Result<(), String> {
+    if docker.inspect_image(image).await.is_ok() {
+        return Ok(());
+    }
+    pull_image(docker, image, |_| ()).await
+}
+
+/// Pull the image, updating the layer progress as each message is received
+async fn pull(
+    docker: &Docker,
+    app_data: &Arc<Mutex<AppData>>,
+    image: &str,
+) -> Result<(), AppError> {
+    pull_image(docker, image, |message| {
+        let (current, total) = message
+            .progress_detail
+            .map_or((None, None), |i| (i.current, i.total));
+        app_data.lock().update_recreate(|i| {
+            i.pull_progress(message.id, message.status, current, total);
+        });
+    })
+    .await
+    .map_err(|_| AppError::DockerRecreate(format!("pull {image}")))
+}
+
+/// Put the old container back the way it was, removing the new container, if it was created
+/// Returns false if the old container couldn't be restored
+async fn rollback(
+    docker: &Docker,
+    id: &ContainerId,
+    name: &str,
+    new_id: Option<&str>,
+    was_running: bool,
+) -> bool {
+    if let Some(new_id) = new_id {
+        docker
+            .remove_container(
+                new_id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+            .ok();
+    }
+    if docker
+        .rename_container(
+            id.get(),
+            RenameContainerOptions {
+                name: name.to_owned(),
+            },
+        )
+        .await
+        .is_err()
+    {
+        return false;
+    }
+    !was_running
+        || docker
+            .start_container(id.get(), None::<StartContainerOptions>)
+            .await
+            .is_ok()
+}
+
+/// Create the new container, and start it if the old container was running
+/// Returns the new id, or the failed step and the id of the new container if it was created before the error
+async fn replace(
+    docker: &Docker,
+    app_data: &Arc<Mutex<AppData>>,
+    name: &str,
+    body: ContainerCreateBody,
+    start: bool,
+) -> Result<String, (&'static str, Option<String>)> {
+    app_data
+        .lock()
+        .update_recreate(|i| i.stage = RecreateStage::Creating);
+    let new_id = docker
+        .create_container(
+            Some(CreateContainerOptions {
+                name: Some(name.to_owned()),
+                ..Default::default()
+            }),
+            body,
+        )
+        .await
+        .map_err(|_| ("create the new container", None))?
+        .id;
+    if start {
+        app_data
+            .lock()
+            .update_recreate(|i| i.stage = RecreateStage::Starting);
+        docker
+            .start_container(&new_id, None::<StartContainerOptions>)
+            .await
+            .map_err(|_| ("start the new container", Some(new_id.clone())))?;
+    }
+    Ok(new_id)
+}
+
+/// Pull the image of a container, and replace the container with a new one, created from the same config, with the same name
+/// The old container is renamed, rather than removed, until the new container has started, so that it can be restored if anything fails
+/// The new container is only started if the old container was running
+pub async fn recreate(
+    docker: &Docker,
+    app_data: &Arc<Mutex<AppData>>,
+    id: &ContainerId,
+    stop_timeout: Option<i32>,
+) -> Result<(), AppError> {
+    let inspect = docker
+        .inspect_container(id.get(), None)
+        .await
+        .map_err(|_| AppError::DockerRecreate("inspect the container".to_owned()))?;
+    let name = inspect
+        .name
+        .as_deref()
+        .unwrap_or_default()
+        .trim_start_matches('/')
+        .to_owned();
+    let image = image_name(&inspect).ok_or_else(|| {
+        AppError::DockerRecreate("pull an image that is referenced by id".to_owned())
+    })?;
+    let was_running = inspect
+        .state
+        .as_ref()
+        .and_then(|i| i.running)
+        .unwrap_or_default();
+
+    // The defaults of the image the old container was created from, inspected by id, before the pull moves the name to the new image
+    let image_config = match inspect.image.as_deref() {
+        Some(old_image) => docker
+            .inspect_image(old_image)
+            .await
+            .map_err(|_| AppError::DockerRecreate("inspect the old image".to_owned()))?
+            .config
+            .unwrap_or_default(),
+        None => ImageConfig::default(),
+    };
+
+    app_data
+        .lock()
+        .set_recreate(Recreate::new(name.clone(), image.clone()));
+    pull(docker, app_data, &image).await?;
+
+    app_data
+        .lock()
+        .update_recreate(|i| i.stage = RecreateStage::Stopping);
+    if was_running {
+        docker
+            .stop_container(
+                id.get(),
+                Some(StopContainerOptions {
+                    t: stop_timeout,
+                    signal: None,
+                }),
+            )
+            .await
+            .map_err(|_| AppError::DockerRecreate("stop the old container".to_owned()))?;
+    }
+    let backup = format!("{name}{BACKUP_SUFFIX}");
+    if docker
+        .rename_container(
+            id.get(),
+            RenameContainerOptions {
+                name: backup.clone(),
+            },
+        )
+        .await
+        .is_err()
+    {
+        rollback(docker, id, &name, None, was_running).await;
+        return Err(AppError::DockerRecreate(
+            "rename the old container, it has been restored".to_owned(),
+        ));
+    }
+
+    if let Err((step, new_id)) = replace(
+        docker,
+        app_data,
+        &name,
+        create_body(inspect, &image_config, &image),
+        was_running,
+    )
+    .await
+    {
+        app_data
+            .lock()
+            .update_recreate(|i| i.stage = RecreateStage::RollingBack);
+        let restored = if rollback(docker, id, &name, new_id.as_deref(), was_running).await {
+            "it has been restored".to_owned()
+        } else {
+            format!("unable to restore it, it is named {backup}")
+        };
+        return Err(AppError::DockerRecreate(format!("{step}, {restored}")));
+    }
+
+    docker
+        .remove_container(
+            id.get(),
+            Some(RemoveContainerOptions {
+                force: true,
+                ..Default::default()
+            }),
+        )
+        .await
+        .map_err(|_| AppError::DockerRecreate(format!("remove the old container {backup}")))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::collections::HashMap;
+
+    use bollard::models::{
+        ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, ImageConfig,
+        MountPoint, MountPointTypeEnum, NetworkSettings, RestartPolicy, RestartPolicyNameEnum,
+    };
+
+    use super::{create_body, image_name, pull_reference};
+
+    const ID: &str = "0123456789abcdef0123456789abcdef";
+
+    fn gen_inspect() -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some(ID.to_owned()),
+            name: Some("/container_1".to_owned()),
+            config: Some(ContainerConfig {
+                hostname: Some("0123456789ab".to_owned()),
+                image: Some("redis:7".to_owned()),
+                env: Some(vec!["KEY=value".to_owned(), "PATH=/bin".to_owned()]),
+                cmd: Some(vec!["redis-server".to_owned()]),
+                working_dir: Some("/data".to_owned()),
+                labels: Some(HashMap::from([
+                    ("label".to_owned(), "value".to_owned()),
+                    ("image_label".to_owned(), "image".to_owned()),
+                ])),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                binds: Some(vec!["named:/data:rw".to_owned()]),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::UNLESS_STOPPED),
+                    maximum_retry_count: None,
+                }),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("named".to_owned()),
+                    destination: Some("/data".to_owned()),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("abcdef".to_owned()),
+                    destination: Some("/anonymous".to_owned()),
+                    ..Default::default()
+                },
+            ]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([(
+                    "network_1".to_owned(),
+                    EndpointSettings {
+                        aliases: Some(vec!["alias".to_owned(), "0123456789ab".to_owned()]),
+                        ip_address: Some("172.18.0.2".to_owned()),
+                        network_id: Some("network_id".to_owned()),
+                        ..Default::default()
+                    },
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn gen_image_config() -> ImageConfig {
+        ImageConfig {
+            env: Some(vec!["PATH=/bin".to_owned()]),
+            cmd: Some(vec!["redis-server".to_owned()]),
+            working_dir: Some("/data".to_owned()),
+            labels: Some(HashMap::from([(
+                "image_label".to_owned(),
+                "image".to_owned(),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    /// Images without a tag are pulled as latest, and registry ports aren't mistaken for tags
+    fn test_recreate_pull_reference() {
+        let result = |reference: &str, tag: Option<&str>| {
+            (
+                reference.to_owned(),
+                tag.map(std::borrow::ToOwned::to_owned),
+            )
+        };
+        assert_eq!(pull_reference("redis"), result("redis", Some("latest")));
+        assert_eq!(pull_reference("redis:7"), result("redis", Some("7")));
+        assert_eq!(
+            pull_reference("localhost:5000/app"),
+            result("localhost:5000/app", Some("latest"))
+        );
+        assert_eq!(
+            pull_reference("localhost:5000/app:1.2"),
+            result("localhost:5000/app", Some("1.2"))
+        );
+        assert_eq!(
+            pull_reference("redis@sha256:abc"),
+            result("redis@sha256:abc", None)
+        );
+    }
+
+    #[test]
+    /// Containers created from an image id can't be pulled
+    fn test_recreate_image_name() {
+        let mut inspect = gen_inspect();
+        assert_eq!(image_name(&inspect), Some("redis:7".to_owned()));
+        inspect.config.as_mut().unwrap().image = Some("sha256:abc".to_owned());
+        assert!(image_name(&inspect).is_none());
+        inspect.config.as_mut().unwrap().image = Some("0123456789ab".to_owned());
+        assert!(image_name(&inspect).is_none());
+    }
+
+    #[test]
+    /// The config, host config, & networks are copied, runtime values & image defaults are removed, and anonymous volumes are kept
+    fn test_recreate_create_body() {
+        let result = create_body(gen_inspect(), &gen_image_config(), "redis:7");
+        assert_eq!(result.image, Some("redis:7".to_owned()));
+        assert!(result.hostname.is_none());
+        assert_eq!(result.env, Some(vec!["KEY=value".to_owned()]));
+        assert!(result.cmd.is_none());
+        assert!(result.working_dir.is_none());
+        assert_eq!(
+            result.labels,
+            Some(HashMap::from([("label".to_owned(), "value".to_owned())]))
+        );
+
+        let host_config = result.host_config.unwrap();
+        assert_eq!(
+            host_config.restart_policy.unwrap().name,
+            Some(RestartPolicyNameEnum::UNLESS_STOPPED)
+        );
+        assert_eq!(
+            host_config.binds,
+            Some(vec![
+                "named:/data:rw".to_owned(),
+                "abcdef:/anonymous".to_owned()
+            ])
+        );
+
+        let endpoints = result.networking_config.unwrap().endpoints_config.unwrap();
+        let network = endpoints.get("network_1").unwrap();
+        assert_eq!(network.aliases, Some(vec!["alias".to_owned()]));
+        assert!(network.ip_address.is_none());
+        assert!(network.network_id.is_none());
+    }
+
+    #[test]
+    /// A changed default is kept, and the cmd is kept when the entrypoint has been changed, as Docker would otherwise clear it
+    fn test_recreate_create_body_changed_defaults() {
+        let mut inspect = gen_inspect();
+        let config = inspect.config.as_mut().unwrap();
+        config.env = Some(vec!["PATH=/usr/bin".to_owned()]);
+        config.entrypoint = Some(vec!["/entrypoint.sh".to_owned()]);
+        let result = create_body(inspect, &gen_image_config(), "redis:7");
+        assert_eq!(result.env, Some(vec!["PATH=/usr/bin".to_owned()]));
+        assert_eq!(result.entrypoint, Some(vec!["/entrypoint.sh".to_owned()]));
+        assert_eq!(result.cmd, Some(vec!["redis-server".to_owned()]));
+
+        let result = create_body(gen_inspect(), &ImageConfig::default(), "redis:7");
+        assert_eq!(result.cmd, Some(vec!["redis-server".to_owned()]));
+        assert_eq!(result.labels.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    fs::OpenOptions,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use bollard::{Docker, query_parameters::LogsOptions};
+use flate2::{Compression, write::GzEncoder};
+use serde::Serialize;
+
+use super::{DockerData, files::now};
+use crate::{
+    app_data::{LogStream, LogsTz, SaveFormat, SaveLines, SaveLogsSpec, SaveTarget},
+    app_error::AppError,
+    ui::log_sanitizer,
+};
+
+/// A single line of the NDJSON format, a struct so that the keys are always in the same order
+#[derive(Serialize)]
+struct NdjsonLine<'a> {
+    timestamp: String,
+    stream: String,
+    container: &'a str,
+    message: &'a str,
+}
+
+/// Format a single line, the raw docker line always has a timestamp, which is only kept in the plain & ANSI formats if `timestamp` is true
+fn format_line(
+    format: SaveFormat,
+    name: &str,
+    stream: LogStream,
+    (tz, content): (&LogsTz, &str),
+    timestamp: bool,
+) -> String {
+    let content = content.trim_end_matches(['\r', '\n']);
+    let content = match format {
+        SaveFormat::Ansi => content.to_owned(),
+        SaveFormat::Plain | SaveFormat::Ndjson => log_sanitizer::strip_ansi(content),
+    };
+    match format {
+        SaveFormat::Ndjson => serde_json::to_string(&NdjsonLine {
+            timestamp: tz.to_string(),
+            stream: stream.to_string(),
+            container: name,
+            message: &content,
+        })
+        .unwrap_or_default(),
+        SaveFormat::Plain | SaveFormat::Ansi if timestamp => format!("{tz} {content}"),
+        SaveFormat::Plain | SaveFormat::Ansi => content,
+    }
+}
+
+/// Writes each line, one per line, to the output, optionally gzipped
+enum LineWriter<W: Write> {
+    Plain(W),
+    Gzip(GzEncoder<W>),
+}
+
+impl<W: Write> LineWriter<W> {
+    fn new(output: W, gzip: bool) -> Self {
+        if gzip {
+            Self::Gzip(GzEncoder::new(output, Compression::default()))
+        } else {
+            Self::Plain(output)
+        }
+    }
+
+    fn write_line(&mut self, line: &str) -> std::io::Result<()> {
+        match self {
+            Self::Plain(output) => writeln!(output, "{line}"),
+            Self::Gzip(encoder) => writeln!(encoder, "{line}"),
+        }
+    }
+
+    /// Write the gzip footer, if gzipped, and flush the output
+    fn finish(self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(mut output) => output.flush(),
+            Self::Gzip(encoder) => encoder.finish()?.flush(),
+        }
+    }
+}
+
+/// Refetch every line of a container, passing each kept line to `on_line` as it arrives, docker only filters by whole seconds, so a range is also checked against the timestamp of each line
+async fn fetch(
+    docker: &Docker,
+    target: &SaveTarget,
+    (format, timestamp): (SaveFormat, bool),
+    mut on_line: impl FnMut(&str),
+) {
+    let (since, until) = match &target.lines {
+        SaveLines::Range((since, until)) => (
+            since.map_or(0, |i| i32::try_from(i.as_second()).unwrap_or_default()),
+            until.map_or(0, |i| {
+                i32::try_from(i.as_second().saturating_add(1)).unwrap_or_default()
+            }),
+        ),
+        SaveLines::All | SaveLines::Results(_) => (0, 0),
+    };
+    let options = LogsOptions {
+        stdout: true,
+        stderr: true,
+        timestamps: true,
+        since,
+        until,
+        ..Default::default()
+    };
+    DockerData::stream_logs(docker, &target.id, options, |(stream, data)| {
+        let (tz, content) = LogsTz::splitter(&data);
+        if target.lines.keeps(tz.timestamp()) {
+            on_line(&format_line(
+                format,
+                &target.name,
+                stream,
+                (&tz, &content),
+                timestamp,
+            ));
+        }
+    })
+    .await;
+}
+
+/// Save the logs of a single container to `[container_name]_[timestamp].[extension]`,
+/// or of multiple containers to `[container_name].[extension]` files in a `oxker_logs_[timestamp]` directory
+/// Each line is written as it is received, the file is only created once the first line to save arrives, so containers without any lines to save are skipped
+/// Returns the path of the file or directory, and the number of files written
+pub async fn save(
+    docker: &Docker,
+    spec: SaveLogsSpec,
+    dir_save: &Path,
+    timestamp: bool,
+) -> Result<(PathBuf, usize), AppError> {
+    let io_err = |e: std::io::Error| AppError::IO(e.to_string());
+    let extension = spec.format.extension(spec.gzip);
+    let now = now();
+    let output = if spec.dir {
+        let output = dir_save.join(format!("oxker_logs_{now}"));
+        std::fs::create_dir_all(&output).map_err(io_err)?;
+        output
+    } else {
+        dir_save.to_owned()
+    };
+
+    let mut count = 0;
+    let mut last_path = None;
+    for target in &spec.targets {
+        let path = if spec.dir {
+            output.join(format!("{}.{extension}", target.name))
+        } else {
+            output.join(format!("{}_{now}.{extension}", target.name))
+        };
+        let mut writer = None;
+        let mut error = None;
+        fetch(docker, target, (spec.format, timestamp), |line| {
+            if error.is_some() {
+                return;
+            }
+            if writer.is_none() {
+                match OpenOptions::new()
+                    .write(true)
+                    .create(true)
+                    .truncate(true)
+                    .open(&path)
+                {
+                    Ok(file) => writer = Some(LineWriter::new(BufWriter::new(file), spec.gzip)),
+                    Err(e) => {
+                        error = Some(e);
+                        return;
+                    }
+                }
+            }
+            if let Some(Err(e)) = writer.as_mut().map(|i| i.write_line(line)) {
+                error = Some(e);
+            }
+        })
+        .await;
+        if let Some(e) = error {
+            return Err(io_err(e));
+        }
+        if let Some(writer) = writer {
+            writer.finish().map_err(io_err)?;
+            count += 1;
+            last_path = Some(path);
+        }
+    }
+    let path = if spec.dir {
+        output
+    } else {
+        last_path.unwrap_or(output)
+    };
+    Ok((path, count))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+
+    use super::{LineWriter, format_line};
+    use crate::app_data::{LogStream, LogsTz, SaveFormat};
+
+    #[test]
+    /// Plain strips the ANSI codes, ANSI keeps them, and NDJSON has the timestamp, stream, container, and plain message of each line
+    fn test_save_logs_format_line() {
+        let (tz, content) =
+            LogsTz::splitter("2025-02-18T08:00:00.000000000Z \x1b[31mred\x1b[0m line\n");
+        let line = |format: SaveFormat, timestamp: bool| {
+            format_line(
+                format,
+                "container_1",
+                LogStream::Stderr,
+                (&tz, &content),
+                timestamp,
+            )
+        };
+        assert_eq!(line(SaveFormat::Plain, false), "red line");
+        assert_eq!(
+            line(SaveFormat::Plain, true),
+            "2025-02-18T08:00:00.000000000Z red line"
+        );
+        assert_eq!(line(SaveFormat::Ansi, false), "\x1b[31mred\x1b[0m line");
+        assert_eq!(
+            line(SaveFormat::Ndjson, false),
+            r#"{"timestamp":"2025-02-18T08:00:00.000000000Z","stream":"stderr","container":"container_1","message":"red line"}"#
+        );
+    }
+
+    #[test]
+    /// Each line is written on its own line, and the gzipped output decompresses to the same text
+    fn test_save_logs_write_lines() {
+        let write = |gzip: bool| {
+            let mut output = vec![];
+            let mut writer = LineWriter::new(&mut output, gzip);
+            writer.write_line("line 1").unwrap();
+            writer.write_line("line 2").unwrap();
+            writer.finish().unwrap();
+            output
+        };
+        let plain = write(false);
+        assert_eq!(plain, b"line 1\nline 2\n");
+
+        let gzip = write(true);
+        assert_ne!(gzip, plain);
+        let mut output = String::new();
+        GzDecoder::new(gzip.as_slice())
+            .read_to_string(&mut output)
+            .unwrap();
+        assert_eq!(output, "line 1\nline 2\n");
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use bollard::{
+    Docker,
+    models::{ContainerCreateBody, HostConfig},
+    query_parameters::{
+        CreateContainerOptions, ListContainersOptions, RemoveContainerOptions,
+        StartContainerOptions,
+    },
+};
+
+use crate::{app_data::ContainerId, app_error::AppError, docker_data::pull_if_missing};
+
+/// Label applied to every debug container, the value is the id of the container being debugged
+const LABEL: &str = "oxker.debug";
+
+/// Create & start a temporary container from the debug image, with the volumes of the target container mounted
+/// If the target is running, the pid & network namespaces are shared, these can't be joined on a stopped container
+/// The container is kept alive by an attached `sh`, so that it can then be exec-ed into, and is removed by Docker as soon as it stops
+pub async fn create(
+    docker: &Docker,
+    target: &ContainerId,
+    image: &str,
+    running: bool,
+) -> Result<ContainerId, AppError> {
+    pull_if_missing(docker, image)
+        .await
+        .map_err(|_| AppError::DockerDebug(image.to_owned()))?;
+    let namespace = running.then(|| format!("container:{}", target.get()));
+
+    let container = docker
+        .create_container(
+            None::<CreateContainerOptions>,
+            ContainerCreateBody {
+                image: Some(image.to_owned()),
+                cmd: Some(vec!["sh".to_owned()]),
+                tty: Some(true),
+                open_stdin: Some(true),
+                labels: Some(HashMap::from([(LABEL.to_owned(), target.get().to_owned())])),
+                host_config: Some(HostConfig {
+                    auto_remove: Some(true),
+                    network_mode: namespace.clone(),
+                    pid_mode: namespace,
+                    volumes_from: Some(vec![target.get().to_owned()]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|_| AppError::DockerDebug(image.to_owned()))?;
+
+    let id = ContainerId::from(container.id.as_str());
+    if docker
+        .start_container(id.get(), None::<StartContainerOptions>)
+        .await
+        .is_err()
+    {
+        remove(docker, &id).await;
+        return Err(AppError::DockerDebug(image.to_owned()));
+    }
+    Ok(id)
+}
+
+/// Force remove a debug container, volumes are left alone, as they belong to the container being debugged
+pub async fn remove(docker: &Docker, id: &ContainerId) {
+    docker
+        .remove_container(
+            id.get(),
+            Some(RemoveContainerOptions {
+                v: false,
+                force: true,
+                link: false,
+            }),
+        )
+        .await
+        .ok();
+}
+
+/// Remove every debug container, as one is left running if oxker exits, or crashes, during a debug session, executed at startup
+pub async fn remove_all(docker: &Docker) {
+    let containers = docker
+        .list_containers(Some(ListContainersOptions {
+            all: true,
+            filters: Some(HashMap::from([(
+                "label".to_owned(),
+                vec![LABEL.to_owned()],
+            )])),
+            ..Default::default()
+        }))
+        .await
+        .unwrap_or_default();
+    for id in containers.into_iter().filter_map(|i| i.id) {
+        remove(docker, &ContainerId::from(id.as_str())).await;
+    }
+}
@@ -7,7 +7,10 @@ use bollard::{
     Docker,
     exec::{CreateExecOptions, ResizeExecOptions, StartExecOptions, StartExecResults},
 };
-use crossterm::terminal::enable_raw_mode;
+use crossterm::{
+    event::{KeyCode, KeyModifiers},
+    terminal::enable_raw_mode,
+};
 use futures_util::StreamExt;
 use parking_lot::Mutex;
 use ratatui::layout::Size;
@@ -20,8 +23,14 @@ use tokio_util::sync::CancellationToken;
 use crate::{
     app_data::{AppData, ContainerId, RunningState, State},
     app_error::AppError,
+    ui::GuiState,
 };
 
+pub mod debug;
+mod terminal;
+pub use terminal::Screen;
+use terminal::{Emulator, key_to_bytes};
+
 /// TTY location
 const TTY: &str = "/dev/tty";
 
@@ -31,6 +40,9 @@ const OCI_ERROR: &str = "OCI runtime exec failed";
 /// Set the cursor position on the screen to (0,0)
 const CURSOR_POS: &str = "\x1B[J\x1B[H";
 
+/// How often to check the terminal size whilst in an exec session, so that resizes can be forwarded to the container
+const RESIZE_POLL: std::time::Duration = std::time::Duration::from_millis(250);
+
 /// This needs to be written to stdout when exiting the exec mode, else the input handler thread gets confused,
 /// see https://sw.kovidgoyal.net/kitty/keyboard-protocol/#progressive-enhancement
 const KEYBOARD_PROTO: &str = "\x1B[?u\x1B[c";
@@ -153,6 +165,8 @@ pub enum ExecMode {
     Internal((Arc<ContainerId>, Arc<Docker>)),
     // use the external `docker-cli`
     External(Arc<ContainerId>),
+    // use Bollard Rust library, with a temporary debug container, which is removed once the exec session has ended
+    Debug((Arc<ContainerId>, Arc<Docker>)),
 }
 
 impl ExecMode {
@@ -207,7 +221,48 @@ impl ExecMode {
         None
     }
 
+    /// Get the current size of the terminal, as a ratatui Size
+    fn terminal_size() -> Option<Size> {
+        crossterm::terminal::size()
+            .ok()
+            .map(|(width, height)| Size { width, height })
+    }
+
+    /// Forward the initial size to the exec tty, then keep checking for any size changes until the cancel_token is cancelled
+    /// A change in the size will then be forwarded to the exec tty, so that the likes of vim & htop continue to draw correctly
+    async fn resize_watcher(
+        docker: Arc<Docker>,
+        exec_id: String,
+        mut get_size: impl FnMut() -> Option<Size> + Send + 'static,
+        cancel_token: CancellationToken,
+    ) {
+        let mut current = None;
+        let mut next = get_size();
+        loop {
+            if let Some(size) = next
+                && current != Some(size)
+                && docker
+                    .resize_exec(
+                        &exec_id,
+                        ResizeExecOptions {
+                            height: size.height,
+                            width: size.width,
+                        },
+                    )
+                    .await
+                    .is_ok()
+            {
+                current = Some(size);
+            }
+            tokio::select! {
+                () = cancel_token.cancelled() => break,
+                () = tokio::time::sleep(RESIZE_POLL) => next = get_size(),
+            }
+        }
+    }
+
     /// exec into the container using the external docker cli, the result it just piped into oxker
+    /// As stdin/stdout are inherited, the docker cli handles any terminal resizes itself
     fn exec_external(id: &ContainerId) {
         let mut stdout = std::io::stdout();
         stdout.write_all(CURSOR_POS.as_bytes()).ok();
@@ -264,6 +319,13 @@ impl ExecMode {
                     mut input,
                 }) => {
                     if let Some(tty) = AsyncTTY::get(&cancel_token) {
+                        let mut initial_size = terminal_size;
+                        tokio::spawn(Self::resize_watcher(
+                            Arc::clone(docker),
+                            exec_result.id.clone(),
+                            move || initial_size.take().or_else(Self::terminal_size),
+                            cancel_token.clone(),
+                        ));
                         tokio::spawn(async move {
                             enable_raw_mode().ok();
                             let mut stdout = std::io::stdout();
@@ -276,19 +338,6 @@ impl ExecMode {
                             cancel_token.cancel();
                         });
 
-                        if let Some(terminal_size) = terminal_size {
-                            docker
-                                .resize_exec(
-                                    &exec_result.id,
-                                    ResizeExecOptions {
-                                        height: terminal_size.height,
-                                        width: terminal_size.width,
-                                    },
-                                )
-                                .await
-                                .ok();
-                        }
-
                         while let Ok(x) = tty.rx.recv() {
                             input.write_all(&[x]).await.ok();
                         }
@@ -310,7 +359,7 @@ impl ExecMode {
     fn internal_cleanup(&self) -> Result<(), AppError> {
         match self {
             Self::External(_) => Ok(()),
-            Self::Internal(_) => {
+            Self::Internal(_) | Self::Debug(_) => {
                 let waiting = Arc::new(AtomicBool::new(true));
                 let waiting_thread = Arc::clone(&waiting);
 
@@ -355,6 +404,184 @@ impl ExecMode {
             }
 
             Self::Internal((id, docker)) => self.exec_internal(id, docker, tty_size).await,
+            Self::Debug((id, docker)) => {
+                let result = self.exec_internal(id, docker, tty_size).await;
+                debug::remove(docker, id).await;
+                result
+            }
+        }
+    }
+}
+
+/// An exec session drawn inside a panel of the main ui, rather than taking over the whole terminal
+/// The tty output is parsed by a terminal emulator, and key presses are forwarded to the tty whilst the panel is selected
+#[derive(Debug, Clone)]
+pub struct EmbeddedExec {
+    cancel_token: CancellationToken,
+    emulator: Arc<Mutex<Emulator>>,
+    input_tx: tokio::sync::mpsc::UnboundedSender<Vec<u8>>,
+    name: String,
+    /// The output thread, which clears the session, and removes a debug container, once the exec ends or the session is cancelled
+    output_task: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+}
+
+impl EmbeddedExec {
+    /// Create & attach to an exec session, with stdin, stdout, and the tty size, all handled on their own tokio threads
+    /// Once the exec process ends, or the session is closed, the session is removed from the gui_state, and if it's a debug container, the container is removed
+    pub async fn start(
+        id: &ContainerId,
+        name: String,
+        docker: &Arc<Docker>,
+        gui_state: &Arc<Mutex<GuiState>>,
+        is_debug: bool,
+    ) -> Result<Self, AppError> {
+        let exec_result = docker
+            .create_exec(
+                id.get(),
+                CreateExecOptions {
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    attach_stdin: Some(true),
+                    tty: Some(true),
+                    cmd: Some(vec![command::SH]),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|_| AppError::DockerExec)?;
+
+        let Ok(StartExecResults::Attached {
+            mut output,
+            mut input,
+        }) = docker
+            .start_exec(
+                &exec_result.id,
+                Some(StartExecOptions {
+                    detach: false,
+                    ..Default::default()
+                }),
+            )
+            .await
+        else {
+            return Err(AppError::DockerExec);
+        };
+
+        let cancel_token = CancellationToken::new();
+        let emulator = Arc::new(Mutex::new(Emulator::default()));
+        let (input_tx, mut input_rx) = tokio::sync::mpsc::unbounded_channel::<Vec<u8>>();
+
+        let size_emulator = Arc::clone(&emulator);
+        tokio::spawn(ExecMode::resize_watcher(
+            Arc::clone(docker),
+            exec_result.id,
+            move || Some(size_emulator.lock().screen().size()),
+            cancel_token.clone(),
+        ));
+
+        let input_token = cancel_token.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    () = input_token.cancelled() => break,
+                    bytes = input_rx.recv() => {
+                        let Some(bytes) = bytes else {
+                            break;
+                        };
+                        if input.write_all(&bytes).await.is_err() || input.flush().await.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        let (output_emulator, output_token, output_tx, gui_state, docker, id) = (
+            Arc::clone(&emulator),
+            cancel_token.clone(),
+            input_tx.clone(),
+            Arc::clone(gui_state),
+            Arc::clone(docker),
+            id.clone(),
+        );
+        let output_task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    () = output_token.cancelled() => break,
+                    msg = output.next() => {
+                        let Some(Ok(msg)) = msg else {
+                            break;
+                        };
+                        let responses = {
+                            let mut emulator = output_emulator.lock();
+                            emulator.process(&msg.into_bytes());
+                            emulator.take_responses()
+                        };
+                        if !responses.is_empty() {
+                            output_tx.send(responses).ok();
+                        }
+                        gui_state.lock().redraw_embedded_exec();
+                    }
+                }
+            }
+            output_token.cancel();
+            gui_state.lock().clear_embedded_exec();
+            if is_debug {
+                debug::remove(&docker, &id).await;
+            }
+        });
+
+        Ok(Self {
+            cancel_token,
+            emulator,
+            input_tx,
+            name,
+            output_task: Arc::new(Mutex::new(Some(output_task))),
+        })
+    }
+
+    /// Create a session that isn't attached to a container, with the given bytes already processed, for now only used by tests
+    #[cfg(test)]
+    pub fn test(name: &str, size: Size, bytes: &[u8]) -> Self {
+        let mut emulator = Emulator::new(size);
+        emulator.process(bytes);
+        Self {
+            cancel_token: CancellationToken::new(),
+            emulator: Arc::new(Mutex::new(emulator)),
+            input_tx: tokio::sync::mpsc::unbounded_channel().0,
+            name: name.to_owned(),
+            output_task: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Cancel the resize, input, & output threads, which ends the exec, and wait for the session to be cleared, and a debug container removed
+    pub async fn close(&self) {
+        self.cancel_token.cancel();
+        let output_task = self.output_task.lock().take();
+        if let Some(output_task) = output_task {
+            output_task.await.ok();
+        }
+    }
+
+    /// The name of the container the session is attached to
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get a copy of the current emulated screen
+    pub fn screen(&self) -> Screen {
+        self.emulator.lock().screen().clone()
+    }
+
+    /// Resize the emulated screen, the resize_watcher will then forward the new size to the exec tty
+    pub fn resize(&self, size: Size) {
+        self.emulator.lock().resize(size);
+    }
+
+    /// Convert a key press into bytes, and send to the exec tty
+    pub fn send_key(&self, key_code: KeyCode, modifier: KeyModifiers) {
+        let application_cursor = self.emulator.lock().screen().application_cursor();
+        if let Some(bytes) = key_to_bytes(key_code, modifier, application_cursor) {
+            self.input_tx.send(bytes).ok();
         }
     }
 }
@@ -0,0 +1,1035 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::Size,
+    style::{Color, Modifier, Style},
+};
+use vtparse::{CsiParam, VTActor, VTParser};
+
+/// Default size of the emulated screen, used until the exec panel has been drawn
+const DEFAULT_SIZE: Size = Size {
+    width: 80,
+    height: 24,
+};
+
+/// Response to a primary device attributes request, VT100 with advanced video option
+const DEVICE_ATTRIBUTES: &[u8] = b"\x1b[?1;2c";
+
+/// A single character cell of the emulated screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self {
+            ch: ' ',
+            style: Style::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub row: u16,
+    pub col: u16,
+}
+
+/// The state of the emulated screen, all output from the exec tty is parsed by the VTParser, which in turn calls the VTActor methods on this struct
+/// Every char is treated as being a single cell wide
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Screen {
+    application_cursor: bool,
+    autowrap: bool,
+    cursor: Cursor,
+    cursor_visible: bool,
+    grid: Vec<Vec<Cell>>,
+    pen: Style,
+    primary: Option<(Vec<Vec<Cell>>, Cursor)>,
+    responses: Vec<u8>,
+    saved_cursor: (Cursor, Style),
+    scroll_region: (u16, u16),
+    size: Size,
+    wrap_pending: bool,
+}
+
+impl Screen {
+    fn new(size: Size) -> Self {
+        Self {
+            application_cursor: false,
+            autowrap: true,
+            cursor: Cursor::default(),
+            cursor_visible: true,
+            grid: Self::blank_grid(size, Style::default()),
+            pen: Style::default(),
+            primary: None,
+            responses: vec![],
+            saved_cursor: (Cursor::default(), Style::default()),
+            scroll_region: (0, size.height.saturating_sub(1)),
+            size,
+            wrap_pending: false,
+        }
+    }
+
+    fn blank_grid(size: Size, style: Style) -> Vec<Vec<Cell>> {
+        (0..size.height)
+            .map(|_| Self::blank_row(size.width, style))
+            .collect()
+    }
+
+    fn blank_row(width: u16, style: Style) -> Vec<Cell> {
+        vec![Cell { ch: ' ', style }; usize::from(width)]
+    }
+
+    /// Erased cells keep the current background color, but nothing else from the pen
+    fn blank_style(&self) -> Style {
+        Style {
+            bg: self.pen.bg,
+            ..Style::default()
+        }
+    }
+
+    /// The rows of cells that make up the screen
+    pub fn rows(&self) -> &[Vec<Cell>] {
+        &self.grid
+    }
+
+    pub const fn cursor(&self) -> Cursor {
+        self.cursor
+    }
+
+    pub const fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// If true, the cursor keys should be sent as SS3 sequences, rather than CSI sequences
+    pub const fn application_cursor(&self) -> bool {
+        self.application_cursor
+    }
+
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// Resize a grid, removing lines from the top if the cursor would otherwise end up off screen
+    fn resize_grid(grid: &mut Vec<Vec<Cell>>, cursor: &mut Cursor, size: Size) {
+        if cursor.row >= size.height && size.height > 0 {
+            let remove = usize::from(cursor.row - size.height + 1);
+            grid.drain(..remove.min(grid.len()));
+            cursor.row = size.height - 1;
+        }
+        grid.resize_with(usize::from(size.height), || {
+            Self::blank_row(size.width, Style::default())
+        });
+        for row in grid.iter_mut() {
+            row.resize(usize::from(size.width), Cell::default());
+        }
+        cursor.row = cursor.row.min(size.height.saturating_sub(1));
+        cursor.col = cursor.col.min(size.width.saturating_sub(1));
+    }
+
+    /// Change the size of the screen, the scroll region is reset to the whole screen
+    fn resize(&mut self, size: Size) {
+        if size == self.size {
+            return;
+        }
+        Self::resize_grid(&mut self.grid, &mut self.cursor, size);
+        if let Some((grid, cursor)) = self.primary.as_mut() {
+            Self::resize_grid(grid, cursor, size);
+        }
+        self.size = size;
+        self.scroll_region = (0, size.height.saturating_sub(1));
+        self.wrap_pending = false;
+    }
+
+    const fn max_row(&self) -> u16 {
+        self.size.height.saturating_sub(1)
+    }
+
+    const fn max_col(&self) -> u16 {
+        self.size.width.saturating_sub(1)
+    }
+
+    fn set_cell(&mut self, ch: char) {
+        let style = self.pen;
+        if let Some(cell) = self
+            .grid
+            .get_mut(usize::from(self.cursor.row))
+            .and_then(|row| row.get_mut(usize::from(self.cursor.col)))
+        {
+            *cell = Cell { ch, style };
+        }
+    }
+
+    fn put_char(&mut self, ch: char) {
+        if self.size.width == 0 || self.size.height == 0 {
+            return;
+        }
+        if self.wrap_pending {
+            self.cursor.col = 0;
+            self.linefeed();
+        }
+        self.set_cell(ch);
+        if self.cursor.col >= self.max_col() {
+            self.wrap_pending = self.autowrap;
+        } else {
+            self.cursor.col += 1;
+        }
+    }
+
+    /// Move the cursor down a line, scrolling the scroll region if the cursor is on the bottom margin
+    fn linefeed(&mut self) {
+        self.wrap_pending = false;
+        if self.cursor.row == self.scroll_region.1 {
+            self.scroll_up(1);
+        } else if self.cursor.row < self.max_row() {
+            self.cursor.row += 1;
+        }
+    }
+
+    /// Move the cursor up a line, scrolling the scroll region if the cursor is on the top margin
+    fn reverse_index(&mut self) {
+        self.wrap_pending = false;
+        if self.cursor.row == self.scroll_region.0 {
+            self.scroll_down(1);
+        } else {
+            self.cursor.row = self.cursor.row.saturating_sub(1);
+        }
+    }
+
+    /// Scroll the given region up by n lines, blank lines are inserted at the bottom
+    fn scroll_region_up(&mut self, top: u16, bottom: u16, n: u16) {
+        let (top, bottom) = (usize::from(top), usize::from(bottom));
+        if top > bottom || bottom >= self.grid.len() {
+            return;
+        }
+        let n = usize::from(n).min(bottom - top + 1);
+        let blank = Self::blank_row(self.size.width, self.blank_style());
+        self.grid[top..=bottom].rotate_left(n);
+        for row in &mut self.grid[bottom + 1 - n..=bottom] {
+            row.clone_from(&blank);
+        }
+    }
+
+    /// Scroll the given region down by n lines, blank lines are inserted at the top
+    fn scroll_region_down(&mut self, top: u16, bottom: u16, n: u16) {
+        let (top, bottom) = (usize::from(top), usize::from(bottom));
+        if top > bottom || bottom >= self.grid.len() {
+            return;
+        }
+        let n = usize::from(n).min(bottom - top + 1);
+        let blank = Self::blank_row(self.size.width, self.blank_style());
+        self.grid[top..=bottom].rotate_right(n);
+        for row in &mut self.grid[top..top + n] {
+            row.clone_from(&blank);
+        }
+    }
+
+    fn scroll_up(&mut self, n: u16) {
+        self.scroll_region_up(self.scroll_region.0, self.scroll_region.1, n);
+    }
+
+    fn scroll_down(&mut self, n: u16) {
+        self.scroll_region_down(self.scroll_region.0, self.scroll_region.1, n);
+    }
+
+    /// Erase a range of cells on a single row, end is exclusive
+    fn erase_cells(&mut self, row: u16, start: u16, end: u16) {
+        let style = self.blank_style();
+        if let Some(row) = self.grid.get_mut(usize::from(row)) {
+            let end = usize::from(end).min(row.len());
+            let start = usize::from(start).min(end);
+            for cell in &mut row[start..end] {
+                *cell = Cell { ch: ' ', style };
+            }
+        }
+    }
+
+    fn erase_display(&mut self, mode: u16) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        let (width, height) = (self.size.width, self.size.height);
+        match mode {
+            0 => {
+                self.erase_cells(row, col, width);
+                for i in row.saturating_add(1)..height {
+                    self.erase_cells(i, 0, width);
+                }
+            }
+            1 => {
+                for i in 0..row {
+                    self.erase_cells(i, 0, width);
+                }
+                self.erase_cells(row, 0, col.saturating_add(1));
+            }
+            2 | 3 => {
+                for i in 0..height {
+                    self.erase_cells(i, 0, width);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn erase_line(&mut self, mode: u16) {
+        let (row, col) = (self.cursor.row, self.cursor.col);
+        match mode {
+            0 => self.erase_cells(row, col, self.size.width),
+            1 => self.erase_cells(row, 0, col.saturating_add(1)),
+            2 => self.erase_cells(row, 0, self.size.width),
+            _ => (),
+        }
+    }
+
+    /// Insert blank chars at the cursor, shifting the rest of the line to the right
+    fn insert_chars(&mut self, n: u16) {
+        let blank = Cell {
+            ch: ' ',
+            style: self.blank_style(),
+        };
+        let col = usize::from(self.cursor.col);
+        if let Some(row) = self.grid.get_mut(usize::from(self.cursor.row))
+            && col < row.len()
+        {
+            let n = usize::from(n).min(row.len() - col);
+            row[col..].rotate_right(n);
+            for cell in &mut row[col..col + n] {
+                *cell = blank;
+            }
+        }
+    }
+
+    /// Delete chars at the cursor, shifting the rest of the line to the left
+    fn delete_chars(&mut self, n: u16) {
+        let blank = Cell {
+            ch: ' ',
+            style: self.blank_style(),
+        };
+        let col = usize::from(self.cursor.col);
+        if let Some(row) = self.grid.get_mut(usize::from(self.cursor.row))
+            && col < row.len()
+        {
+            let n = usize::from(n).min(row.len() - col);
+            row[col..].rotate_left(n);
+            let len = row.len();
+            for cell in &mut row[len - n..] {
+                *cell = blank;
+            }
+        }
+    }
+
+    /// Insert or delete lines are only actioned if the cursor is inside the scroll region
+    const fn cursor_in_scroll_region(&self) -> bool {
+        self.cursor.row >= self.scroll_region.0 && self.cursor.row <= self.scroll_region.1
+    }
+
+    fn insert_lines(&mut self, n: u16) {
+        if self.cursor_in_scroll_region() {
+            self.scroll_region_down(self.cursor.row, self.scroll_region.1, n);
+            self.cursor.col = 0;
+        }
+    }
+
+    fn delete_lines(&mut self, n: u16) {
+        if self.cursor_in_scroll_region() {
+            self.scroll_region_up(self.cursor.row, self.scroll_region.1, n);
+            self.cursor.col = 0;
+        }
+    }
+
+    /// Move the cursor to an absolute position, 0 indexed
+    fn goto(&mut self, row: u16, col: u16) {
+        self.wrap_pending = false;
+        self.cursor.row = row.min(self.max_row());
+        self.cursor.col = col.min(self.max_col());
+    }
+
+    /// Switch to, or from, the alternate screen
+    fn alternate_screen(&mut self, enable: bool, save_cursor: bool) {
+        if enable && self.primary.is_none() {
+            if save_cursor {
+                self.saved_cursor = (self.cursor, self.pen);
+            }
+            let alternate = Self::blank_grid(self.size, Style::default());
+            self.primary = Some((std::mem::replace(&mut self.grid, alternate), self.cursor));
+        } else if !enable && let Some((grid, cursor)) = self.primary.take() {
+            self.grid = grid;
+            self.cursor = cursor;
+            if save_cursor {
+                (self.cursor, self.pen) = self.saved_cursor;
+            }
+        }
+        self.wrap_pending = false;
+    }
+
+    /// Reset the whole screen to its initial state, keeping the current size
+    fn reset(&mut self) {
+        *self = Self::new(self.size);
+    }
+
+    fn set_private_mode(&mut self, mode: u16, enable: bool) {
+        match mode {
+            1 => self.application_cursor = enable,
+            7 => self.autowrap = enable,
+            25 => self.cursor_visible = enable,
+            47 | 1047 => self.alternate_screen(enable, false),
+            1049 => self.alternate_screen(enable, true),
+            _ => (),
+        }
+    }
+
+    /// Convert an SGR color index (0-7) into a ratatui Color
+    const fn ansi_color(index: u16, bright: bool) -> Color {
+        match (index, bright) {
+            (0, false) => Color::Black,
+            (1, false) => Color::Red,
+            (2, false) => Color::Green,
+            (3, false) => Color::Yellow,
+            (4, false) => Color::Blue,
+            (5, false) => Color::Magenta,
+            (6, false) => Color::Cyan,
+            (7, false) => Color::Gray,
+            (0, true) => Color::DarkGray,
+            (1, true) => Color::LightRed,
+            (2, true) => Color::LightGreen,
+            (3, true) => Color::LightYellow,
+            (4, true) => Color::LightBlue,
+            (5, true) => Color::LightMagenta,
+            (6, true) => Color::LightCyan,
+            _ => Color::White,
+        }
+    }
+
+    /// Parse an extended color, either `5;n` or `2;r;g;b`, returns the color and the number of params used
+    fn extended_color(params: &[Option<u16>]) -> (Option<Color>, usize) {
+        let get = |i: usize| {
+            params
+                .get(i)
+                .copied()
+                .flatten()
+                .map_or(0, |i| u8::try_from(i).unwrap_or(u8::MAX))
+        };
+        match params.first().copied().flatten() {
+            Some(5) => (Some(Color::Indexed(get(1))), 2),
+            Some(2) => (Some(Color::Rgb(get(1), get(2), get(3))), 4),
+            _ => (None, params.len()),
+        }
+    }
+
+    /// Select Graphic Rendition, update the pen with the given params
+    fn sgr(&mut self, params: &[Option<u16>]) {
+        if params.is_empty() {
+            self.pen = Style::default();
+            return;
+        }
+        let mut index = 0;
+        while let Some(param) = params.get(index) {
+            index += 1;
+            match param.unwrap_or_default() {
+                0 => self.pen = Style::default(),
+                1 => self.pen = self.pen.add_modifier(Modifier::BOLD),
+                2 => self.pen = self.pen.add_modifier(Modifier::DIM),
+                3 => self.pen = self.pen.add_modifier(Modifier::ITALIC),
+                4 => self.pen = self.pen.add_modifier(Modifier::UNDERLINED),
+                5 | 6 => self.pen = self.pen.add_modifier(Modifier::SLOW_BLINK),
+                7 => self.pen = self.pen.add_modifier(Modifier::REVERSED),
+                8 => self.pen = self.pen.add_modifier(Modifier::HIDDEN),
+                9 => self.pen = self.pen.add_modifier(Modifier::CROSSED_OUT),
+                21 | 22 => self.pen = self.pen.remove_modifier(Modifier::BOLD | Modifier::DIM),
+                23 => self.pen = self.pen.remove_modifier(Modifier::ITALIC),
+                24 => self.pen = self.pen.remove_modifier(Modifier::UNDERLINED),
+                25 => self.pen = self.pen.remove_modifier(Modifier::SLOW_BLINK),
+                27 => self.pen = self.pen.remove_modifier(Modifier::REVERSED),
+                28 => self.pen = self.pen.remove_modifier(Modifier::HIDDEN),
+                29 => self.pen = self.pen.remove_modifier(Modifier::CROSSED_OUT),
+                i @ 30..=37 => self.pen.fg = Some(Self::ansi_color(i - 30, false)),
+                i @ 40..=47 => self.pen.bg = Some(Self::ansi_color(i - 40, false)),
+                i @ 90..=97 => self.pen.fg = Some(Self::ansi_color(i - 90, true)),
+                i @ 100..=107 => self.pen.bg = Some(Self::ansi_color(i - 100, true)),
+                38 => {
+                    let (color, used) = Self::extended_color(&params[index..]);
+                    self.pen.fg = color.or(self.pen.fg);
+                    index += used;
+                }
+                48 => {
+                    let (color, used) = Self::extended_color(&params[index..]);
+                    self.pen.bg = color.or(self.pen.bg);
+                    index += used;
+                }
+                39 => self.pen.fg = None,
+                49 => self.pen.bg = None,
+                _ => (),
+            }
+        }
+    }
+
+    /// Split the CSI params into the private marker (if any), and a list of numeric params, an empty param is None
+    fn parse_params(params: &[CsiParam]) -> (Option<u8>, Vec<Option<u16>>) {
+        let mut marker = None;
+        let mut output = vec![];
+        let mut current = None;
+        for param in params {
+            match param {
+                CsiParam::Integer(i) => {
+                    current = Some(u16::try_from(*i).unwrap_or(u16::MAX));
+                }
+                CsiParam::P(b';' | b':') => output.push(current.take()),
+                CsiParam::P(p) => marker = Some(*p),
+            }
+        }
+        if current.is_some() || !output.is_empty() {
+            output.push(current);
+        }
+        (marker, output)
+    }
+
+    /// Get the nth numeric param, with a default used when the param is missing, or is 0 and zero_is_default
+    fn param(params: &[Option<u16>], index: usize, default: u16) -> u16 {
+        match params.get(index).copied().flatten() {
+            Some(0) | None => default,
+            Some(i) => i,
+        }
+    }
+}
+
+impl VTActor for Screen {
+    fn print(&mut self, b: char) {
+        self.put_char(b);
+    }
+
+    fn execute_c0_or_c1(&mut self, control: u8) {
+        match control {
+            // BS
+            0x08 => {
+                self.wrap_pending = false;
+                self.cursor.col = self.cursor.col.saturating_sub(1);
+            }
+            // HT
+            0x09 => {
+                let next = (self.cursor.col / 8 + 1) * 8;
+                self.cursor.col = next.min(self.max_col());
+            }
+            // LF, VT, FF
+            0x0a..=0x0c => self.linefeed(),
+            // CR
+            0x0d => {
+                self.wrap_pending = false;
+                self.cursor.col = 0;
+            }
+            _ => (),
+        }
+    }
+
+    fn dcs_hook(&mut self, _: u8, _: &[i64], _: &[u8], _: bool) {}
+
+    fn dcs_put(&mut self, _: u8) {}
+
+    fn dcs_unhook(&mut self) {}
+
+    fn esc_dispatch(&mut self, _: &[i64], intermediates: &[u8], _: bool, byte: u8) {
+        if !intermediates.is_empty() {
+            return;
+        }
+        match byte {
+            b'7' => self.saved_cursor = (self.cursor, self.pen),
+            b'8' => {
+                let (cursor, pen) = self.saved_cursor;
+                self.pen = pen;
+                self.goto(cursor.row, cursor.col);
+            }
+            b'D' => self.linefeed(),
+            b'E' => {
+                self.linefeed();
+                self.cursor.col = 0;
+            }
+            b'M' => self.reverse_index(),
+            b'c' => self.reset(),
+            _ => (),
+        }
+    }
+
+    fn csi_dispatch(&mut self, params: &[CsiParam], _: bool, byte: u8) {
+        let (marker, params) = Self::parse_params(params);
+        let first = Self::param(&params, 0, 1);
+        let mode = params.first().copied().flatten().unwrap_or_default();
+        let (row, col) = (self.cursor.row, self.cursor.col);
+
+        match (marker, byte) {
+            (Some(b'?'), b'h') => {
+                for i in params.iter().flatten() {
+                    self.set_private_mode(*i, true);
+                }
+            }
+            (Some(b'?'), b'l') => {
+                for i in params.iter().flatten() {
+                    self.set_private_mode(*i, false);
+                }
+            }
+            (None, b'A') => self.goto(row.saturating_sub(first), col),
+            (None, b'B' | b'e') => self.goto(row.saturating_add(first), col),
+            (None, b'C' | b'a') => self.goto(row, col.saturating_add(first)),
+            (None, b'D') => self.goto(row, col.saturating_sub(first)),
+            (None, b'E') => self.goto(row.saturating_add(first), 0),
+            (None, b'F') => self.goto(row.saturating_sub(first), 0),
+            (None, b'G' | b'`') => self.goto(row, first - 1),
+            (None, b'H' | b'f') => self.goto(first - 1, Self::param(&params, 1, 1) - 1),
+            (None, b'd') => self.goto(first - 1, col),
+            (None, b'J') => self.erase_display(mode),
+            (None, b'K') => self.erase_line(mode),
+            (None, b'L') => self.insert_lines(first),
+            (None, b'M') => self.delete_lines(first),
+            (None, b'@') => self.insert_chars(first),
+            (None, b'P') => self.delete_chars(first),
+            (None, b'X') => self.erase_cells(row, col, col.saturating_add(first)),
+            (None, b'S') => self.scroll_up(first),
+            (None, b'T') => self.scroll_down(first),
+            (None, b'm') => self.sgr(&params),
+            (None, b'r') => {
+                let top = first - 1;
+                let bottom = Self::param(&params, 1, self.size.height)
+                    .min(self.size.height)
+                    .saturating_sub(1);
+                if top < bottom {
+                    self.scroll_region = (top, bottom);
+                    self.goto(0, 0);
+                }
+            }
+            (None, b's') => self.saved_cursor = (self.cursor, self.pen),
+            (None, b'u') => {
+                let (cursor, pen) = self.saved_cursor;
+                self.pen = pen;
+                self.goto(cursor.row, cursor.col);
+            }
+            (None, b'n') => match mode {
+                5 => self.responses.extend_from_slice(b"\x1b[0n"),
+                6 => self
+                    .responses
+                    .extend_from_slice(format!("\x1b[{};{}R", row + 1, col + 1).as_bytes()),
+                _ => (),
+            },
+            (None, b'c') if mode == 0 => self.responses.extend_from_slice(DEVICE_ATTRIBUTES),
+            _ => (),
+        }
+    }
+
+    fn osc_dispatch(&mut self, _: &[&[u8]]) {}
+
+    fn apc_dispatch(&mut self, _: Vec<u8>) {}
+}
+
+/// A VT100/ANSI terminal emulator, combines a vtparse parser with the emulated Screen
+pub struct Emulator {
+    parser: VTParser,
+    screen: Screen,
+}
+
+impl std::fmt::Debug for Emulator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Emulator")
+            .field("screen", &self.screen)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Emulator {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIZE)
+    }
+}
+
+impl Emulator {
+    pub fn new(size: Size) -> Self {
+        Self {
+            parser: VTParser::new(),
+            screen: Screen::new(size),
+        }
+    }
+
+    /// Parse output from the exec tty, updating the screen
+    pub fn process(&mut self, bytes: &[u8]) {
+        self.parser.parse(bytes, &mut self.screen);
+    }
+
+    pub const fn screen(&self) -> &Screen {
+        &self.screen
+    }
+
+    pub fn resize(&mut self, size: Size) {
+        self.screen.resize(size);
+    }
+
+    /// Take any replies to queries (such as a cursor position request) that need to be written back to the exec tty
+    pub fn take_responses(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.screen.responses)
+    }
+}
+
+/// Convert a key press into the bytes that a terminal would send for it, None if the key has no known representation
+pub fn key_to_bytes(
+    key_code: KeyCode,
+    modifier: KeyModifiers,
+    application_cursor: bool,
+) -> Option<Vec<u8>> {
+    // xterm style modifier param, 1 + shift(1) + alt(2) + control(4)
+    let modifier_param = 1
+        + u8::from(modifier.contains(KeyModifiers::SHIFT))
+        + u8::from(modifier.contains(KeyModifiers::ALT)) * 2
+        + u8::from(modifier.contains(KeyModifiers::CONTROL)) * 4;
+
+    let cursor_key = |c: char| {
+        if modifier_param > 1 {
+            format!("\x1b[1;{modifier_param}{c}").into_bytes()
+        } else if application_cursor {
+            format!("\x1bO{c}").into_bytes()
+        } else {
+            format!("\x1b[{c}").into_bytes()
+        }
+    };
+    let tilde_key = |n: u8| {
+        if modifier_param > 1 {
+            format!("\x1b[{n};{modifier_param}~").into_bytes()
+        } else {
+            format!("\x1b[{n}~").into_bytes()
+        }
+    };
+
+    let bytes = match key_code {
+        KeyCode::Char(c) => {
+            let mut bytes = vec![];
+            if modifier.contains(KeyModifiers::ALT) {
+                bytes.push(0x1b);
+            }
+            if modifier.contains(KeyModifiers::CONTROL) {
+                let control = match c.to_ascii_lowercase() {
+                    c @ 'a'..='z' => u8::try_from(c).unwrap_or_default() - b'a' + 1,
+                    '@' | ' ' | '2' => 0x00,
+                    '[' | '3' => 0x1b,
+                    '\\' | '4' => 0x1c,
+                    ']' | '5' => 0x1d,
+                    '^' | '6' => 0x1e,
+                    '_' | '7' | '/' => 0x1f,
+                    '?' | '8' => 0x7f,
+                    _ => return None,
+                };
+                bytes.push(control);
+            } else {
+                bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+            bytes
+        }
+        KeyCode::Enter => vec![b'\r'],
+        KeyCode::Tab => vec![b'\t'],
+        KeyCode::BackTab => b"\x1b[Z".to_vec(),
+        KeyCode::Backspace => vec![0x7f],
+        KeyCode::Esc => vec![0x1b],
+        KeyCode::Up => cursor_key('A'),
+        KeyCode::Down => cursor_key('B'),
+        KeyCode::Right => cursor_key('C'),
+        KeyCode::Left => cursor_key('D'),
+        KeyCode::Home => cursor_key('H'),
+        KeyCode::End => cursor_key('F'),
+        KeyCode::Insert => tilde_key(2),
+        KeyCode::Delete => tilde_key(3),
+        KeyCode::PageUp => tilde_key(5),
+        KeyCode::PageDown => tilde_key(6),
+        KeyCode::F(n @ 1..=4) => format!("\x1bO{}", char::from(b'P' + n - 1)).into_bytes(),
+        KeyCode::F(n @ 5..=12) => tilde_key(match n {
+            5 => 15,
+            6..=10 => n + 11,
+            _ => n + 12,
+        }),
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use ratatui::{
+        layout::Size,
+        style::{Color, Modifier},
+    };
+
+    use super::{Cursor, Emulator, key_to_bytes};
+
+    /// Create an emulator of a given size, and process the given input
+    fn gen_emulator(width: u16, height: u16, input: &str) -> Emulator {
+        let mut emulator = Emulator::new(Size { width, height });
+        emulator.process(input.as_bytes());
+        emulator
+    }
+
+    /// Get the text of each row of the screen
+    fn get_text(emulator: &Emulator) -> Vec<String> {
+        emulator
+            .screen()
+            .rows()
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.ch).collect::<String>())
+            .collect()
+    }
+
+    const fn cursor(row: u16, col: u16) -> Cursor {
+        Cursor { row, col }
+    }
+
+    #[test]
+    /// Plain text, CR, LF, BS & HT move the cursor correctly
+    fn test_exec_terminal_text() {
+        let emulator = gen_emulator(10, 3, "ab\r\ncd\x08e\tf");
+        assert_eq!(
+            get_text(&emulator),
+            ["ab        ", "ce      f ", "          "]
+        );
+        assert_eq!(emulator.screen().cursor(), cursor(1, 9));
+    }
+
+    #[test]
+    /// Text wraps at the end of a line, and the screen scrolls when the bottom is reached
+    fn test_exec_terminal_wrap_scroll() {
+        let emulator = gen_emulator(4, 2, "abcdefgh\r\nij");
+        assert_eq!(get_text(&emulator), ["efgh", "ij  "]);
+        assert_eq!(emulator.screen().cursor(), cursor(1, 2));
+
+        // autowrap disabled, last char is overwritten
+        let emulator = gen_emulator(4, 2, "\x1b[?7labcdef");
+        assert_eq!(get_text(&emulator), ["abcf", "    "]);
+    }
+
+    #[test]
+    /// Cursor movement sequences, and positions are clamped to the screen size
+    fn test_exec_terminal_cursor_movement() {
+        let mut emulator = gen_emulator(10, 5, "\x1b[3;4H");
+        assert_eq!(emulator.screen().cursor(), cursor(2, 3));
+        emulator.process(b"\x1b[A");
+        assert_eq!(emulator.screen().cursor(), cursor(1, 3));
+        emulator.process(b"\x1b[2B");
+        assert_eq!(emulator.screen().cursor(), cursor(3, 3));
+        emulator.process(b"\x1b[20C");
+        assert_eq!(emulator.screen().cursor(), cursor(3, 9));
+        emulator.process(b"\x1b[3D");
+        assert_eq!(emulator.screen().cursor(), cursor(3, 6));
+        emulator.process(b"\x1b[2G");
+        assert_eq!(emulator.screen().cursor(), cursor(3, 1));
+        emulator.process(b"\x1b[1d");
+        assert_eq!(emulator.screen().cursor(), cursor(0, 1));
+        emulator.process(b"\x1b[99;99H");
+        assert_eq!(emulator.screen().cursor(), cursor(4, 9));
+        emulator.process(b"\x1b[H");
+        assert_eq!(emulator.screen().cursor(), cursor(0, 0));
+        emulator.process(b"\x1b[;5H");
+        assert_eq!(emulator.screen().cursor(), cursor(0, 4));
+    }
+
+    #[test]
+    /// Save and restore the cursor, via both ESC and CSI sequences
+    fn test_exec_terminal_save_restore_cursor() {
+        let mut emulator = gen_emulator(10, 5, "\x1b[2;3H\x1b7\x1b[5;5H\x1b8");
+        assert_eq!(emulator.screen().cursor(), cursor(1, 2));
+        emulator.process(b"\x1b[4;4H\x1b[s\x1b[H\x1b[u");
+        assert_eq!(emulator.screen().cursor(), cursor(3, 3));
+    }
+
+    #[test]
+    /// Erase in display & erase in line
+    fn test_exec_terminal_erase() {
+        let input = "abcd\r\nefgh\r\nijkl\x1b[2;3H";
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[K");
+        assert_eq!(get_text(&emulator), ["abcd", "ef  ", "ijkl"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[1K");
+        assert_eq!(get_text(&emulator), ["abcd", "   h", "ijkl"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[2K");
+        assert_eq!(get_text(&emulator), ["abcd", "    ", "ijkl"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[J");
+        assert_eq!(get_text(&emulator), ["abcd", "ef  ", "    "]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[1J");
+        assert_eq!(get_text(&emulator), ["    ", "   h", "ijkl"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[2J");
+        assert_eq!(get_text(&emulator), ["    ", "    ", "    "]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[2X");
+        assert_eq!(get_text(&emulator), ["abcd", "ef  ", "ijkl"]);
+    }
+
+    #[test]
+    /// Insert & delete chars and lines
+    fn test_exec_terminal_insert_delete() {
+        let input = "abcd\r\nefgh\r\nijkl\x1b[2;2H";
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[2@");
+        assert_eq!(get_text(&emulator), ["abcd", "e  f", "ijkl"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[2P");
+        assert_eq!(get_text(&emulator), ["abcd", "eh  ", "ijkl"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[L");
+        assert_eq!(get_text(&emulator), ["abcd", "    ", "efgh"]);
+
+        let mut emulator = gen_emulator(4, 3, input);
+        emulator.process(b"\x1b[M");
+        assert_eq!(get_text(&emulator), ["abcd", "ijkl", "    "]);
+    }
+
+    #[test]
+    /// Scrolling is restricted to the scroll region, and reverse index scrolls down at the top margin
+    fn test_exec_terminal_scroll_region() {
+        let mut emulator = gen_emulator(2, 4, "a\r\nb\r\nc\r\nd");
+        emulator.process(b"\x1b[2;3r\x1b[3;1H\n");
+        assert_eq!(get_text(&emulator), ["a ", "c ", "  ", "d "]);
+
+        emulator.process(b"\x1b[2;1H\x1bM");
+        assert_eq!(get_text(&emulator), ["a ", "  ", "c ", "d "]);
+
+        emulator.process(b"\x1b[r\x1b[S");
+        assert_eq!(get_text(&emulator), ["  ", "c ", "d ", "  "]);
+
+        emulator.process(b"\x1b[T");
+        assert_eq!(get_text(&emulator), ["  ", "  ", "c ", "d "]);
+    }
+
+    #[test]
+    /// SGR sequences set the style of printed chars
+    fn test_exec_terminal_sgr() {
+        let emulator = gen_emulator(
+            8,
+            1,
+            "\x1b[1;31ma\x1b[0mb\x1b[4;42mc\x1b[24;49;39md\x1b[38;5;200me\x1b[48;2;1;2;3mf\x1b[93mg\x1b[7;0mh",
+        );
+        let row = &emulator.screen().rows()[0];
+
+        assert_eq!(row[0].style.fg, Some(Color::Red));
+        assert!(row[0].style.add_modifier.contains(Modifier::BOLD));
+
+        assert_eq!(row[1].style, ratatui::style::Style::default());
+
+        assert_eq!(row[2].style.bg, Some(Color::Green));
+        assert!(row[2].style.add_modifier.contains(Modifier::UNDERLINED));
+
+        assert_eq!(row[3].style.bg, None);
+        assert_eq!(row[3].style.fg, None);
+        assert!(!row[3].style.add_modifier.contains(Modifier::UNDERLINED));
+
+        assert_eq!(row[4].style.fg, Some(Color::Indexed(200)));
+        assert_eq!(row[5].style.bg, Some(Color::Rgb(1, 2, 3)));
+        assert_eq!(row[6].style.fg, Some(Color::LightYellow));
+        assert_eq!(row[7].style, ratatui::style::Style::default());
+    }
+
+    #[test]
+    /// Alternate screen is blank, and the primary screen is restored on exit
+    fn test_exec_terminal_alternate_screen() {
+        let mut emulator = gen_emulator(4, 2, "ab");
+        emulator.process(b"\x1b[?1049h");
+        assert_eq!(get_text(&emulator), ["    ", "    "]);
+        emulator.process(b"\x1b[2;1Hxyz");
+        assert_eq!(get_text(&emulator), ["    ", "xyz "]);
+        emulator.process(b"\x1b[?1049l");
+        assert_eq!(get_text(&emulator), ["ab  ", "    "]);
+        assert_eq!(emulator.screen().cursor(), cursor(0, 2));
+    }
+
+    #[test]
+    /// Private modes for cursor visibility & application cursor keys
+    fn test_exec_terminal_private_modes() {
+        let mut emulator = gen_emulator(4, 2, "\x1b[?25l\x1b[?1h");
+        assert!(!emulator.screen().cursor_visible());
+        assert!(emulator.screen().application_cursor());
+        emulator.process(b"\x1b[?25h\x1b[?1l");
+        assert!(emulator.screen().cursor_visible());
+        assert!(!emulator.screen().application_cursor());
+    }
+
+    #[test]
+    /// Cursor position & device attribute requests queue a response, which is only returned once
+    fn test_exec_terminal_responses() {
+        let mut emulator = gen_emulator(10, 5, "\x1b[3;4H\x1b[6n\x1b[c");
+        assert_eq!(emulator.take_responses(), b"\x1b[3;4R\x1b[?1;2c");
+        assert!(emulator.take_responses().is_empty());
+    }
+
+    #[test]
+    /// Full reset clears screen and cursor
+    fn test_exec_terminal_reset() {
+        let emulator = gen_emulator(4, 2, "\x1b[31mab\x1bc");
+        assert_eq!(get_text(&emulator), ["    ", "    "]);
+        assert_eq!(emulator.screen().cursor(), cursor(0, 0));
+    }
+
+    #[test]
+    /// Resizing keeps content, and keeps the cursor on screen
+    fn test_exec_terminal_resize() {
+        let mut emulator = gen_emulator(4, 3, "ab\r\ncd\r\nef");
+        emulator.resize(Size {
+            width: 3,
+            height: 2,
+        });
+        assert_eq!(get_text(&emulator), ["cd ", "ef "]);
+        assert_eq!(emulator.screen().cursor(), cursor(1, 2));
+
+        emulator.resize(Size {
+            width: 5,
+            height: 3,
+        });
+        assert_eq!(get_text(&emulator), ["cd   ", "ef   ", "     "]);
+        assert_eq!(
+            emulator.screen().size(),
+            Size {
+                width: 5,
+                height: 3
+            }
+        );
+    }
+
+    #[test]
+    /// Key presses are converted into the correct byte sequences
+    fn test_exec_terminal_key_to_bytes() {
+        let none = KeyModifiers::NONE;
+        let bytes = |code: KeyCode, modifier: KeyModifiers, app: bool| {
+            key_to_bytes(code, modifier, app).unwrap()
+        };
+
+        assert_eq!(bytes(KeyCode::Char('a'), none, false), b"a");
+        assert_eq!(bytes(KeyCode::Char('é'), none, false), "é".as_bytes());
+        assert_eq!(bytes(KeyCode::Char('c'), KeyModifiers::CONTROL, false), [3]);
+        assert_eq!(bytes(KeyCode::Char('D'), KeyModifiers::CONTROL, false), [4]);
+        assert_eq!(
+            bytes(KeyCode::Char('b'), KeyModifiers::ALT, false),
+            b"\x1bb"
+        );
+        assert_eq!(bytes(KeyCode::Enter, none, false), b"\r");
+        assert_eq!(bytes(KeyCode::Backspace, none, false), [0x7f]);
+        assert_eq!(bytes(KeyCode::Up, none, false), b"\x1b[A");
+        assert_eq!(bytes(KeyCode::Up, none, true), b"\x1bOA");
+        assert_eq!(
+            bytes(KeyCode::Left, KeyModifiers::CONTROL, true),
+            b"\x1b[1;5D"
+        );
+        assert_eq!(bytes(KeyCode::Delete, none, false), b"\x1b[3~");
+        assert_eq!(
+            bytes(KeyCode::PageDown, KeyModifiers::SHIFT, false),
+            b"\x1b[6;2~"
+        );
+        assert_eq!(bytes(KeyCode::F(1), none, false), b"\x1bOP");
+        assert_eq!(bytes(KeyCode::F(5), none, false), b"\x1b[15~");
+        assert_eq!(bytes(KeyCode::F(10), none, false), b"\x1b[21~");
+        assert_eq!(bytes(KeyCode::F(12), none, false), b"\x1b[24~");
+        assert!(key_to_bytes(KeyCode::Null, none, false).is_none());
+    }
+}
@@ -5,13 +5,10 @@ use std::{
     time::SystemTime,
 };
 
-use bollard::query_parameters::LogsOptions;
-use cansi::v3::categorise_text;
 use crossterm::{
     event::{DisableMouseCapture, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
     execute,
 };
-use futures_util::StreamExt;
 use parking_lot::Mutex;
 use ratatui::layout::Rect;
 use tokio::sync::mpsc::{Receiver, Sender};
@@ -19,11 +16,15 @@ use uuid::Uuid;
 
 mod message;
 use crate::{
-    app_data::{AppData, DockerCommand, Header, ScrollDirection},
+    app_data::{
+        AppData, ConnectForm, ContainerId, ContainerNetworks, ContainerProcesses, DeleteOption,
+        DockerCommand, FileBrowser, FileKind, Header, NewContainer, ProcessHeader, Prompt,
+        PromptSpec, Prune, ScrollDirection,
+    },
     app_error::AppError,
     config,
     docker_data::DockerMessage,
-    exec::{ExecMode, tty_readable},
+    exec::{EmbeddedExec, ExecMode, debug, tty_readable},
     ui::{DeleteButton, GuiState, SelectablePanel, Status, Ui},
 };
 pub use message::InputMessages;
@@ -38,6 +39,7 @@ pub struct InputHandler {
     is_running: Arc<AtomicBool>,
     mouse_capture: bool,
     rx: Receiver<InputMessages>,
+    yanked: Option<ContainerId>,
 }
 
 impl InputHandler {
@@ -58,6 +60,7 @@ impl InputHandler {
             keymap,
             rx,
             mouse_capture: true,
+            yanked: None,
         };
         inner.message_handler().await;
     }
@@ -76,11 +79,18 @@ impl InputHandler {
                     } else if !contains(Status::Error)
                         && !contains(Status::Help)
                         && !contains(Status::DeleteConfirm)
+                        && !contains(Status::KillSignal)
                         && !contains(Status::Filter)
                         && !contains(Status::SearchLogs)
+                        && !contains(Status::RunCommand)
+                        && !contains(Status::FilesUpload)
+                        && !contains(Status::NewContainer)
+                        && !contains(Status::Limits)
+                        && !contains(Status::Prompt)
+                        && !contains(Status::Networks)
                     {
                         // TODO handle state where you want to scroll log search results with the mouse wheel
-                        self.mouse_press(mouse_event, modifider);
+                        self.mouse_press(mouse_event, modifider).await;
                     }
                 }
             }
@@ -108,7 +118,7 @@ impl InputHandler {
         let id = self.gui_state.lock().get_delete_container();
         if let Some(id) = id {
             self.docker_tx
-                .send(DockerMessage::Control((DockerCommand::Delete, id)))
+                .send(DockerMessage::Control((DockerCommand::Delete, id, None)))
                 .await
                 .ok();
         }
@@ -127,9 +137,116 @@ impl InputHandler {
         }
     }
 
+    /// Open the one-off command prompt, if a container is selected
+    fn exec_command_key(&self) {
+        if self.app_data.lock().get_selected_container_id().is_some() {
+            self.gui_state.lock().command_input_clear();
+            self.gui_state.lock().status_push(Status::RunCommand);
+        }
+    }
+
+    /// Open the file browser for a container, and list the given directory
+    async fn open_file_browser(&self, id: ContainerId, name: String, path: String) {
+        self.app_data
+            .lock()
+            .set_file_browser(FileBrowser::new(id.clone(), name));
+        self.gui_state.lock().status_push(Status::Files);
+        self.docker_tx
+            .send(DockerMessage::FilesList((id, path)))
+            .await
+            .ok();
+    }
+
+    /// Open the file browser for the selected container, and list the root directory
+    async fn file_browser_key(&self) {
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, name)) = container {
+            self.open_file_browser(id, name, "/".to_owned()).await;
+        }
+    }
+
+    /// Request the process list of the selected container, the processes panel is opened once it has been retrieved
+    async fn processes_key(&self) {
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, name)) = container {
+            self.docker_tx
+                .send(DockerMessage::Processes((id, name)))
+                .await
+                .ok();
+        }
+    }
+
+    /// Open the prune menu, not available when oxker is running in a container, in the same way as the docker commands
+    fn prune_key(&self) {
+        if self.app_data.lock().is_oxker_in_container() {
+            return;
+        }
+        self.app_data.lock().set_prune(Prune::new());
+        self.gui_state.lock().status_push(Status::Prune);
+    }
+
+    /// Open the new container form, and request the images & networks that can be chosen
+    async fn new_container_key(&self) {
+        if self.app_data.lock().is_oxker_in_container() {
+            return;
+        }
+        self.app_data.lock().set_new_container(NewContainer::new());
+        self.gui_state.lock().status_push(Status::NewContainer);
+        self.docker_tx
+            .send(DockerMessage::NewContainerLists)
+            .await
+            .ok();
+    }
+
+    /// Request the current limits of the selected container, if it's running, the limits form is opened once they've been retrieved
+    async fn update_limits_key(&self) {
+        if self.app_data.lock().is_oxker_in_container() {
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, state, name)) = container
+            && state.is_alive()
+        {
+            self.docker_tx
+                .send(DockerMessage::Limits((id, name)))
+                .await
+                .ok();
+        }
+    }
+
+    /// Request every network, for the selected container, the networks popup is opened once they've been retrieved
+    async fn networks_key(&self) {
+        if self.app_data.lock().is_oxker_in_container() {
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, name)) = container {
+            self.docker_tx
+                .send(DockerMessage::Networks((id, name)))
+                .await
+                .ok();
+        }
+    }
+
+    /// Request the filesystem changes of the selected container, the changes panel is opened once they've been retrieved
+    async fn filesystem_diff_key(&self) {
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, _, name)) = container {
+            self.docker_tx
+                .send(DockerMessage::Changes((id, name)))
+                .await
+                .ok();
+        }
+    }
+
     /// Validate that one can exec into a Docker container
+    /// If embedded_exec is enabled, and the exec can be run via the Docker API, the session is drawn inside a panel, else it'll take over the whole terminal
+    /// Only a single embedded exec session can be active, if one already exists its panel is selected instead
     async fn exec_key(&self) {
         let is_oxker = self.app_data.lock().is_oxker();
+        if self.gui_state.lock().select_embedded_exec() {
+            return;
+        }
         if !is_oxker && tty_readable() {
             let uuid = Uuid::new_v4();
             GuiState::start_loading_animation(&self.gui_state, uuid);
@@ -137,23 +254,126 @@ impl InputHandler {
             self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
 
             if let Ok(docker) = rx.await {
-                (ExecMode::new(&self.app_data, &docker).await).map_or_else(
-                    || {
+                let embedded_exec = self.app_data.lock().config.embedded_exec;
+                match ExecMode::new(&self.app_data, &docker).await {
+                    Some(ExecMode::Internal((id, docker))) if embedded_exec => {
+                        let name = self
+                            .app_data
+                            .lock()
+                            .get_container_name_by_id(&id)
+                            .map_or_else(|| id.get().to_owned(), |i| i.get().to_owned());
+                        match EmbeddedExec::start(&id, name, &docker, &self.gui_state, false).await
+                        {
+                            Ok(embedded_exec) => {
+                                self.gui_state.lock().set_embedded_exec(embedded_exec);
+                            }
+                            Err(e) => {
+                                self.app_data
+                                    .lock()
+                                    .set_error(e, &self.gui_state, Status::Error);
+                            }
+                        }
+                    }
+                    Some(mode) => self.gui_state.lock().set_exec_mode(mode),
+                    None => {
                         self.app_data.lock().set_error(
                             AppError::DockerExec,
                             &self.gui_state,
                             Status::Error,
                         );
-                    },
-                    |mode| {
-                        self.gui_state.lock().set_exec_mode(mode);
-                    },
-                );
+                    }
+                }
             }
             self.gui_state.lock().stop_loading_animation(uuid);
         }
     }
 
+    /// Create a temporary debug container for the selected container, and then exec into it
+    /// Works on stopped containers, and on images without a shell, the debug container is removed once the exec session ends
+    async fn exec_debug_key(&self) {
+        let is_oxker = self.app_data.lock().is_oxker();
+        if self.gui_state.lock().select_embedded_exec() {
+            return;
+        }
+        let container = self.app_data.lock().get_selected_container_id_state_name();
+        if let Some((id, state, name)) = container
+            && !is_oxker
+            && tty_readable()
+        {
+            let uuid = Uuid::new_v4();
+            GuiState::start_loading_animation(&self.gui_state, uuid);
+            let (sx, rx) = tokio::sync::oneshot::channel();
+            self.docker_tx.send(DockerMessage::Exec(sx)).await.ok();
+
+            if let Ok(docker) = rx.await {
+                let (image, embedded_exec) = {
+                    let config = &self.app_data.lock().config;
+                    (config.debug_image.clone(), config.embedded_exec)
+                };
+                let result = match debug::create(&docker, &id, &image, state.is_alive()).await {
+                    Ok(debug_id) if embedded_exec => {
+                        match EmbeddedExec::start(
+                            &debug_id,
+                            format!("{name} (debug)"),
+                            &docker,
+                            &self.gui_state,
+                            true,
+                        )
+                        .await
+                        {
+                            Ok(embedded_exec) => {
+                                self.gui_state.lock().set_embedded_exec(embedded_exec);
+                                Ok(())
+                            }
+                            // The debug container is only removed once the exec ends, so remove it here, as the exec never started
+                            Err(e) => {
+                                debug::remove(&docker, &debug_id).await;
+                                Err(e)
+                            }
+                        }
+                    }
+                    Ok(debug_id) => {
+                        self.gui_state
+                            .lock()
+                            .set_exec_mode(ExecMode::Debug((Arc::new(debug_id), docker)));
+                        Ok(())
+                    }
+                    Err(e) => Err(e),
+                };
+                if let Err(e) = result {
+                    self.app_data
+                        .lock()
+                        .set_error(e, &self.gui_state, Status::Error);
+                }
+            }
+            self.gui_state.lock().stop_loading_animation(uuid);
+        }
+    }
+
+    /// Actions to take when the embedded exec panel is selected
+    /// All key presses are sent to the exec tty, apart from the select_next_panel & select_previous_panel keys, which move the focus out of the panel, and the exec_close key, which closes the session
+    async fn handle_embedded_exec(&self, key_code: KeyCode, modifier: KeyModifiers) {
+        if self.keymap.exec_close.0 == key_code || self.keymap.exec_close.1 == Some(key_code) {
+            let embedded_exec = self.gui_state.lock().get_embedded_exec();
+            if let Some(embedded_exec) = embedded_exec {
+                embedded_exec.close().await;
+            }
+        } else if self.keymap.select_next_panel.0 == key_code
+            || self.keymap.select_next_panel.1 == Some(key_code)
+        {
+            self.next_panel_key();
+        } else if self.keymap.select_previous_panel.0 == key_code
+            || self.keymap.select_previous_panel.1 == Some(key_code)
+        {
+            self.previous_panel_key();
+        } else {
+            let embedded_exec = self.gui_state.lock().get_embedded_exec();
+            if let Some(embedded_exec) = embedded_exec {
+                embedded_exec.send_key(key_code, modifier);
+            }
+        }
+    }
+
     /// Toggle the mouse capture (via input of the 'm' key)
     fn mouse_capture_key(&mut self) {
         let err = || {
@@ -182,84 +402,124 @@ impl InputHandler {
         self.mouse_capture = !self.mouse_capture;
     }
 
-    /// Save the currently selected containers logs into a `[container_name]_[timestamp].log` file
-    async fn save_logs(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let args = self.app_data.lock().config.clone();
-        let container = self.app_data.lock().get_selected_container_id_state_name();
-        if let Some((id, _, name)) = container
-            && let Some(log_path) = args.dir_save
-        {
-            let (sx, rx) = tokio::sync::oneshot::channel();
-            self.docker_tx.send(DockerMessage::Exec(sx)).await?;
-
-            let now = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .map_or(0, |i| i.as_secs());
-
-            let path = log_path.join(format!("{name}_{now}.log"));
-
-            let options = Some(LogsOptions {
-                stderr: true,
-                stdout: true,
-                timestamps: args.show_timestamp,
-                since: 0,
-                ..Default::default()
-            });
-            let mut logs = rx.await?.logs(id.get(), options);
-            let mut output = vec![];
-
-            while let Some(Ok(value)) = logs.next().await {
-                let data = value.to_string();
-                if !data.trim().is_empty() {
-                    output.push(
-                        categorise_text(&data)
-                            .into_iter()
-                            .map(|i| i.text)
-                            .collect::<String>(),
-                    );
-                }
-            }
-            if !output.is_empty() {
-                let mut stream = BufWriter::new(
-                    OpenOptions::new()
-                        .read(true)
-                        .write(true)
-                        .create(true)
-                        .truncate(true)
-                        .open(&path)?,
-                );
-
-                for line in &output {
-                    stream.write_all(line.as_bytes())?;
-                }
-                stream.flush()?;
+    /// Copy text to the clipboard, and show an info box, or an error if the text is too large, or the escape sequence can't be written
+    fn copy_to_clipboard(&self, text: &str, info: &str) {
+        match Ui::copy_to_clipboard(text) {
+            Ok(()) => self
+                .gui_state
+                .lock()
+                .set_info_box(&format!("✓ copied {info}")),
+            Err(e) => self
+                .app_data
+                .lock()
+                .set_error(e, &self.gui_state, Status::Error),
+        }
+    }
 
-                self.gui_state
-                    .lock()
-                    .set_info_box(&format!("saved to {}", path.display()));
+    /// Copy the selected log lines when the logs panel is selected, otherwise the id of the selected container, and the name when pressed again
+    fn yank_key(&mut self) {
+        let panel = self.gui_state.lock().get_selected_panel();
+        if panel == SelectablePanel::Logs {
+            let lines = self.app_data.lock().take_log_yank();
+            if !lines.is_empty() {
+                let info = if lines.len() == 1 {
+                    String::from("1 log line")
+                } else {
+                    format!("{} log lines", lines.len())
+                };
+                self.copy_to_clipboard(&lines.join("\n"), &info);
+            }
+        } else {
+            let container = self.app_data.lock().get_selected_container_id_state_name();
+            if let Some((id, _, name)) = container {
+                if self.yanked.as_ref() == Some(&id) {
+                    self.yanked = None;
+                    self.copy_to_clipboard(&name, "container name");
+                } else {
+                    self.copy_to_clipboard(id.get(), "container id");
+                    self.yanked = Some(id);
+                }
             }
         }
+    }
+
+    /// Copy the value of the top line of the inspect view
+    fn inspect_yank_key(&self) {
+        let offset = self.gui_state.lock().get_inspect_offset().y;
+        let value = self
+            .app_data
+            .lock()
+            .get_inspect_data()
+            .and_then(|i| i.value_at(offset));
+        if let Some((key, value)) = value {
+            let info = if key.is_empty() {
+                String::from("value")
+            } else {
+                key
+            };
+            self.copy_to_clipboard(&value, &info);
+        }
+    }
+
+    /// Write text into a `[name]_[timestamp].txt` file in the save directory, and show the path in an info box
+    /// Returns an error if there isn't a save directory
+    fn save_text(&self, name: &str, text: &str) -> Result<(), AppError> {
+        let io_err = |e: std::io::Error| AppError::IO(e.to_string());
+        let dir_save = self
+            .app_data
+            .lock()
+            .config
+            .dir_save
+            .clone()
+            .ok_or_else(|| AppError::IO("no save directory".to_owned()))?;
+        let now = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |i| i.as_secs());
+        let path = dir_save.join(format!("{name}_{now}.txt"));
+
+        let mut stream = BufWriter::new(
+            OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&path)
+                .map_err(io_err)?,
+        );
+        stream.write_all(text.as_bytes()).map_err(io_err)?;
+        stream.flush().map_err(io_err)?;
+
+        self.gui_state
+            .lock()
+            .set_info_box(&format!("saved to {}", path.display()));
         Ok(())
     }
 
-    /// Attempt to save the currently selected container logs to a file
-    async fn save_key(&self) {
-        let status = self.gui_state.lock().get_status();
-        let contains = |s: Status| status.contains(&s);
+    /// Save the output of a one-off command into a `[container_name]_[timestamp].txt` file
+    fn save_command_output(&self) -> Result<(), AppError> {
+        let command_output = self.app_data.lock().get_command_output();
+        if let Some(command_output) = command_output {
+            let mut text = format!("$ {}\n{}", command_output.command, command_output.output);
+            if let Some(exit_code) = command_output.exit_code {
+                text.push_str(&format!("\nexit code: {exit_code}\n"));
+            }
+            self.save_text(&command_output.name, &text)?;
+        }
+        Ok(())
+    }
 
-        if !contains(Status::Logs) {
-            self.gui_state.lock().status_push(Status::Logs);
-            let uuid = Uuid::new_v4();
-            GuiState::start_loading_animation(&self.gui_state, uuid);
-            if self.save_logs().await.is_err() {
-                self.app_data.lock().set_error(
-                    AppError::DockerLogs,
-                    &self.gui_state,
-                    Status::Error,
-                );
-            }
-            self.gui_state.lock().status_del(Status::Logs);
-            self.gui_state.lock().stop_loading_animation(uuid);
+    /// Save the filesystem changes that match the current filter into a `[container_name]_changes_[timestamp].txt` file
+    fn save_container_changes(&self) -> Result<(), AppError> {
+        let changes = self.app_data.lock().get_container_changes();
+        if let Some(changes) = changes {
+            self.save_text(&format!("{}_changes", changes.name), &changes.as_text())?;
+        }
+        Ok(())
+    }
+
+    /// Open the save logs popup for the selected container, only if a save directory is available
+    fn save_key(&self) {
+        if self.app_data.lock().config.dir_save.is_some() && self.app_data.lock().set_save_logs() {
+            self.gui_state.lock().status_push(Status::SaveLogs);
         }
     }
 
@@ -267,7 +527,15 @@ impl InputHandler {
     async fn enter_key(&self) {
         // This isn't great, just means you can't send docker commands before full initialization of the program
         let panel = self.gui_state.lock().get_selected_panel();
-        if panel == SelectablePanel::Commands {
+        if panel == SelectablePanel::Logs {
+            // A group of lines is expanded, or collapsed, otherwise every field of a JSON log line is shown in a popup
+            if self.app_data.lock().log_group_expand_toggle() {
+                return;
+            }
+            if self.app_data.lock().set_log_detail() {
+                self.gui_state.lock().status_push(Status::LogDetail);
+            }
+        } else if panel == SelectablePanel::Commands {
             let option_command = self.app_data.lock().selected_docker_controls();
 
             if let Some(command) = option_command {
@@ -275,8 +543,8 @@ impl InputHandler {
                 if self.app_data.lock().is_oxker_in_container() {
                     return;
                 }
-                let option_id = self.app_data.lock().get_selected_container_id();
-                if let Some(id) = option_id {
+                let option_container = self.app_data.lock().get_selected_container_id_state_name();
+                if let Some((id, _, name)) = option_container {
                     match command {
                         DockerCommand::Delete => self
                             .docker_tx
@@ -284,11 +552,27 @@ impl InputHandler {
                             .await
                             .ok(),
 
-                        _ => self
-                            .docker_tx
-                            .send(DockerMessage::Control((command, id)))
-                            .await
-                            .ok(),
+                        DockerCommand::Kill => {
+                            self.gui_state.lock().set_kill_container(Some(id));
+                            Some(())
+                        }
+                        DockerCommand::Rename | DockerCommand::Commit => {
+                            let prompt = if command == DockerCommand::Rename {
+                                Prompt::rename(id, name)
+                            } else {
+                                Prompt::commit(id, name)
+                            };
+                            self.app_data.lock().set_prompt(prompt);
+                            self.gui_state.lock().status_push(Status::Prompt);
+                            Some(())
+                        }
+                        _ => {
+                            let timeout = self.gui_state.lock().command_timeout_take();
+                            self.docker_tx
+                                .send(DockerMessage::Control((command, id, timeout)))
+                                .await
+                                .ok()
+                        }
                     };
                 }
             }
@@ -326,6 +610,25 @@ impl InputHandler {
         }
     }
 
+    /// Change the timeout of the selected stop or restart command, if the Commands panel is selected
+    /// The change is only used for the next time the command is sent, and starts from the config timeout, or the Docker default of 10 seconds
+    fn command_timeout_key(&self, increase: bool) {
+        let panel = self.gui_state.lock().get_selected_panel();
+        if panel == SelectablePanel::Commands {
+            let base = {
+                let app_data = self.app_data.lock();
+                match app_data.selected_docker_controls() {
+                    Some(DockerCommand::Restart) => app_data.config.restart_timeout,
+                    Some(DockerCommand::Stop) => app_data.config.stop_timeout,
+                    _ => return,
+                }
+            };
+            self.gui_state
+                .lock()
+                .command_timeout_change(increase, base.unwrap_or(10));
+        }
+    }
+
     /// Change the the "next" selectable panel
     /// If no containers, and on Commands panel, skip to next panel, as Commands panel isn't visible in this state
     fn next_panel_key(&self) {
@@ -340,12 +643,27 @@ impl InputHandler {
             .selectable_panel_previous(&self.app_data);
     }
 
-    fn scroll_start_key(&self) {
+    async fn scroll_start_key(&self) {
         let selected_panel = self.gui_state.lock().get_selected_panel();
         match selected_panel {
             SelectablePanel::Containers => self.app_data.lock().containers_start(),
-            SelectablePanel::Logs => self.app_data.lock().log_start(),
-            SelectablePanel::Commands => self.app_data.lock().docker_controls_start(),
+            SelectablePanel::Logs => {
+                self.app_data.lock().log_start();
+                self.logs_older().await;
+            }
+            SelectablePanel::Commands => {
+                self.gui_state.lock().command_timeout_take();
+                self.app_data.lock().docker_controls_start();
+            }
+            SelectablePanel::Exec => (),
+        }
+    }
+
+    /// Request older log lines for the selected container, when the first line is selected
+    async fn logs_older(&self) {
+        let id = self.app_data.lock().get_logs_older_id();
+        if let Some(id) = id {
+            self.docker_tx.send(DockerMessage::LogsOlder(id)).await.ok();
         }
     }
 
@@ -355,7 +673,11 @@ impl InputHandler {
         match selected_panel {
             SelectablePanel::Containers => self.app_data.lock().containers_end(),
             SelectablePanel::Logs => self.app_data.lock().log_end(),
-            SelectablePanel::Commands => self.app_data.lock().docker_controls_end(),
+            SelectablePanel::Commands => {
+                self.gui_state.lock().command_timeout_take();
+                self.app_data.lock().docker_controls_end();
+            }
+            SelectablePanel::Exec => (),
         }
     }
 
@@ -384,12 +706,22 @@ impl InputHandler {
         }
     }
 
-    /// Actions to take when Delete status active
+    /// Actions to take when Delete status active, the sort by name, state, & status keys toggle the delete options
     async fn handle_delete(&self, key_code: KeyCode) {
         if self.keymap.delete_confirm.0 == key_code
             || self.keymap.delete_confirm.1 == Some(key_code)
         {
             self.confirm_delete().await;
+        } else if let Some(option) = [
+            self.keymap.sort_by_name,
+            self.keymap.sort_by_state,
+            self.keymap.sort_by_status,
+        ]
+        .into_iter()
+        .zip(DeleteOption::ALL)
+        .find_map(|(key, option)| (key.0 == key_code || key.1 == Some(key_code)).then_some(option))
+        {
+            self.gui_state.lock().delete_option_toggle(option);
         } else if self.keymap.delete_deny.0 == key_code
             || self.keymap.delete_deny.1 == Some(key_code)
             || self.keymap.clear.0 == key_code
@@ -399,115 +731,959 @@ impl InputHandler {
         }
     }
 
-    /// Actions to take when Filter status active
-    fn handle_search_logs(&self, key_code: KeyCode, modifier: KeyModifiers) {
+    /// Actions to take when the kill signal picker is open, scroll to change the signal, Enter to send it
+    async fn handle_kill_signal(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Enter => {
+                let kill = self.gui_state.lock().get_kill_container();
+                if let Some(kill) = kill {
+                    self.docker_tx.send(DockerMessage::Kill(kill)).await.ok();
+                }
+                self.gui_state.lock().set_kill_container(None);
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().kill_signal_cycle(false);
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().kill_signal_cycle(true);
+            }
+            _ if self.keymap.delete_deny.0 == key_code
+                || self.keymap.delete_deny.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().set_kill_container(None);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when Filter status active
+    fn handle_search_logs(&self, key_code: KeyCode, modifier: KeyModifiers) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().logs_search_clear();
+                self.gui_state.lock().status_del(Status::SearchLogs);
+            }
+            _ if KeyCode::Enter == key_code
+                || self.keymap.log_search_mode.0 == key_code
+                || self.keymap.log_search_mode.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().status_del(Status::SearchLogs);
+            }
+
+            _ if self.keymap.scroll_back.0 == key_code
+                || self.keymap.scroll_back.1 == Some(key_code) =>
+            {
+                self.logs_horizontal_scroll(modifier, &ScrollDirection::Up);
+            }
+
+            _ if self.keymap.scroll_forward.0 == key_code
+                || self.keymap.scroll_forward.1 == Some(key_code) =>
+            {
+                self.logs_horizontal_scroll(modifier, &ScrollDirection::Down);
+            }
+
+            _ if self.keymap.scroll_down.0 == key_code => {
+                self.app_data
+                    .lock()
+                    .log_search_scroll(&ScrollDirection::Down);
+                // TODO should only do this is log_search_scroll returns some
+                // Need to wait til app_data and gui_data is combined
+                self.gui_state
+                    .lock()
+                    .set_logs_panel_selected(&self.app_data);
+                //
+            }
+
+            _ if self.keymap.scroll_up.0 == key_code => {
+                self.app_data.lock().log_search_scroll(&ScrollDirection::Up);
+                // TODO should only do this is log_search_scroll returns some
+                // Need to wait til app_data and gui_data is combined
+                self.gui_state
+                    .lock()
+                    .set_logs_panel_selected(&self.app_data);
+            }
+
+            // handle up and down keys
+            KeyCode::Backspace => {
+                self.app_data.lock().log_search_pop();
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().log_search_push(x);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when Filter status active
+    fn handle_inspect(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        match key_code {
+            _ if self.keymap.inspect.0 == key_code
+                || self.keymap.inspect.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.app_data.lock().clear_inspect_data();
+                self.gui_state.lock().clear_inspect_offset();
+                self.gui_state.lock().status_del(Status::Inspect);
+            }
+
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Down);
+            }
+
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Up);
+            }
+
+            _ if self.keymap.scroll_forward.0 == key_code
+                || self.keymap.scroll_forward.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Right);
+            }
+
+            _ if self.keymap.scroll_back.0 == key_code
+                || self.keymap.scroll_back.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Left);
+            }
+
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().clear_inspect_offset();
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().set_inspect_offset_y_to_max();
+            }
+            _ if self.keymap.yank.0 == key_code || self.keymap.yank.1 == Some(key_code) => {
+                self.inspect_yank_key();
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the one-off command prompt is active
+    /// Enter will send the command to be executed in the selected container, Esc will close the prompt
+    async fn handle_run_command(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.gui_state.lock().command_input_clear();
+                self.gui_state.lock().status_del(Status::RunCommand);
+            }
+            KeyCode::Enter => {
+                let command = self.gui_state.lock().get_command_input();
+                let id = self.app_data.lock().get_selected_container_id();
+                if let Some(id) = id
+                    && !command.trim().is_empty()
+                {
+                    self.gui_state.lock().command_input_clear();
+                    self.gui_state.lock().status_del(Status::RunCommand);
+                    self.docker_tx
+                        .send(DockerMessage::RunCommand((id, command)))
+                        .await
+                        .ok();
+                }
+            }
+            KeyCode::Backspace => {
+                self.gui_state.lock().command_input_pop();
+            }
+            KeyCode::Char(x) => {
+                self.gui_state.lock().command_input_push(x);
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the new container form is open
+    /// Every character is typed into the selected field, so only the arrow, tab, enter, backspace, & escape keys are used to navigate
+    async fn handle_new_container(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().clear_new_container();
+                self.gui_state.lock().status_del(Status::NewContainer);
+            }
+            KeyCode::Enter => {
+                let spec = self
+                    .app_data
+                    .lock()
+                    .update_new_container(NewContainer::validate)
+                    .flatten();
+                if let Some(spec) = spec {
+                    self.docker_tx
+                        .send(DockerMessage::NewContainer(spec))
+                        .await
+                        .ok();
+                }
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.app_data
+                    .lock()
+                    .update_new_container(|i| i.form.change_field(true));
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                self.app_data
+                    .lock()
+                    .update_new_container(|i| i.form.change_field(false));
+            }
+            KeyCode::Right => {
+                self.app_data
+                    .lock()
+                    .update_new_container(|i| i.choose(true));
+            }
+            KeyCode::Left => {
+                self.app_data
+                    .lock()
+                    .update_new_container(|i| i.choose(false));
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().update_new_container(|i| i.form.pop());
+            }
+            KeyCode::Char(x) => {
+                self.app_data
+                    .lock()
+                    .update_new_container(|i| i.form.push(x));
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the limits form is open, navigated in the same way as the new container form
+    async fn handle_limits(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().clear_limits();
+                self.gui_state.lock().status_del(Status::Limits);
+            }
+            KeyCode::Enter => {
+                let spec = self
+                    .app_data
+                    .lock()
+                    .update_limits(|i| i.validate().map(|spec| (i.id.clone(), spec)))
+                    .flatten();
+                if let Some(spec) = spec {
+                    self.docker_tx
+                        .send(DockerMessage::LimitsUpdate(spec))
+                        .await
+                        .ok();
+                }
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.app_data
+                    .lock()
+                    .update_limits(|i| i.form.change_field(true));
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                self.app_data
+                    .lock()
+                    .update_limits(|i| i.form.change_field(false));
+            }
+            KeyCode::Right => {
+                self.app_data.lock().update_limits(|i| i.choose(true));
+            }
+            KeyCode::Left => {
+                self.app_data.lock().update_limits(|i| i.choose(false));
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().update_limits(|i| i.form.pop());
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().update_limits(|i| i.form.push(x));
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the networks popup is open
+    /// Enter disconnects from an attached network, or opens the connect form for any other network, which takes every key press until submitted or closed
+    async fn handle_networks(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        let is = |key: (KeyCode, Option<KeyCode>)| key.0 == key_code || key.1 == Some(key_code);
+        let Some(networks) = self.app_data.lock().get_networks() else {
+            self.gui_state.lock().status_del(Status::Networks);
+            return;
+        };
+        if networks.connect.is_some() {
+            match key_code {
+                KeyCode::Esc => {
+                    self.app_data.lock().update_networks(|i| i.connect = None);
+                }
+                KeyCode::Enter => {
+                    let spec = self
+                        .app_data
+                        .lock()
+                        .update_networks(|i| i.connect.as_mut().and_then(ConnectForm::validate))
+                        .flatten();
+                    if let Some(spec) = spec {
+                        self.docker_tx
+                            .send(DockerMessage::NetworkConnect((networks.id, spec)))
+                            .await
+                            .ok();
+                    }
+                }
+                KeyCode::Up | KeyCode::Down | KeyCode::Tab | KeyCode::BackTab => {
+                    self.app_data
+                        .lock()
+                        .update_networks(|i| i.connect.as_mut().map(|i| i.form.change_field(true)));
+                }
+                KeyCode::Backspace => {
+                    self.app_data
+                        .lock()
+                        .update_networks(|i| i.connect.as_mut().map(|i| i.form.pop()));
+                }
+                KeyCode::Char(x) => {
+                    self.app_data
+                        .lock()
+                        .update_networks(|i| i.connect.as_mut().map(|i| i.form.push(x)));
+                }
+                _ => (),
+            }
+            return;
+        }
+        match key_code {
+            _ if is(self.keymap.scroll_down) || is(self.keymap.scroll_up) => {
+                let sd = if is(self.keymap.scroll_down) {
+                    ScrollDirection::Down
+                } else {
+                    ScrollDirection::Up
+                };
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_networks(|i| {
+                    for _ in 0..total {
+                        i.scroll(&sd);
+                    }
+                });
+            }
+            KeyCode::Enter => match networks.selected() {
+                Some(network) if network.connected => {
+                    self.docker_tx
+                        .send(DockerMessage::NetworkDisconnect((
+                            networks.id.clone(),
+                            network.name.clone(),
+                        )))
+                        .await
+                        .ok();
+                }
+                Some(_) => {
+                    self.app_data
+                        .lock()
+                        .update_networks(ContainerNetworks::open_connect);
+                }
+                None => (),
+            },
+            _ if is(self.keymap.networks) || is(self.keymap.clear) => {
+                self.app_data.lock().clear_networks();
+                self.gui_state.lock().status_del(Status::Networks);
+            }
+            _ if is(self.keymap.toggle_mouse_capture) => self.mouse_capture_key(),
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the rename, commit, or log time prompt is open
+    /// The log time prompt is applied straight away, and only needs Docker if older lines need to be fetched
+    async fn handle_prompt(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().clear_prompt();
+                self.gui_state.lock().status_del(Status::Prompt);
+            }
+            KeyCode::Enter => {
+                let spec = self
+                    .app_data
+                    .lock()
+                    .update_prompt(|i| i.validate().map(|spec| (i.id.clone(), spec)))
+                    .flatten();
+                let message = match spec {
+                    Some((id, PromptSpec::Rename(name))) => Some(DockerMessage::Rename((id, name))),
+                    Some((id, PromptSpec::Commit(spec))) => Some(DockerMessage::Commit((id, spec))),
+                    Some((id, PromptSpec::LogTime(spec))) => {
+                        let from = self.app_data.lock().log_time_apply(&id, spec);
+                        self.gui_state.lock().status_del(Status::Prompt);
+                        from.map(|from| DockerMessage::LogsFrom((id, from)))
+                    }
+                    None => None,
+                };
+                if let Some(message) = message {
+                    self.docker_tx.send(message).await.ok();
+                }
+            }
+            KeyCode::Down | KeyCode::Tab => {
+                self.app_data
+                    .lock()
+                    .update_prompt(|i| i.form.change_field(true));
+            }
+            KeyCode::Up | KeyCode::BackTab => {
+                self.app_data
+                    .lock()
+                    .update_prompt(|i| i.form.change_field(false));
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().update_prompt(|i| i.form.pop());
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().update_prompt(|i| i.form.push(x));
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the one-off command output popup is visible
+    fn handle_command_output(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        match key_code {
+            _ if self.keymap.exec_command.0 == key_code
+                || self.keymap.exec_command.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.app_data.lock().clear_command_output();
+                self.gui_state.lock().clear_inspect_offset();
+                self.gui_state.lock().status_del(Status::CommandOutput);
+            }
+
+            _ if self.keymap.save_logs.0 == key_code
+                || self.keymap.save_logs.1 == Some(key_code) =>
+            {
+                if let Err(e) = self.save_command_output() {
+                    self.app_data
+                        .lock()
+                        .set_error(e, &self.gui_state, Status::Error);
+                }
+            }
+
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Down);
+            }
+
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Up);
+            }
+
+            _ if self.keymap.scroll_forward.0 == key_code
+                || self.keymap.scroll_forward.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Right);
+            }
+
+            _ if self.keymap.scroll_back.0 == key_code
+                || self.keymap.scroll_back.1 == Some(key_code) =>
+            {
+                self.inspect_scroll(modifier, &ScrollDirection::Left);
+            }
+
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().clear_inspect_offset();
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.gui_state.lock().set_inspect_offset_y_to_max();
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the processes panel is open
+    /// The first five sort keys sort by each column, the panel keys change the signal, and Enter sends the signal to the selected process
+    async fn handle_processes(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        let is = |key: (KeyCode, Option<KeyCode>)| key.0 == key_code || key.1 == Some(key_code);
+        let sort_keys = [
+            self.keymap.sort_by_name,
+            self.keymap.sort_by_state,
+            self.keymap.sort_by_status,
+            self.keymap.sort_by_cpu,
+            self.keymap.sort_by_memory,
+        ];
+        if let Some(header) = sort_keys
+            .into_iter()
+            .zip(ProcessHeader::ALL)
+            .find_map(|(key, header)| is(key).then_some(header))
+        {
+            self.app_data
+                .lock()
+                .update_processes(None, |i| i.set_sort_by_header(header));
+            return;
+        }
+        match key_code {
+            _ if is(self.keymap.processes) || is(self.keymap.clear) => {
+                self.app_data.lock().clear_processes();
+                self.gui_state.lock().status_del(Status::Processes);
+            }
+            _ if is(self.keymap.scroll_down) => {
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_processes(None, |i| {
+                    for _ in 0..total {
+                        i.processes.scroll(&ScrollDirection::Down);
+                    }
+                });
+            }
+            _ if is(self.keymap.scroll_up) => {
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_processes(None, |i| {
+                    for _ in 0..total {
+                        i.processes.scroll(&ScrollDirection::Up);
+                    }
+                });
+            }
+            _ if is(self.keymap.scroll_start) => {
+                self.app_data
+                    .lock()
+                    .update_processes(None, |i| i.processes.start());
+            }
+            _ if is(self.keymap.scroll_end) => {
+                self.app_data
+                    .lock()
+                    .update_processes(None, |i| i.processes.end());
+            }
+            _ if is(self.keymap.sort_reset) => {
+                self.app_data
+                    .lock()
+                    .update_processes(None, ContainerProcesses::reset_sorted);
+            }
+            _ if is(self.keymap.select_next_panel) => {
+                self.app_data.lock().update_processes(None, |i| {
+                    if i.signals {
+                        i.signal = i.signal.cycle(true);
+                    }
+                });
+            }
+            _ if is(self.keymap.select_previous_panel) => {
+                self.app_data.lock().update_processes(None, |i| {
+                    if i.signals {
+                        i.signal = i.signal.cycle(false);
+                    }
+                });
+            }
+            _ if is(self.keymap.toggle_mouse_capture) => self.mouse_capture_key(),
+            KeyCode::Enter => {
+                let processes = self.app_data.lock().get_processes();
+                if let Some(processes) = processes
+                    && processes.signals
+                    && let Some(process) = processes.selected()
+                {
+                    self.docker_tx
+                        .send(DockerMessage::Signal((
+                            processes.id.clone(),
+                            process.pid,
+                            processes.signal,
+                        )))
+                        .await
+                        .ok();
+                }
+            }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the prune menu is open
+    /// Enter requests a preview of the selected target, and the preview must then be confirmed before anything is removed
+    async fn handle_prune(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        let is = |key: (KeyCode, Option<KeyCode>)| key.0 == key_code || key.1 == Some(key_code);
+        let Some(prune) = self.app_data.lock().get_prune() else {
+            self.gui_state.lock().status_del(Status::Prune);
+            return;
+        };
+        let close = || {
+            self.app_data.lock().clear_prune();
+            self.gui_state.lock().status_del(Status::Prune);
+        };
+        match key_code {
+            _ if is(self.keymap.scroll_down) || is(self.keymap.scroll_up) => {
+                let sd = if is(self.keymap.scroll_down) {
+                    ScrollDirection::Down
+                } else {
+                    ScrollDirection::Up
+                };
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_prune(|i| {
+                    for _ in 0..total {
+                        i.scroll(&sd);
+                    }
+                });
+            }
+            _ if is(self.keymap.toggle_mouse_capture) => self.mouse_capture_key(),
+            _ => match prune.preview {
+                Some(preview) => {
+                    if is(self.keymap.delete_confirm) {
+                        close();
+                        self.docker_tx
+                            .send(DockerMessage::Prune(preview.target))
+                            .await
+                            .ok();
+                    } else if is(self.keymap.delete_deny) || is(self.keymap.clear) {
+                        self.app_data.lock().update_prune(|i| i.preview = None);
+                    }
+                }
+                None => {
+                    if key_code == KeyCode::Enter
+                        && let Some(target) = prune.selected()
+                    {
+                        self.docker_tx
+                            .send(DockerMessage::PrunePreview(target))
+                            .await
+                            .ok();
+                    } else if is(self.keymap.prune) || is(self.keymap.clear) {
+                        close();
+                    }
+                }
+            },
+        }
+    }
+
+    /// Actions to take when the save logs popup is open
+    /// Left & right change the value of the selected row, and Enter saves the logs with the chosen options
+    async fn handle_save_logs(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        let is = |key: (KeyCode, Option<KeyCode>)| key.0 == key_code || key.1 == Some(key_code);
+        let close = || {
+            self.app_data.lock().clear_save_logs();
+            self.gui_state.lock().status_del(Status::SaveLogs);
+        };
+        match key_code {
+            _ if is(self.keymap.scroll_down) || is(self.keymap.scroll_up) => {
+                let sd = if is(self.keymap.scroll_down) {
+                    ScrollDirection::Down
+                } else {
+                    ScrollDirection::Up
+                };
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_save_logs(|i| {
+                    for _ in 0..total {
+                        i.scroll(&sd);
+                    }
+                });
+            }
+            KeyCode::Left | KeyCode::Right => {
+                self.app_data
+                    .lock()
+                    .update_save_logs(|i| i.cycle(key_code == KeyCode::Right));
+            }
+            KeyCode::Enter => {
+                let spec = self.app_data.lock().get_save_logs_spec();
+                close();
+                if let Some(spec) = spec {
+                    self.docker_tx
+                        .send(DockerMessage::SaveLogs(spec))
+                        .await
+                        .ok();
+                }
+            }
+            _ if is(self.keymap.toggle_mouse_capture) => self.mouse_capture_key(),
+            _ if is(self.keymap.save_logs) || is(self.keymap.clear) => close(),
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the log detail popup is open, Enter also closes it, as it's used to open it
+    fn handle_log_detail(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
         match key_code {
-            KeyCode::Esc => {
-                self.app_data.lock().logs_search_clear();
-                self.gui_state.lock().status_del(Status::SearchLogs);
-            }
-            _ if KeyCode::Enter == key_code
-                || self.keymap.log_search_mode.0 == key_code
-                || self.keymap.log_search_mode.1 == Some(key_code) =>
+            _ if self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code)
+                || KeyCode::Enter == key_code =>
             {
-                self.gui_state.lock().status_del(Status::SearchLogs);
+                self.app_data.lock().clear_log_detail();
+                self.gui_state.lock().status_del(Status::LogDetail);
             }
-
-            _ if self.keymap.scroll_back.0 == key_code
-                || self.keymap.scroll_back.1 == Some(key_code) =>
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
             {
-                self.logs_horizontal_scroll(modifier, &ScrollDirection::Up);
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_log_detail(|i| {
+                    for _ in 0..total {
+                        i.scroll(&ScrollDirection::Down);
+                    }
+                });
             }
-
-            _ if self.keymap.scroll_forward.0 == key_code
-                || self.keymap.scroll_forward.1 == Some(key_code) =>
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
             {
-                self.logs_horizontal_scroll(modifier, &ScrollDirection::Down);
-            }
-
-            _ if self.keymap.scroll_down.0 == key_code => {
-                self.app_data
-                    .lock()
-                    .log_search_scroll(&ScrollDirection::Down);
-                // TODO should only do this is log_search_scroll returns some
-                // Need to wait til app_data and gui_data is combined
-                self.gui_state
-                    .lock()
-                    .set_logs_panel_selected(&self.app_data);
-                //
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_log_detail(|i| {
+                    for _ in 0..total {
+                        i.scroll(&ScrollDirection::Up);
+                    }
+                });
             }
-
-            _ if self.keymap.scroll_up.0 == key_code => {
-                self.app_data.lock().log_search_scroll(&ScrollDirection::Up);
-                // TODO should only do this is log_search_scroll returns some
-                // Need to wait til app_data and gui_data is combined
-                self.gui_state
-                    .lock()
-                    .set_logs_panel_selected(&self.app_data);
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.app_data.lock().update_log_detail(|i| i.fields.start());
             }
-
-            // handle up and down keys
-            KeyCode::Backspace => {
-                self.app_data.lock().log_search_pop();
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.app_data.lock().update_log_detail(|i| i.fields.end());
             }
-            KeyCode::Char(x) => {
-                self.app_data.lock().log_search_push(x);
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
             }
             _ => (),
         }
     }
 
-    /// Actions to take when Filter status active
-    fn handle_inspect(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+    /// Actions to take when the filesystem changes panel is open
+    /// Directories are expanded & collapsed with Enter, or scroll_forward & scroll_back, and the change type filter is cycled with the panel keys
+    async fn handle_changes(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
         match key_code {
-            _ if self.keymap.inspect.0 == key_code
-                || self.keymap.inspect.1 == Some(key_code)
+            _ if self.keymap.filesystem_diff.0 == key_code
+                || self.keymap.filesystem_diff.1 == Some(key_code)
                 || self.keymap.clear.0 == key_code
                 || self.keymap.clear.1 == Some(key_code) =>
             {
-                self.app_data.lock().clear_inspect_data();
-                self.gui_state.lock().clear_inspect_offset();
-                self.gui_state.lock().status_del(Status::Inspect);
+                self.app_data.lock().clear_container_changes();
+                self.gui_state.lock().status_del(Status::Changes);
             }
-
             _ if self.keymap.scroll_down.0 == key_code
                 || self.keymap.scroll_down.1 == Some(key_code) =>
             {
-                self.inspect_scroll(modifier, &ScrollDirection::Down);
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_container_changes(|i| {
+                    for _ in 0..total {
+                        i.scroll(&ScrollDirection::Down);
+                    }
+                });
             }
-
             _ if self.keymap.scroll_up.0 == key_code
                 || self.keymap.scroll_up.1 == Some(key_code) =>
             {
-                self.inspect_scroll(modifier, &ScrollDirection::Up);
+                let total = self.get_modifier_total(modifier);
+                self.app_data.lock().update_container_changes(|i| {
+                    for _ in 0..total {
+                        i.scroll(&ScrollDirection::Up);
+                    }
+                });
+            }
+            _ if self.keymap.scroll_start.0 == key_code
+                || self.keymap.scroll_start.1 == Some(key_code) =>
+            {
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.rows.start());
+            }
+            _ if self.keymap.scroll_end.0 == key_code
+                || self.keymap.scroll_end.1 == Some(key_code) =>
+            {
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.rows.end());
+            }
+            _ if KeyCode::Enter == key_code => {
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.collapse(None));
             }
-
             _ if self.keymap.scroll_forward.0 == key_code
                 || self.keymap.scroll_forward.1 == Some(key_code) =>
             {
-                self.inspect_scroll(modifier, &ScrollDirection::Right);
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.collapse(Some(false)));
             }
-
             _ if self.keymap.scroll_back.0 == key_code
                 || self.keymap.scroll_back.1 == Some(key_code) =>
             {
-                self.inspect_scroll(modifier, &ScrollDirection::Left);
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.collapse(Some(true)));
+            }
+            _ if self.keymap.select_next_panel.0 == key_code
+                || self.keymap.select_next_panel.1 == Some(key_code) =>
+            {
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.cycle_filter(true));
+            }
+            _ if self.keymap.select_previous_panel.0 == key_code
+                || self.keymap.select_previous_panel.1 == Some(key_code) =>
+            {
+                self.app_data
+                    .lock()
+                    .update_container_changes(|i| i.cycle_filter(false));
+            }
+            _ if self.keymap.file_browser.0 == key_code
+                || self.keymap.file_browser.1 == Some(key_code) =>
+            {
+                let changes = self.app_data.lock().get_container_changes();
+                if let Some(changes) = changes {
+                    let path = changes.selected_dir();
+                    self.open_file_browser(changes.id, changes.name, path).await;
+                }
+            }
+            _ if self.keymap.save_logs.0 == key_code
+                || self.keymap.save_logs.1 == Some(key_code) =>
+            {
+                if let Err(e) = self.save_container_changes() {
+                    self.app_data
+                        .lock()
+                        .set_error(e, &self.gui_state, Status::Error);
+                }
             }
-
             _ if self.keymap.toggle_mouse_capture.0 == key_code
                 || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
             {
                 self.mouse_capture_key();
             }
+            _ => (),
+        }
+    }
+
+    /// Actions to take when the file browser is open
+    /// Directories are opened with Enter or scroll_forward, and files are previewed, the parent directory is opened with Backspace or scroll_back
+    /// The selected entry can be saved with the save_logs key, or saved as a tar archive with `t`, and `u` will open the upload prompt
+    async fn handle_files(&mut self, key_code: KeyCode, modifier: KeyModifiers) {
+        let Some(file_browser) = self.app_data.lock().get_file_browser() else {
+            self.gui_state.lock().status_del(Status::Files);
+            return;
+        };
+        let id = file_browser.id.clone();
+        let message = match key_code {
+            _ if self.keymap.file_browser.0 == key_code
+                || self.keymap.file_browser.1 == Some(key_code)
+                || self.keymap.clear.0 == key_code
+                || self.keymap.clear.1 == Some(key_code) =>
+            {
+                self.app_data.lock().clear_file_browser();
+                self.gui_state.lock().status_del(Status::Files);
+                None
+            }
+            _ if self.keymap.scroll_down.0 == key_code
+                || self.keymap.scroll_down.1 == Some(key_code) =>
+            {
+                for _ in 0..self.get_modifier_total(modifier) {
+                    self.app_data
+                        .lock()
+                        .file_browser_scroll(&ScrollDirection::Down);
+                }
+                None
+            }
+            _ if self.keymap.scroll_up.0 == key_code
+                || self.keymap.scroll_up.1 == Some(key_code) =>
+            {
+                for _ in 0..self.get_modifier_total(modifier) {
+                    self.app_data
+                        .lock()
+                        .file_browser_scroll(&ScrollDirection::Up);
+                }
+                None
+            }
             _ if self.keymap.scroll_start.0 == key_code
                 || self.keymap.scroll_start.1 == Some(key_code) =>
             {
-                self.gui_state.lock().clear_inspect_offset();
+                self.app_data.lock().file_browser_start();
+                None
             }
             _ if self.keymap.scroll_end.0 == key_code
                 || self.keymap.scroll_end.1 == Some(key_code) =>
             {
-                self.gui_state.lock().set_inspect_offset_y_to_max();
+                self.app_data.lock().file_browser_end();
+                None
+            }
+            _ if self.keymap.save_logs.0 == key_code
+                || self.keymap.save_logs.1 == Some(key_code) =>
+            {
+                file_browser
+                    .selected()
+                    .map(|(path, _)| DockerMessage::FilesSave((id, path, false)))
+            }
+            _ if self.keymap.toggle_mouse_capture.0 == key_code
+                || self.keymap.toggle_mouse_capture.1 == Some(key_code) =>
+            {
+                self.mouse_capture_key();
+                None
+            }
+            _ if KeyCode::Enter == key_code
+                || self.keymap.scroll_forward.0 == key_code
+                || self.keymap.scroll_forward.1 == Some(key_code) =>
+            {
+                file_browser.selected().map(|(path, kind)| match kind {
+                    FileKind::Dir | FileKind::Symlink => DockerMessage::FilesList((id, path)),
+                    FileKind::File => DockerMessage::FilesPreview((id, path)),
+                })
+            }
+            _ if KeyCode::Backspace == key_code
+                || self.keymap.scroll_back.0 == key_code
+                || self.keymap.scroll_back.1 == Some(key_code) =>
+            {
+                (file_browser.path != "/")
+                    .then(|| DockerMessage::FilesList((id, file_browser.parent())))
+            }
+            _ if self.keymap.file_save_tar.0 == key_code
+                || self.keymap.file_save_tar.1 == Some(key_code) =>
+            {
+                file_browser
+                    .selected()
+                    .map(|(path, _)| DockerMessage::FilesSave((id, path, true)))
+            }
+            _ if self.keymap.file_upload.0 == key_code
+                || self.keymap.file_upload.1 == Some(key_code) =>
+            {
+                self.app_data.lock().file_browser_upload_clear();
+                self.gui_state.lock().status_push(Status::FilesUpload);
+                None
+            }
+            _ => None,
+        };
+        if let Some(message) = message {
+            self.docker_tx.send(message).await.ok();
+        }
+    }
+
+    /// Actions to take when the file upload prompt is open, Enter will upload the local file into the current directory
+    async fn handle_files_upload(&self, key_code: KeyCode) {
+        match key_code {
+            KeyCode::Esc => {
+                self.app_data.lock().file_browser_upload_clear();
+                self.gui_state.lock().status_del(Status::FilesUpload);
+            }
+            KeyCode::Enter => {
+                let file_browser = self.app_data.lock().get_file_browser();
+                if let Some(file_browser) = file_browser
+                    && !file_browser.upload.trim().is_empty()
+                {
+                    self.app_data.lock().file_browser_upload_clear();
+                    self.gui_state.lock().status_del(Status::FilesUpload);
+                    self.docker_tx
+                        .send(DockerMessage::FilesUpload((
+                            file_browser.id,
+                            file_browser.path,
+                            std::path::PathBuf::from(file_browser.upload.trim()),
+                        )))
+                        .await
+                        .ok();
+                }
+            }
+            KeyCode::Backspace => {
+                self.app_data.lock().file_browser_upload_pop();
+            }
+            KeyCode::Char(x) => {
+                self.app_data.lock().file_browser_upload_push(x);
             }
             _ => (),
         }
@@ -635,6 +1811,56 @@ impl InputHandler {
                 self.exec_key().await;
             }
 
+            _ if self.keymap.exec_command.0 == key_code
+                || self.keymap.exec_command.1 == Some(key_code) =>
+            {
+                self.exec_command_key();
+            }
+
+            _ if self.keymap.exec_debug.0 == key_code
+                || self.keymap.exec_debug.1 == Some(key_code) =>
+            {
+                self.exec_debug_key().await;
+            }
+
+            _ if self.keymap.file_browser.0 == key_code
+                || self.keymap.file_browser.1 == Some(key_code) =>
+            {
+                self.file_browser_key().await;
+            }
+
+            _ if self.keymap.filesystem_diff.0 == key_code
+                || self.keymap.filesystem_diff.1 == Some(key_code) =>
+            {
+                self.filesystem_diff_key().await;
+            }
+
+            _ if self.keymap.processes.0 == key_code
+                || self.keymap.processes.1 == Some(key_code) =>
+            {
+                self.processes_key().await;
+            }
+
+            _ if self.keymap.prune.0 == key_code || self.keymap.prune.1 == Some(key_code) => {
+                self.prune_key();
+            }
+
+            _ if self.keymap.new_container.0 == key_code
+                || self.keymap.new_container.1 == Some(key_code) =>
+            {
+                self.new_container_key().await;
+            }
+
+            _ if self.keymap.networks.0 == key_code || self.keymap.networks.1 == Some(key_code) => {
+                self.networks_key().await;
+            }
+
+            _ if self.keymap.update_limits.0 == key_code
+                || self.keymap.update_limits.1 == Some(key_code) =>
+            {
+                self.update_limits_key().await;
+            }
+
             _ if self.keymap.toggle_help.0 == key_code
                 || self.keymap.toggle_help.1 == Some(key_code) =>
             {
@@ -667,7 +1893,7 @@ impl InputHandler {
             _ if self.keymap.save_logs.0 == key_code
                 || self.keymap.save_logs.1 == Some(key_code) =>
             {
-                self.save_key().await;
+                self.save_key();
             }
 
             _ if self.keymap.inspect.0 == key_code || self.keymap.inspect.1 == Some(key_code) => {
@@ -689,7 +1915,7 @@ impl InputHandler {
             _ if self.keymap.scroll_start.0 == key_code
                 || self.keymap.scroll_start.1 == Some(key_code) =>
             {
-                self.scroll_start_key();
+                self.scroll_start_key().await;
             }
 
             _ if self.keymap.scroll_end.0 == key_code
@@ -701,13 +1927,13 @@ impl InputHandler {
             _ if self.keymap.scroll_up.0 == key_code
                 || self.keymap.scroll_up.1 == Some(key_code) =>
             {
-                self.scroll(modifier, &ScrollDirection::Up);
+                self.scroll(modifier, &ScrollDirection::Up).await;
             }
 
             _ if self.keymap.scroll_down.0 == key_code
                 || self.keymap.scroll_down.1 == Some(key_code) =>
             {
-                self.scroll(modifier, &ScrollDirection::Down);
+                self.scroll(modifier, &ScrollDirection::Down).await;
             }
 
             _ if self.keymap.filter_mode.0 == key_code
@@ -726,10 +1952,77 @@ impl InputHandler {
                 self.gui_state.lock().status_push(Status::SearchLogs);
             }
 
+            _ if self.keymap.log_level_filter.0 == key_code
+                || self.keymap.log_level_filter.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_level_filter_cycle();
+            }
+
+            _ if self.keymap.log_stream_filter.0 == key_code
+                || self.keymap.log_stream_filter.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_stream_filter_cycle();
+            }
+
+            _ if self.keymap.log_wrap.0 == key_code || self.keymap.log_wrap.1 == Some(key_code) => {
+                self.app_data.lock().log_wrap_toggle();
+            }
+
+            _ if self.keymap.log_collapse.0 == key_code
+                || self.keymap.log_collapse.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_collapse_toggle();
+            }
+
+            _ if self.keymap.log_group.0 == key_code
+                || self.keymap.log_group.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_group_toggle();
+            }
+
+            _ if self.keymap.log_follow.0 == key_code
+                || self.keymap.log_follow.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_follow_toggle();
+            }
+
+            _ if self.keymap.log_live.0 == key_code || self.keymap.log_live.1 == Some(key_code) => {
+                self.app_data.lock().log_live();
+            }
+
+            _ if (self.keymap.log_mark.0 == key_code
+                || self.keymap.log_mark.1 == Some(key_code))
+                && self.gui_state.lock().get_selected_panel() == SelectablePanel::Logs =>
+            {
+                self.app_data.lock().log_mark_toggle();
+            }
+
+            _ if self.keymap.yank.0 == key_code || self.keymap.yank.1 == Some(key_code) => {
+                self.yank_key();
+            }
+
+            _ if (self.keymap.log_time.0 == key_code
+                || self.keymap.log_time.1 == Some(key_code))
+                && self.app_data.lock().set_log_time_prompt() =>
+            {
+                self.gui_state.lock().status_push(Status::Prompt);
+            }
+
+            _ if self.keymap.log_time_column.0 == key_code
+                || self.keymap.log_time_column.1 == Some(key_code) =>
+            {
+                self.app_data.lock().log_time_column_cycle();
+            }
+
+            _ if self.keymap.clear.0 == key_code || self.keymap.clear.1 == Some(key_code) => {
+                self.app_data.lock().log_mark_clear();
+            }
+
             _ if self.keymap.scroll_back.0 == key_code
                 || self.keymap.scroll_back.1 == Some(key_code) =>
             {
                 self.logs_horizontal_scroll(modifier, &ScrollDirection::Up);
+                self.command_timeout_key(false);
                 // self.logs_back(modifier);
             }
 
@@ -737,6 +2030,7 @@ impl InputHandler {
                 || self.keymap.scroll_forward.1 == Some(key_code) =>
             {
                 self.logs_horizontal_scroll(modifier, &ScrollDirection::Down);
+                self.command_timeout_key(true);
             }
 
             KeyCode::Enter => self.enter_key().await,
@@ -754,15 +2048,48 @@ impl InputHandler {
         let contains_exec = contains(Status::Exec);
         let contains_filter = contains(Status::Filter);
         let contains_delete = contains(Status::DeleteConfirm);
+        let contains_kill_signal = contains(Status::KillSignal);
         let contains_search_logs = contains(Status::SearchLogs);
         let contains_inspect = contains(Status::Inspect);
-
-        if !contains_exec {
+        let contains_run_command = contains(Status::RunCommand);
+        let contains_new_container = contains(Status::NewContainer);
+        let contains_limits = contains(Status::Limits);
+        let contains_prompt = contains(Status::Prompt);
+        let contains_networks = contains(Status::Networks);
+        // The connect form of the networks popup takes user input, so the quit key can be typed into it
+        let contains_networks_connect = contains_networks
+            && self
+                .app_data
+                .lock()
+                .get_networks()
+                .is_some_and(|i| i.connect.is_some());
+        let contains_command_output = contains(Status::CommandOutput);
+        let contains_files = contains(Status::Files);
+        let contains_changes = contains(Status::Changes);
+        let contains_log_detail = contains(Status::LogDetail);
+        let contains_processes = contains(Status::Processes);
+        let contains_prune = contains(Status::Prune);
+        let contains_save_logs = contains(Status::SaveLogs);
+        let contains_files_upload = contains(Status::FilesUpload);
+        let exec_selected = self.gui_state.lock().get_selected_panel() == SelectablePanel::Exec;
+
+        if exec_selected && !contains_exec && !contains_error && !contains_help {
+            // The embedded exec panel takes all key presses, including the quit keys
+            self.handle_embedded_exec(key_code, key_modifier).await;
+        } else if !contains_exec {
             let is_q = || key_code == self.keymap.quit.0 || Some(key_code) == self.keymap.quit.1;
             if key_modifier == KeyModifiers::CONTROL && key_code == KeyCode::Char('c')
-                || is_q() && !contains_filter && !contains_search_logs
+                || is_q()
+                    && !contains_filter
+                    && !contains_search_logs
+                    && !contains_run_command
+                    && !contains_files_upload
+                    && !contains_new_container
+                    && !contains_limits
+                    && !contains_prompt
+                    && !contains_networks_connect
             {
-                // Always just quit on Ctrl + c/C or q/Q, unless in filter/search_logs mode, i.e. when user inmput can include the q key
+                // Always just quit on Ctrl + c/C or q/Q, unless in filter/search_logs/run_command/files_upload/new_container/limits/prompt/networks connect mode, i.e. when user inmput can include the q key
                 self.quit();
             }
 
@@ -774,10 +2101,38 @@ impl InputHandler {
                 self.handle_filter(key_code);
             } else if contains_search_logs {
                 self.handle_search_logs(key_code, key_modifier);
+            } else if contains_run_command {
+                self.handle_run_command(key_code).await;
+            } else if contains_files_upload {
+                self.handle_files_upload(key_code).await;
+            } else if contains_new_container {
+                self.handle_new_container(key_code).await;
+            } else if contains_limits {
+                self.handle_limits(key_code).await;
+            } else if contains_prompt {
+                self.handle_prompt(key_code).await;
+            } else if contains_networks {
+                self.handle_networks(key_code, key_modifier).await;
             } else if contains_delete {
                 self.handle_delete(key_code).await;
+            } else if contains_kill_signal {
+                self.handle_kill_signal(key_code).await;
             } else if contains_inspect {
                 self.handle_inspect(key_code, key_modifier);
+            } else if contains_command_output {
+                self.handle_command_output(key_code, key_modifier);
+            } else if contains_files {
+                self.handle_files(key_code, key_modifier).await;
+            } else if contains_changes {
+                self.handle_changes(key_code, key_modifier).await;
+            } else if contains_log_detail {
+                self.handle_log_detail(key_code, key_modifier);
+            } else if contains_processes {
+                self.handle_processes(key_code, key_modifier).await;
+            } else if contains_prune {
+                self.handle_prune(key_code, key_modifier).await;
+            } else if contains_save_logs {
+                self.handle_save_logs(key_code, key_modifier).await;
             } else {
                 self.handle_others(key_code, key_modifier).await;
             }
@@ -798,16 +2153,19 @@ impl InputHandler {
                 match button {
                     DeleteButton::Confirm => self.confirm_delete().await,
                     DeleteButton::Cancel => self.clear_delete(),
+                    DeleteButton::Toggle(option) => {
+                        self.gui_state.lock().delete_option_toggle(option);
+                    }
                 }
             }
         }
     }
 
     /// Handle mouse button events
-    fn mouse_press(&self, mouse_event: MouseEvent, modifier: KeyModifiers) {
+    async fn mouse_press(&self, mouse_event: MouseEvent, modifier: KeyModifiers) {
         let status = self.gui_state.lock().get_status();
 
-        if status.contains(&Status::Inspect) {
+        if status.contains(&Status::Inspect) || status.contains(&Status::CommandOutput) {
             match mouse_event.kind {
                 MouseEventKind::ScrollDown => self.inspect_scroll(modifier, &ScrollDirection::Down),
                 MouseEventKind::ScrollUp => self.inspect_scroll(modifier, &ScrollDirection::Up),
@@ -817,6 +2175,54 @@ impl InputHandler {
                 MouseEventKind::ScrollLeft => self.inspect_scroll(modifier, &ScrollDirection::Left),
                 _ => (),
             }
+        } else if status.contains(&Status::Files) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => self
+                    .app_data
+                    .lock()
+                    .file_browser_scroll(&ScrollDirection::Down),
+                MouseEventKind::ScrollUp => self
+                    .app_data
+                    .lock()
+                    .file_browser_scroll(&ScrollDirection::Up),
+                _ => (),
+            }
+        } else if status.contains(&Status::Changes) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => self
+                    .app_data
+                    .lock()
+                    .update_container_changes(|i| i.scroll(&ScrollDirection::Down)),
+                MouseEventKind::ScrollUp => self
+                    .app_data
+                    .lock()
+                    .update_container_changes(|i| i.scroll(&ScrollDirection::Up)),
+                _ => (),
+            }
+        } else if status.contains(&Status::LogDetail) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => self
+                    .app_data
+                    .lock()
+                    .update_log_detail(|i| i.scroll(&ScrollDirection::Down)),
+                MouseEventKind::ScrollUp => self
+                    .app_data
+                    .lock()
+                    .update_log_detail(|i| i.scroll(&ScrollDirection::Up)),
+                _ => (),
+            }
+        } else if status.contains(&Status::Processes) {
+            match mouse_event.kind {
+                MouseEventKind::ScrollDown => self
+                    .app_data
+                    .lock()
+                    .update_processes(None, |i| i.processes.scroll(&ScrollDirection::Down)),
+                MouseEventKind::ScrollUp => self
+                    .app_data
+                    .lock()
+                    .update_processes(None, |i| i.processes.scroll(&ScrollDirection::Up)),
+                _ => (),
+            }
         } else if status.contains(&Status::Help) {
             let mouse_point = Rect::new(mouse_event.column, mouse_event.row, 1, 1);
             let help_intersect = self.gui_state.lock().get_intersect_help(mouse_point);
@@ -825,8 +2231,10 @@ impl InputHandler {
             }
         } else {
             match mouse_event.kind {
-                MouseEventKind::ScrollUp => self.scroll(modifier, &ScrollDirection::Up),
-                MouseEventKind::ScrollDown => self.scroll(modifier, &ScrollDirection::Down),
+                MouseEventKind::ScrollUp => self.scroll(modifier, &ScrollDirection::Up).await,
+                MouseEventKind::ScrollDown => {
+                    self.scroll(modifier, &ScrollDirection::Down).await;
+                }
                 // TODO left and right for log offsets
                 MouseEventKind::Down(MouseButton::Left) => {
                     let mouse_point = Rect::new(mouse_event.column, mouse_event.row, 1, 1);
@@ -847,7 +2255,7 @@ impl InputHandler {
     }
 
     /// Change state to next, depending which panel is currently in focus
-    fn scroll(&self, modifier: KeyModifiers, scroll: &ScrollDirection) {
+    async fn scroll(&self, modifier: KeyModifiers, scroll: &ScrollDirection) {
         let status = self.gui_state.lock().get_status();
         if status.contains(&Status::SearchLogs) {
             self.app_data.lock().log_search_scroll(scroll);
@@ -863,8 +2271,13 @@ impl InputHandler {
                     for _ in 0..self.get_modifier_total(modifier) {
                         self.app_data.lock().log_scroll(scroll);
                     }
+                    self.logs_older().await;
+                }
+                SelectablePanel::Commands => {
+                    self.gui_state.lock().command_timeout_take();
+                    self.app_data.lock().docker_controls_scroll(scroll);
                 }
-                SelectablePanel::Commands => self.app_data.lock().docker_controls_scroll(scroll),
+                SelectablePanel::Exec => (),
             }
         }
     }
@@ -167,7 +167,7 @@ mod tests {
     use crate::{
         app_data::{
             AppData, ContainerId, ContainerItem, ContainerPorts, ContainerStatus, Filter,
-            RunningState, State, StatefulList,
+            LogStream, RunningState, State, StatefulList,
         },
         config::{AppColors, Config, Keymap},
         ui::Rerender,
@@ -178,19 +178,29 @@ mod tests {
         Config {
             app_colors: AppColors::new(),
             color_logs: false,
+            debug_image: "busybox".to_owned(),
+            delete_force: true,
+            delete_link: false,
+            delete_volumes: false,
             dir_save: None,
             dir_config: None,
             docker_interval_ms: 1000,
+            embedded_exec: false,
             gui: true,
             host: None,
             in_container: false,
             keymap: Keymap::new(),
             log_search_case_sensitive: true,
+            log_json_columns: vec!["level".to_owned(), "msg".to_owned(), "caller".to_owned()],
+            log_stderr_marker: String::from("!"),
+            log_tail: 1000,
             raw_logs: false,
+            restart_timeout: None,
             show_logs: true,
             show_self: false,
             show_std_err: false,
             show_timestamp: false,
+            stop_timeout: None,
             timestamp_format: "HH:MM:SS.NNNNN dd-mm-yyyy".to_owned(),
             timezone: None,
             use_cli: false,
@@ -214,13 +224,32 @@ mod tests {
         )
     }
 
+    /// Mark every log line as written to stdout
+    pub fn gen_stdout(logs: Vec<String>) -> Vec<(LogStream, String)> {
+        logs.into_iter().map(|i| (LogStream::Stdout, i)).collect()
+    }
+
     pub fn gen_appdata(containers: &[ContainerItem]) -> AppData {
         AppData {
+            command_output: None,
             containers: StatefulList::new(containers.to_vec()),
             hidden_containers: vec![],
             current_sorted_id: vec![],
             inspect_data: None,
+            limits: None,
+            log_detail: None,
+            log_time_column: None,
+            log_wrap: false,
+            networks: None,
+            new_container: None,
             error: None,
+            container_changes: None,
+            file_browser: None,
+            processes: None,
+            prompt: None,
+            prune: None,
+            recreate: None,
+            save_logs: None,
             sorted_by: None,
             rerender: Arc::new(Rerender::new()),
             filter: Filter::new(),
@@ -0,0 +1,172 @@
+use ratatui::{
+    Frame,
+    style::{Color, Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{List, ListItem, Paragraph},
+};
+
+use super::SELECT_ARROW;
+use crate::{
+    app_data::{ChangeKind, ChangeRow, ContainerChanges},
+    config::{AppColors, Keymap},
+    ui::draw_blocks::inspect::title_block,
+};
+
+/// Create the upper title, with the container name, the current filter, and the total of each change type
+fn generate_upper_title(data: &ContainerChanges) -> String {
+    let filter = match data.filter {
+        None => "all",
+        Some(ChangeKind::Added) => "added",
+        Some(ChangeKind::Changed) => "changed",
+        Some(ChangeKind::Deleted) => "deleted",
+    };
+    format!(
+        " changes: {} - filter: {filter} - added {} changed {} deleted {} ",
+        data.name,
+        data.count(ChangeKind::Added),
+        data.count(ChangeKind::Changed),
+        data.count(ChangeKind::Deleted),
+    )
+}
+
+/// Create the lower title, with the keys used to collapse, filter, browse, & save
+fn generate_lower_title(keymap: &Keymap) -> String {
+    let mut clear_key = keymap.clear.0.to_string();
+    if let Some(x) = keymap.clear.1 {
+        clear_key.push_str(&format!(" or {x}"));
+    }
+    format!(
+        " Enter collapse - {} filter - {} browse - {} save - {clear_key} to exit ",
+        keymap.select_next_panel.0, keymap.file_browser.0, keymap.save_logs.0
+    )
+}
+
+/// Get the color of a change type, reuses the container state colors, added is healthy, changed is paused, deleted is dead
+const fn kind_color(colors: &AppColors, kind: Option<ChangeKind>) -> Color {
+    match kind {
+        Some(ChangeKind::Added) => colors.container_state.running_healthy,
+        Some(ChangeKind::Changed) => colors.container_state.paused,
+        Some(ChangeKind::Deleted) => colors.container_state.dead,
+        None => Color::DarkGray,
+    }
+}
+
+/// Generate a single tree row, indented by depth, with an arrow if the row has children
+fn gen_row<'a>(row: &'a ChangeRow, colors: &AppColors) -> ListItem<'a> {
+    let arrow = match (row.has_children, row.collapsed) {
+        (false, _) => "  ",
+        (true, false) => "▾ ",
+        (true, true) => "▸ ",
+    };
+    let style = Style::default().fg(kind_color(colors, row.kind));
+    let kind = row.kind.map_or_else(|| " ".to_owned(), |i| i.to_string());
+    ListItem::new(Line::from(vec![
+        Span::from(format!("{}{arrow}", "  ".repeat(row.depth))).gray(),
+        Span::styled(format!("{kind} "), style.add_modifier(Modifier::BOLD)),
+        Span::styled(row.name(), style),
+    ]))
+}
+
+/// Draw the filesystem changes tree to the entire screen
+pub fn draw(f: &mut Frame, colors: AppColors, mut data: ContainerChanges, keymap: &Keymap) {
+    let rect = f.area();
+    let upper_title = generate_upper_title(&data);
+    let lower_title = generate_lower_title(keymap);
+    let block = title_block(&upper_title, &lower_title, &colors);
+
+    if data.rows.items.is_empty() {
+        let paragraph = Paragraph::new(" no changes").block(block).gray();
+        f.render_widget(paragraph, rect);
+    } else {
+        let items = data
+            .rows
+            .items
+            .iter()
+            .map(|i| gen_row(i, &colors))
+            .collect::<Vec<_>>();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol(SELECT_ARROW);
+        f.render_stateful_widget(list, rect, &mut data.rows.state);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::models::{ChangeType, FilesystemChange};
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ContainerChanges, ContainerId},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_changes() -> ContainerChanges {
+        let change = |path: &str, kind: ChangeType| FilesystemChange {
+            path: path.to_owned(),
+            kind,
+        };
+        ContainerChanges::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            vec![
+                change("/etc", ChangeType::_0),
+                change("/etc/hosts", ChangeType::_0),
+                change("/tmp-old", ChangeType::_2),
+                change("/var", ChangeType::_0),
+                change("/var/log", ChangeType::_0),
+                change("/var/log/app.log", ChangeType::_1),
+            ],
+        )
+    }
+
+    #[test]
+    /// Changes drawn as a tree, with each change type in the correct color
+    fn test_draw_blocks_changes_default() {
+        let mut setup = test_setup(90, 9, true, true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), gen_changes(), &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        assert_eq!(result[1].1[5].fg, Color::Yellow);
+        assert_eq!(result[3].1[5].fg, Color::Red);
+        assert_eq!(result[6].1[9].fg, Color::Green);
+    }
+
+    #[test]
+    /// Collapsed directories, filtered changes, & no changes, all drawn correctly
+    fn test_draw_blocks_changes_collapsed_filtered() {
+        let mut setup = test_setup(90, 6, true, true);
+        let mut changes = gen_changes();
+        changes.collapse(None);
+        changes.cycle_filter(false);
+        changes.cycle_filter(false);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), changes, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let mut setup = test_setup(90, 4, true, true);
+        let changes =
+            ContainerChanges::new(ContainerId::from("1"), "container_1".to_owned(), vec![]);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), changes, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
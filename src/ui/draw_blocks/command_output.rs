@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    style::Stylize,
+    widgets::{Paragraph, Wrap},
+};
+
+use crate::{
+    app_data::CommandOutput,
+    config::{AppColors, Keymap},
+    ui::{
+        GuiState,
+        draw_blocks::inspect::{gen_lines, generate_lower_title, title_block},
+        gui_state::ScrollOffset,
+    },
+};
+
+/// Create the upper title, with the command, container name, exit code, and keymap to clear & save
+fn generate_upper_title(data: &CommandOutput, keymap: &Keymap) -> String {
+    let exit_code = data
+        .exit_code
+        .map_or_else(|| "unknown".to_owned(), |i| i.to_string());
+    let mut exec_command_key = keymap.exec_command.0.to_string();
+    if let Some(x) = keymap.exec_command.1 {
+        exec_command_key.push_str(&format!(" or {x}"));
+    }
+    let mut clear_key = keymap.clear.0.to_string();
+    if let Some(x) = keymap.clear.1 {
+        clear_key.push_str(&format!(" or {x}"));
+    }
+    format!(
+        " {}: $ {} - exit code: {exit_code} - {clear_key} or {exec_command_key} to exit, {} to save ",
+        data.name, data.command, keymap.save_logs.0
+    )
+}
+
+/// Draw the output of a one-off command to the entire screen, scrolling uses the same offsets as the inspect widget
+pub fn draw(
+    f: &mut Frame,
+    colors: AppColors,
+    data: CommandOutput,
+    gui_state: &Arc<Mutex<GuiState>>,
+    keymap: &Keymap,
+) {
+    let rect = f.area();
+    let offset = gui_state.lock().get_inspect_offset();
+    // +2 to account for the border
+    let height = data
+        .height
+        .saturating_sub(usize::from(rect.height))
+        .saturating_add(2);
+    let width = data
+        .width
+        .saturating_sub(usize::from(rect.width))
+        .saturating_add(2);
+    let upper_title = generate_upper_title(&data, keymap);
+    let lower_title = generate_lower_title(height, width, offset);
+
+    gui_state.lock().set_inspect_offset_max(ScrollOffset {
+        x: width,
+        y: height,
+    });
+
+    let paragraph = Paragraph::new(gen_lines(&data.output, &offset, &rect))
+        .block(title_block(&upper_title, &lower_title, &colors))
+        .gray()
+        .left_aligned()
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, rect);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{
+        app_data::{CommandOutput, ScrollDirection},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    fn gen_output() -> CommandOutput {
+        CommandOutput::new(
+            "container_1".to_owned(),
+            "cat /etc/hosts".to_owned(),
+            "127.0.0.1 localhost\r\n::1 localhost ip6-localhost ip6-loopback\n\x1b[31mfe00::\x1b[0m ip6-localnet\n172.17.0.2 abcdef",
+            Some(0),
+        )
+    }
+
+    #[test]
+    /// ansi codes & carriage returns are removed from the output, and the width & height are calculated
+    fn test_draw_blocks_command_output_new() {
+        let output = gen_output();
+        assert_eq!(output.height, 4);
+        assert_eq!(output.width, 40);
+        assert!(!output.output.contains('\r'));
+        assert!(!output.output.contains('\x1b'));
+    }
+
+    #[test]
+    /// Command output is drawn with the command, name, & exit code in the title, and border colors are correct
+    fn test_draw_blocks_command_output_default() {
+        let mut setup = test_setup(90, 8, true, true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    f,
+                    AppColors::new(),
+                    gen_output(),
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match (row_index, result_cell_index) {
+                    (0 | 7, _) | (_, 0 | 89) => {
+                        assert_eq!(result_cell.fg, Color::LightCyan);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Output can be scrolled, and an unknown exit code is shown
+    fn test_draw_blocks_command_output_scrolled() {
+        let mut setup = test_setup(40, 5, true, true);
+        let mut output = gen_output();
+        output.exit_code = None;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    f,
+                    AppColors::new(),
+                    output.clone(),
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+
+        setup
+            .gui_state
+            .lock()
+            .set_inspect_offset(&ScrollDirection::Down);
+        setup
+            .gui_state
+            .lock()
+            .set_inspect_offset(&ScrollDirection::Right);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    f,
+                    AppColors::new(),
+                    output.clone(),
+                    &setup.gui_state,
+                    &Keymap::new(),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
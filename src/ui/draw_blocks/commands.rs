@@ -28,11 +28,23 @@ pub fn draw(
 ) {
     let block = generate_block(area, colors, fd, gui_state, SelectablePanel::Commands)
         .bg(colors.commands.background);
+    let selected = app_data
+        .lock()
+        .get_control_state()
+        .and_then(|i| i.selected());
     let items = app_data.lock().get_control_items().map_or(vec![], |i| {
         i.iter()
-            .map(|c| {
+            .enumerate()
+            .map(|(index, c)| {
+                // Show the stop/restart timeout, if it has been changed for the selected command
+                let text = match fd.command_timeout {
+                    Some(timeout) if c.has_timeout() && selected == Some(index) => {
+                        format!("{c} {timeout}s")
+                    }
+                    _ => c.to_string(),
+                };
                 let lines = Line::from(vec![Span::styled(
-                    c.to_string(),
+                    text,
                     Style::default().fg(c.get_color(colors)),
                 )]);
                 ListItem::new(lines)
@@ -120,10 +132,14 @@ mod tests {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 assert_eq!(result_cell.bg, Color::Reset);
                 match (row_index, result_cell_index) {
-                    // Borders & delete
-                    (0 | 5, _) | (1..=4, 0 | 11) | (4, 3..=8) => {
+                    // Borders
+                    (0 | 5, _) | (1..=4, 0 | 11) => {
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
+                    // kill
+                    (4, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::LightRed);
+                    }
                     // pause
                     (1, 3..=7) => {
                         assert_eq!(result_cell.fg, Color::Yellow);
@@ -203,8 +219,16 @@ mod tests {
                     (2, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Red);
                     }
-                    // delete
-                    (0 | 5, _) | (1..=4, 0 | 11) | (3, 3..=8) => {
+                    // kill
+                    (3, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::LightRed);
+                    }
+                    // recreate
+                    (4, 3..=10) => {
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                    }
+                    // Borders
+                    (0 | 5, _) | (1..=4, 0 | 11) => {
                         assert_eq!(result_cell.fg, Color::Gray);
                     }
                     _ => {
@@ -292,6 +316,7 @@ mod tests {
         colors.commands.restart = Color::Green;
         colors.commands.stop = Color::Blue;
         colors.commands.delete = Color::Magenta;
+        colors.commands.kill = Color::Red;
         colors.commands.resume = Color::Yellow;
         colors.commands.start = Color::Cyan;
 
@@ -326,9 +351,9 @@ mod tests {
                     (3, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
-                    // delete
-                    (4, 3..=8) => {
-                        assert_eq!(result_cell.fg, Color::Magenta);
+                    // kill
+                    (4, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::Red);
                     }
                     _ => (),
                 }
@@ -345,6 +370,7 @@ mod tests {
         colors.commands.restart = Color::Green;
         colors.commands.stop = Color::Blue;
         colors.commands.delete = Color::Magenta;
+        colors.commands.kill = Color::Red;
         colors.commands.resume = Color::Yellow;
         colors.commands.start = Color::Cyan;
 
@@ -401,8 +427,12 @@ mod tests {
                     (2, 3..=6) => {
                         assert_eq!(result_cell.fg, Color::Blue);
                     }
+                    // kill
+                    (3, 3..=6) => {
+                        assert_eq!(result_cell.fg, Color::Red);
+                    }
                     // delete
-                    (3, 3..=8) => {
+                    (4, 3..=8) => {
                         assert_eq!(result_cell.fg, Color::Magenta);
                     }
                     _ => (),
@@ -410,4 +440,37 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    /// The changed stop timeout is only shown next to the selected stop command
+    fn test_draw_blocks_commands_timeout() {
+        let mut setup = test_setup(14, 6, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+        setup
+            .app_data
+            .lock()
+            .docker_controls_scroll(&ScrollDirection::Down);
+        setup
+            .app_data
+            .lock()
+            .docker_controls_scroll(&ScrollDirection::Down);
+        setup.gui_state.lock().command_timeout_change(true, 10);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    colors,
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+    }
 }
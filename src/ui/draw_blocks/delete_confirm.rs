@@ -3,7 +3,7 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use ratatui::{
     Frame,
-    layout::{Alignment, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout},
     style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Clear, Paragraph},
@@ -11,7 +11,7 @@ use ratatui::{
 
 use super::{CONSTRAINT_BUTTONS, CONSTRAINT_POPUP};
 use crate::{
-    app_data::ContainerName,
+    app_data::{ContainerName, DeleteOption},
     config::{AppColors, Keymap},
     ui::{
         DeleteButton, GuiState,
@@ -68,9 +68,30 @@ pub fn draw(
         format!("( {} ) no", keymap.delete_deny.0)
     };
 
+    // Each option is shown with the key used to toggle it, and padded to the same width so that the checkboxes line up
+    let options = gui_state.lock().get_delete_options();
+    let option_keys = [
+        keymap.sort_by_name.0,
+        keymap.sort_by_state.0,
+        keymap.sort_by_status.0,
+    ];
+    let option_text = DeleteOption::ALL
+        .into_iter()
+        .zip(option_keys)
+        .map(|(option, key)| {
+            let checked = if options.get(option) { "x" } else { " " };
+            format!("( {key} ) [{checked}] {option}")
+        })
+        .collect::<Vec<_>>();
+    let option_width = option_text
+        .iter()
+        .map(|i| i.chars().count())
+        .max()
+        .unwrap_or_default();
+
     // Find the maximum line width & height, and add some padding
-    let max_line_width = u16::try_from(confirm.width()).unwrap_or(64) + 12;
-    let lines = 8;
+    let max_line_width = u16::try_from(confirm.width().max(option_width)).unwrap_or(64) + 12;
+    let lines = 12;
 
     let confirm_para = Paragraph::new(confirm).alignment(Alignment::Center);
 
@@ -104,6 +125,11 @@ pub fn draw(
     let split_buttons = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(CONSTRAINT_BUTTONS)
+        .split(split_popup[5]);
+
+    let split_options = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Max(1); 3])
         .split(split_popup[3]);
 
     let no_area = split_buttons[1];
@@ -112,6 +138,19 @@ pub fn draw(
     f.render_widget(Clear, area);
     f.render_widget(block, area);
     f.render_widget(confirm_para, split_popup[1]);
+    for ((option, text), area) in DeleteOption::ALL
+        .into_iter()
+        .zip(option_text)
+        .zip(split_options.iter())
+    {
+        f.render_widget(
+            Paragraph::new(format!("{text:<option_width$}")).alignment(Alignment::Center),
+            *area,
+        );
+        gui_state
+            .lock()
+            .update_region_map(Region::Delete(DeleteButton::Toggle(option)), *area);
+    }
     f.render_widget(no_para, no_area);
     f.render_widget(yes_para, yes_area);
     // Insert button areas into region map, so can interact with them on click
@@ -132,7 +171,7 @@ mod tests {
     use ratatui::style::{Color, Modifier};
 
     use crate::{
-        app_data::ContainerName,
+        app_data::{ContainerName, DeleteOption, DeleteOptions},
         config::{AppColors, Keymap},
         ui::draw_blocks::tests::{get_result, test_setup},
     };
@@ -140,7 +179,7 @@ mod tests {
     #[test]
     /// Delete container popup is drawn correctly
     fn test_draw_blocks_delete() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 14, true, true);
 
         let colors = setup.app_data.lock().config.app_colors;
         let keymap = &setup.app_data.lock().config.keymap;
@@ -162,7 +201,7 @@ mod tests {
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 9, _) | (1..=8, 0..=7 | 74..=81) => {
+                    (0 | 13, _) | (1..=12, 0..=7 | 74..=81) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -183,7 +222,7 @@ mod tests {
     #[test]
     /// Delete container popup is drawn correctly
     fn test_draw_blocks_delete_long_name() {
-        let mut setup = test_setup(106, 10, true, true);
+        let mut setup = test_setup(106, 14, true, true);
         let name = ContainerName::from("container_1_container_1_container_1");
         setup.app_data.lock().containers.items[0].name = name.clone();
 
@@ -200,7 +239,7 @@ mod tests {
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 9, _) | (1..=8, 0..=7 | 98..=106) => {
+                    (0 | 13, _) | (1..=12, 0..=7 | 98..=106) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -221,7 +260,7 @@ mod tests {
     #[test]
     /// Custom colors applied correctly to delete popup
     fn test_draw_blocks_delete_custom_colors() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 14, true, true);
         let mut colors = AppColors::new();
         colors.popup_delete.background = Color::Black;
         colors.popup_delete.text = Color::Yellow;
@@ -245,7 +284,7 @@ mod tests {
         for (row_index, result_row) in get_result(&setup) {
             for (result_cell_index, result_cell) in result_row.iter().enumerate() {
                 match (row_index, result_cell_index) {
-                    (0 | 9, _) | (1..=8, 0..=7 | 74..=81) => {
+                    (0 | 13, _) | (1..=12, 0..=7 | 74..=81) => {
                         assert_eq!(result_cell.bg, Color::Reset);
                         assert_eq!(result_cell.fg, Color::Reset);
                     }
@@ -266,7 +305,7 @@ mod tests {
     #[test]
     /// Custom keymap, with multiple definitions for each button, applied correctly to delete popup
     fn test_draw_blocks_delete_custom_keymap_one_definition() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 14, true, true);
         let mut keymap = Keymap::new();
         keymap.delete_confirm = (KeyCode::F(10), None);
         keymap.delete_deny = (KeyCode::End, None);
@@ -287,7 +326,7 @@ mod tests {
     #[test]
     /// Custom keymap, with multiple definitions for each button, applied correctly to delete popup
     fn test_draw_blocks_delete_custom_keymap_two_definition() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 14, true, true);
         let mut keymap = Keymap::new();
         keymap.delete_confirm = (KeyCode::F(10), Some(KeyCode::Char('L')));
         keymap.delete_deny = (KeyCode::End, Some(KeyCode::Up));
@@ -308,7 +347,7 @@ mod tests {
     #[test]
     /// Custom keymap, with multiple definitions for each button, applied correctly to delete popup
     fn test_draw_blocks_delete_custom_keymap_one_two_definition() {
-        let mut setup = test_setup(82, 10, true, true);
+        let mut setup = test_setup(82, 14, true, true);
         let mut keymap = Keymap::new();
         keymap.delete_confirm = (KeyCode::F(10), None);
         keymap.delete_deny = (KeyCode::End, Some(KeyCode::Up));
@@ -326,4 +365,31 @@ mod tests {
             .unwrap();
         assert_snapshot!(setup.terminal.backend());
     }
+
+    #[test]
+    /// Delete options drawn with the config defaults, and toggled options are checked
+    fn test_draw_blocks_delete_options() {
+        let mut setup = test_setup(82, 14, true, true);
+        let options = DeleteOptions::from(&setup.app_data.lock().config);
+        setup.gui_state.lock().set_delete_options(options);
+        setup
+            .gui_state
+            .lock()
+            .delete_option_toggle(DeleteOption::Volumes);
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    AppColors::new(),
+                    f,
+                    &setup.gui_state,
+                    &Keymap::new(),
+                    &ContainerName::from("container_1"),
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+        assert!(setup.gui_state.lock().get_delete_options().force);
+    }
 }
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{
+    config::AppColors,
+    exec::Screen,
+    ui::{FrameData, GuiState, SelectablePanel},
+};
+
+use super::generate_block;
+
+/// Convert the emulated screen into lines of styled spans, consecutive cells with the same style are combined into a single span
+/// The cursor is shown by reversing the cell style, but only if the panel is selected
+fn screen_to_lines(screen: &Screen, show_cursor: bool) -> Vec<Line<'static>> {
+    let cursor = screen.cursor();
+    screen
+        .rows()
+        .iter()
+        .enumerate()
+        .map(|(row_index, row)| {
+            let mut spans = vec![];
+            let mut text = String::new();
+            let mut current_style: Option<Style> = None;
+            for (col_index, cell) in row.iter().enumerate() {
+                let style = if show_cursor
+                    && usize::from(cursor.row) == row_index
+                    && usize::from(cursor.col) == col_index
+                {
+                    if cell.style.add_modifier.contains(Modifier::REVERSED) {
+                        cell.style.remove_modifier(Modifier::REVERSED)
+                    } else {
+                        cell.style.add_modifier(Modifier::REVERSED)
+                    }
+                } else {
+                    cell.style
+                };
+                if current_style != Some(style) {
+                    if let Some(previous) = current_style {
+                        spans.push(Span::styled(std::mem::take(&mut text), previous));
+                    }
+                    current_style = Some(style);
+                }
+                text.push(cell.ch);
+            }
+            if let Some(style) = current_style {
+                spans.push(Span::styled(text, style));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Draw the embedded exec panel, the emulated screen is resized to fit inside of the panel borders
+pub fn draw(
+    area: Rect,
+    colors: AppColors,
+    f: &mut Frame,
+    fd: &FrameData,
+    gui_state: &Arc<Mutex<GuiState>>,
+) {
+    let Some(embedded_exec) = fd.embedded_exec.as_ref() else {
+        return;
+    };
+    let block = generate_block(area, colors, fd, gui_state, SelectablePanel::Exec);
+    embedded_exec.resize(block.inner(area).as_size());
+
+    let screen = embedded_exec.screen();
+    let show_cursor = fd.selected_panel == SelectablePanel::Exec && screen.cursor_visible();
+    let paragraph = Paragraph::new(screen_to_lines(&screen, show_cursor)).block(block);
+    f.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::{
+        layout::Size,
+        style::{Color, Modifier},
+    };
+
+    use crate::{
+        exec::EmbeddedExec,
+        ui::{
+            FrameData,
+            draw_blocks::tests::{BORDER_CHARS, get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Exec panel is drawn with the container name as the title, the screen is resized to fit the panel, and the cursor is shown when selected
+    fn test_draw_blocks_exec() {
+        let mut setup = test_setup(30, 6, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+
+        let embedded_exec = EmbeddedExec::test(
+            "container_1",
+            Size {
+                width: 80,
+                height: 24,
+            },
+            b"/ # ls\r\nbin  \x1b[31metc\x1b[0m\r\n/ # ",
+        );
+        setup
+            .gui_state
+            .lock()
+            .set_embedded_exec(embedded_exec.clone());
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, colors, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        assert_eq!(
+            embedded_exec.screen().size(),
+            Size {
+                width: 28,
+                height: 4
+            }
+        );
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+                match (row_index, result_cell_index) {
+                    (2, 6..=8) => assert_eq!(result_cell.fg, Color::Red),
+                    (3, 5) => assert!(result_cell.modifier.contains(Modifier::REVERSED)),
+                    _ => assert!(!result_cell.modifier.contains(Modifier::REVERSED)),
+                }
+            }
+        }
+
+        // When not selected, border is gray and cursor isn't shown
+        setup
+            .gui_state
+            .lock()
+            .selectable_panel_next(&setup.app_data);
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, colors, f, &fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        for (_, result_row) in get_result(&setup) {
+            for result_cell in result_row {
+                if BORDER_CHARS.contains(&result_cell.symbol()) {
+                    assert_eq!(result_cell.fg, Color::Gray);
+                }
+                assert!(!result_cell.modifier.contains(Modifier::REVERSED));
+            }
+        }
+    }
+
+    #[test]
+    /// Nothing is drawn when there isn't an embedded exec session
+    fn test_draw_blocks_exec_none() {
+        let mut setup = test_setup(30, 6, true, true);
+        let colors = setup.app_data.lock().config.app_colors;
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, colors, f, &setup.fd, &setup.gui_state);
+            })
+            .unwrap();
+
+        for (_, result_row) in get_result(&setup) {
+            for result_cell in result_row {
+                assert_eq!(result_cell.symbol(), " ");
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph, Wrap},
+};
+
+use super::SELECT_ARROW;
+use crate::{
+    app_data::FileBrowser,
+    config::{AppColors, Keymap},
+    ui::draw_blocks::inspect::title_block,
+};
+
+/// Create the lower title, with the keys used to navigate, save, & upload
+fn generate_lower_title(keymap: &Keymap) -> String {
+    let mut clear_key = keymap.clear.0.to_string();
+    if let Some(x) = keymap.clear.1 {
+        clear_key.push_str(&format!(" or {x}"));
+    }
+    format!(
+        " Enter open - Backspace back - {} save - {} save as tar - {} upload - {clear_key} to exit ",
+        keymap.save_logs.0, keymap.file_save_tar.0, keymap.file_upload.0
+    )
+}
+
+/// Draw the upload prompt, uses the log_search colors
+fn draw_upload(area: Rect, colors: AppColors, f: &mut Frame, upload: &str) {
+    let style_but = Style::default()
+        .fg(colors.log_search.button_text)
+        .bg(colors.log_search.highlight);
+    let style_desc = Style::default()
+        .fg(colors.log_search.text)
+        .bg(colors.log_search.background);
+
+    let line = vec![
+        Span::styled(" Esc ", style_but),
+        Span::styled(" cancel ", style_desc),
+        Span::from(" "),
+        Span::styled(" Enter ", style_but),
+        Span::styled(" upload ", style_desc),
+        Span::from(" "),
+        Span::styled(
+            " local file: ",
+            Style::default()
+                .fg(colors.log_search.highlight)
+                .bg(colors.log_search.background)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(upload.to_owned(), style_desc),
+    ];
+    let paragraph =
+        Paragraph::new(Line::from(line)).style(Style::default().bg(colors.log_search.background));
+    f.render_widget(paragraph, area);
+}
+
+/// Draw the file browser to the entire screen, with a preview of the selected file if one has been loaded
+pub fn draw(
+    f: &mut Frame,
+    colors: AppColors,
+    mut data: FileBrowser,
+    contains_upload: bool,
+    keymap: &Keymap,
+) {
+    let rect = f.area();
+    let (rect, upload_rect) = if contains_upload {
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Max(1)])
+            .split(rect);
+        (split[0], Some(split[1]))
+    } else {
+        (rect, None)
+    };
+
+    let upper_title = if data.truncated {
+        format!(" files: {} - {} - listing truncated ", data.name, data.path)
+    } else {
+        format!(" files: {} - {} ", data.name, data.path)
+    };
+    let lower_title = generate_lower_title(keymap);
+    let block = title_block(&upper_title, &lower_title, &colors);
+    let inner = block.inner(rect);
+    f.render_widget(block, rect);
+
+    let list_rect = if let Some((path, preview)) = data.preview.as_ref() {
+        let split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(inner);
+        let paragraph = Paragraph::new(preview.as_str())
+            .block(
+                Block::default()
+                    .borders(Borders::LEFT)
+                    .border_style(Style::default().fg(colors.borders.unselected))
+                    .title(format!(" {path} ").bold()),
+            )
+            .gray()
+            .wrap(Wrap { trim: false });
+        f.render_widget(paragraph, split[1]);
+        split[0]
+    } else {
+        inner
+    };
+
+    let items = data
+        .entries
+        .items
+        .iter()
+        .map(|i| ListItem::new(i.to_string()).gray())
+        .collect::<Vec<_>>();
+    if items.is_empty() {
+        f.render_widget(Paragraph::new(" empty directory").gray(), list_rect);
+    } else {
+        let list = List::new(items)
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+            .highlight_symbol(SELECT_ARROW);
+        f.render_stateful_widget(list, list_rect, &mut data.entries.state);
+    }
+
+    if let Some(upload_rect) = upload_rect {
+        draw_upload(upload_rect, colors, f, &data.upload);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ContainerId, FileBrowser, FileEntry, FileKind},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_browser() -> FileBrowser {
+        let mut browser = FileBrowser::new(ContainerId::from("1"), "container_1".to_owned());
+        browser.set_entries(
+            "/etc".to_owned(),
+            vec![
+                FileEntry::new("hosts", FileKind::File),
+                FileEntry::new("ssl", FileKind::Dir),
+                FileEntry::new("localtime", FileKind::Symlink),
+            ],
+            false,
+        );
+        browser
+    }
+
+    #[test]
+    /// Entries are drawn with directories first, the first entry selected, and border colors are correct
+    fn test_draw_blocks_files_default() {
+        let mut setup = test_setup(90, 8, true, true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), gen_browser(), false, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if matches!(row_index, 0 | 7) || matches!(result_cell_index, 0 | 89) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Preview drawn next to the entries, and the upload prompt shown at the bottom
+    fn test_draw_blocks_files_preview_upload() {
+        let mut setup = test_setup(90, 9, true, true);
+        let mut browser = gen_browser();
+        browser.entries.end();
+        browser.preview = Some((
+            "/etc/hosts".to_owned(),
+            "127.0.0.1 localhost\n::1 localhost".to_owned(),
+        ));
+        browser.upload = "/tmp/file.txt".to_owned();
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), browser, true, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// A directory too large to fully list, via the archive api, is marked as truncated in the title
+    fn test_draw_blocks_files_truncated() {
+        let mut setup = test_setup(90, 8, true, true);
+        let mut browser = gen_browser();
+        browser.truncated = true;
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), browser, false, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// An empty directory is shown as such
+    fn test_draw_blocks_files_empty() {
+        let mut setup = test_setup(90, 6, true, true);
+        let mut browser = gen_browser();
+        browser.set_entries("/tmp".to_owned(), vec![], false);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), browser, false, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
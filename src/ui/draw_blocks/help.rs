@@ -1136,6 +1136,29 @@ mod tests {
             delete_confirm: (KeyCode::Char('b'), None),
             delete_deny: (KeyCode::Char('c'), None),
             exec: (KeyCode::Char('d'), None),
+            exec_command: (KeyCode::Char('A'), None),
+            exec_debug: (KeyCode::Char('C'), None),
+            exec_close: (KeyCode::F(10), None),
+            file_browser: (KeyCode::Char('E'), None),
+            file_save_tar: (KeyCode::Char('t'), None),
+            file_upload: (KeyCode::Char('u'), None),
+            filesystem_diff: (KeyCode::Char('G'), None),
+            processes: (KeyCode::Char('I'), None),
+            prune: (KeyCode::Char('K'), None),
+            new_container: (KeyCode::Char('M'), None),
+            update_limits: (KeyCode::Char('O'), None),
+            networks: (KeyCode::Char('Q'), None),
+            log_level_filter: (KeyCode::Char('S'), None),
+            log_stream_filter: (KeyCode::Char('U'), None),
+            log_wrap: (KeyCode::Char('W'), None),
+            log_collapse: (KeyCode::Backspace, None),
+            log_group: (KeyCode::Insert, None),
+            log_follow: (KeyCode::Char('F'), None),
+            log_live: (KeyCode::Char('L'), None),
+            log_mark: (KeyCode::Char('Y'), None),
+            yank: (KeyCode::Char('9'), None),
+            log_time: (KeyCode::Char(';'), None),
+            log_time_column: (KeyCode::Char('#'), None),
             inspect: (KeyCode::Char('e'), None),
             filter_mode: (KeyCode::Char('f'), None),
             log_search_mode: (KeyCode::Char('g'), None),
@@ -1195,6 +1218,29 @@ mod tests {
             delete_confirm: (KeyCode::Char('c'), Some(KeyCode::Char('d'))),
             delete_deny: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
             exec: (KeyCode::Char('g'), Some(KeyCode::Char('h'))),
+            exec_command: (KeyCode::Char('A'), Some(KeyCode::Char('B'))),
+            exec_debug: (KeyCode::Char('C'), None),
+            exec_close: (KeyCode::F(10), None),
+            file_browser: (KeyCode::Char('E'), None),
+            file_save_tar: (KeyCode::Char('t'), None),
+            file_upload: (KeyCode::Char('u'), None),
+            filesystem_diff: (KeyCode::Char('G'), None),
+            processes: (KeyCode::Char('I'), None),
+            prune: (KeyCode::Char('K'), None),
+            new_container: (KeyCode::Char('M'), None),
+            update_limits: (KeyCode::Char('O'), None),
+            networks: (KeyCode::Char('Q'), None),
+            log_level_filter: (KeyCode::Char('S'), None),
+            log_stream_filter: (KeyCode::Char('U'), None),
+            log_wrap: (KeyCode::Char('W'), None),
+            log_collapse: (KeyCode::Backspace, None),
+            log_group: (KeyCode::Insert, None),
+            log_follow: (KeyCode::Char('F'), None),
+            log_live: (KeyCode::Char('L'), None),
+            log_mark: (KeyCode::Char('Y'), None),
+            yank: (KeyCode::Char('9'), None),
+            log_time: (KeyCode::Char(';'), None),
+            log_time_column: (KeyCode::Char('#'), None),
             inspect: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             filter_mode: (KeyCode::Char('k'), Some(KeyCode::Char('l'))),
             log_search_mode: (KeyCode::Char('m'), Some(KeyCode::Char('n'))),
@@ -1254,6 +1300,29 @@ mod tests {
             delete_confirm: (KeyCode::Char('c'), None),
             delete_deny: (KeyCode::Char('e'), Some(KeyCode::Char('f'))),
             exec: (KeyCode::Char('g'), None),
+            exec_command: (KeyCode::Char('A'), None),
+            exec_debug: (KeyCode::Char('C'), None),
+            exec_close: (KeyCode::F(10), None),
+            file_browser: (KeyCode::Char('E'), None),
+            file_save_tar: (KeyCode::Char('t'), None),
+            file_upload: (KeyCode::Char('u'), None),
+            filesystem_diff: (KeyCode::Char('G'), None),
+            processes: (KeyCode::Char('I'), None),
+            prune: (KeyCode::Char('K'), None),
+            new_container: (KeyCode::Char('M'), None),
+            update_limits: (KeyCode::Char('O'), None),
+            networks: (KeyCode::Char('Q'), None),
+            log_level_filter: (KeyCode::Char('S'), None),
+            log_stream_filter: (KeyCode::Char('U'), None),
+            log_wrap: (KeyCode::Char('W'), None),
+            log_collapse: (KeyCode::Backspace, None),
+            log_group: (KeyCode::Insert, None),
+            log_follow: (KeyCode::Char('F'), None),
+            log_live: (KeyCode::Char('L'), None),
+            log_mark: (KeyCode::Char('Y'), None),
+            yank: (KeyCode::Char('9'), None),
+            log_time: (KeyCode::Char(';'), None),
+            log_time_column: (KeyCode::Char('#'), None),
             inspect: (KeyCode::Char('i'), Some(KeyCode::Char('j'))),
             filter_mode: (KeyCode::Char('k'), None),
             log_search_mode: (KeyCode::Char('m'), Some(KeyCode::Char('n'))),
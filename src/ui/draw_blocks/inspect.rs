@@ -20,7 +20,11 @@ use crate::{
 };
 
 /// Create a bordered block with a title.
-fn title_block<'a>(upper_title: &'a str, lower_title: &'a str, colors: &AppColors) -> Block<'a> {
+pub fn title_block<'a>(
+    upper_title: &'a str,
+    lower_title: &'a str,
+    colors: &AppColors,
+) -> Block<'a> {
     Block::default()
         .borders(Borders::all())
         .border_type(ratatui::widgets::BorderType::Rounded)
@@ -53,7 +57,7 @@ fn generate_upper_title(data: &InspectData, keymap: &Keymap) -> String {
 }
 
 /// Generate the lower title, with the current scroll and the scrolling limits
-fn generate_lower_title(length: usize, width: usize, offset: ScrollOffset) -> String {
+pub fn generate_lower_title(length: usize, width: usize, offset: ScrollOffset) -> String {
     let length_width = length
         .to_string()
         .chars()
@@ -77,9 +81,9 @@ fn generate_lower_title(length: usize, width: usize, offset: ScrollOffset) -> St
 }
 
 /// Generate the Lines, remove lines & chars based on the offset and viewport
-fn gen_lines<'a>(data_as_str: &'a str, offset: &ScrollOffset, rect: &Rect) -> Vec<Line<'a>> {
-    let first_line_index = offset.y.max(0);
-    let first_char_index = offset.x.max(0);
+pub fn gen_lines<'a>(data_as_str: &'a str, offset: &ScrollOffset, rect: &Rect) -> Vec<Line<'a>> {
+    let first_line_index = offset.y;
+    let first_char_index = offset.x;
     let last_char_index = usize::from(rect.width.saturating_sub(2));
     let take_lines = usize::from(rect.height);
     //todo see if log scrolling does this - What?
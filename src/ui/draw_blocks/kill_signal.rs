@@ -0,0 +1,128 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    app_data::{ContainerName, Signal},
+    config::{AppColors, Keymap},
+    ui::gui_state::BoxLocation,
+};
+
+/// Create the instructions line, with the keys used to send, & cancel
+fn generate_instructions(keymap: &Keymap) -> String {
+    let mut clear_key = keymap.clear.0.to_string();
+    if let Some(x) = keymap.clear.1 {
+        clear_key.push_str(&format!(" or {x}"));
+    }
+    format!(" Enter send - {clear_key} cancel ")
+}
+
+/// Draw the kill signal picker in the centre of the screen, using the delete popup colors
+pub fn draw(
+    colors: AppColors,
+    f: &mut Frame,
+    keymap: &Keymap,
+    name: &ContainerName,
+    signal: Signal,
+) {
+    let title = format!(" Kill {} ", name.get());
+    let instructions = generate_instructions(keymap);
+
+    let block = Block::default()
+        .title(title.as_str())
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_delete.background)
+                .fg(colors.popup_delete.text),
+        )
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL);
+
+    let items = Signal::ALL
+        .iter()
+        .map(|i| ListItem::new(Line::from(format!("SIG{i}"))))
+        .collect::<Vec<_>>();
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .fg(colors.popup_delete.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(SELECT_ARROW);
+    let mut state =
+        ListState::default().with_selected(Signal::ALL.iter().position(|i| *i == signal));
+
+    let max_line_width = title.chars().count().max(instructions.chars().count()) + 4;
+    let lines = Signal::ALL.len() + 4;
+
+    let area = popup::draw(lines, max_line_width, f.area(), BoxLocation::MiddleCentre);
+    let split_popup = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(area);
+
+    let list_area = Layout::default()
+        .direction(Direction::Horizontal)
+        .horizontal_margin(1)
+        .constraints([Constraint::Min(1)])
+        .split(split_popup[0])[0];
+
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_stateful_widget(list, list_area, &mut state);
+    f.render_widget(
+        Paragraph::new(instructions).alignment(Alignment::Center),
+        split_popup[1],
+    );
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::{ContainerName, Signal},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Kill signal picker drawn in the centre of the screen, with the selected signal highlighted
+    fn test_draw_blocks_kill_signal() {
+        let mut setup = test_setup(40, 15, true, true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    AppColors::new(),
+                    f,
+                    &Keymap::new(),
+                    &ContainerName::from("container_1"),
+                    Signal::Kill,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        let selected = result
+            .iter()
+            .find(|(_, row)| row.iter().any(|i| i.symbol() == "▶"))
+            .unwrap();
+        assert!(
+            selected
+                .1
+                .iter()
+                .any(|i| i.modifier.contains(Modifier::BOLD) && i.fg == Color::Red)
+        );
+    }
+}
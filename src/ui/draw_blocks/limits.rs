@@ -0,0 +1,110 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::{
+    new_container::{WIDTH, error_line, error_style, field_line},
+    popup,
+};
+use crate::{
+    app_data::{Limits, LimitsField},
+    config::AppColors,
+    ui::gui_state::BoxLocation,
+};
+
+/// Draw the limits form in the centre of the screen, in the same style as the new container form
+pub fn draw(colors: AppColors, f: &mut Frame, form: &Limits) {
+    let width = WIDTH.min(usize::from(f.area().width));
+
+    let mut lines = vec![];
+    for field in LimitsField::ALL {
+        lines.push(field_line(
+            field.to_string(),
+            form.form.value(field),
+            form.form.selected == field,
+            field.is_choice(),
+            width,
+            colors,
+        ));
+        if let Some(error) = form.form.error(field) {
+            lines.push(error_line(error, colors));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(" {}", form.form.selected.hint())).italic());
+    if let Some(status) = form.form.status.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!(" ✗ {status} "),
+            error_style(colors),
+        )));
+    }
+
+    let area = popup::draw(lines.len() + 2, width, f.area(), BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(format!(" limits {} ", form.name).bold())
+        .title_alignment(Alignment::Center)
+        .title_bottom(
+            Line::from(" ↑ ↓ select - ← → choose - Enter update - Esc cancel ")
+                .bold()
+                .centered(),
+        )
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use bollard::models::HostConfig;
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ContainerId, Limits, LimitsField},
+        config::AppColors,
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Form prefilled with the current limits, with the memory field selected, and an error below the swap field
+    fn test_draw_blocks_limits() {
+        let mut setup = test_setup(84, 16, true, true);
+        let mut form = Limits::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            Some(&HostConfig {
+                memory: Some(1024 * 1024 * 1024),
+                memory_swap: Some(512 * 1024 * 1024),
+                nano_cpus: Some(500_000_000),
+                ..Default::default()
+            }),
+        );
+        form.validate();
+        form.form.selected = LimitsField::Memory;
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &form);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        let error_row = result
+            .iter()
+            .find(|(_, row)| row.iter().any(|i| i.symbol() == "✗"))
+            .unwrap();
+        assert!(error_row.1.iter().any(|i| i.bg == Color::Red));
+    }
+}
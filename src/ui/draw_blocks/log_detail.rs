@@ -0,0 +1,122 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem},
+};
+
+use super::{SELECT_ARROW, popup, prune::key_text};
+use crate::{
+    app_data::LogDetail,
+    config::{AppColors, Keymap},
+    ui::gui_state::BoxLocation,
+};
+
+/// Max width of the popup, long values are cut off, and can be seen in full by saving the logs
+const MAX_WIDTH: usize = 120;
+
+/// Draw every field of the selected JSON log line in the centre of the screen, keys aligned in a column
+pub fn draw(colors: AppColors, f: &mut Frame, keymap: &Keymap, mut detail: LogDetail) {
+    let key_width = detail
+        .fields
+        .items
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or_default();
+    let lower_title = format!(" ↑ ↓ scroll - Enter or {} close ", key_text(keymap.clear));
+    let upper_title = format!(" log detail {} ", detail.name);
+
+    let width = detail
+        .fields
+        .items
+        .iter()
+        .map(|(_, value)| key_width + value.chars().count() + 6)
+        .chain([
+            lower_title.chars().count() + 2,
+            upper_title.chars().count() + 2,
+        ])
+        .max()
+        .unwrap_or_default()
+        .min(MAX_WIDTH)
+        .min(usize::from(f.area().width));
+
+    let items = detail
+        .fields
+        .items
+        .iter()
+        .map(|(key, value)| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{key:<key_width$}  "),
+                    Style::default()
+                        .fg(colors.popup_help.text_highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::from(value.clone()),
+            ]))
+        })
+        .collect::<Vec<_>>();
+
+    let area = popup::draw(
+        detail.fields.items.len() + 2,
+        width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+    let block = Block::default()
+        .title(upper_title.bold())
+        .title_alignment(Alignment::Center)
+        .title_bottom(Line::from(lower_title).bold().centered())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD))
+        .highlight_symbol(SELECT_ARROW);
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut detail.fields.state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::{JsonLog, LogDetail, ScrollDirection},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Every field of a JSON log line, with the keys aligned & highlighted
+    fn test_draw_blocks_log_detail() {
+        let mut setup = test_setup(60, 9, true, true);
+        let json = JsonLog::parse(
+            r#"{"level":"error","msg":"connection refused","caller":"db/pool.go:42","user_id":42}"#,
+        )
+        .unwrap();
+        let mut detail = LogDetail::new("container_1".to_owned(), &json);
+        detail.scroll(&ScrollDirection::Down);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), detail);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        assert_eq!(result[2].1[13].fg, Color::White);
+        assert!(result[2].1[13].modifier.contains(Modifier::BOLD));
+        assert!(!result[2].1[23].modifier.contains(Modifier::BOLD));
+    }
+}
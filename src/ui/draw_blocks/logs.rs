@@ -83,6 +83,7 @@ mod tests {
     use crate::{
         app_data::{ContainerImage, ContainerName, ScrollDirection},
         config::AppColors,
+        tests::gen_stdout,
         ui::{
             FrameData, Status,
             draw_blocks::tests::{BORDER_CHARS, get_result, insert_logs, test_setup},
@@ -288,6 +289,116 @@ mod tests {
             }
         }
     }
+    #[test]
+    /// When wrapping, long lines are split into rows with a continuation indicator, and every row of the selected line is highlighted
+    fn test_draw_blocks_logs_wrap() {
+        let mut setup = test_setup(20, 8, true, true);
+        let logs = [
+            "1 first line is long enough to wrap",
+            "2 second",
+            "3 third line also wraps",
+        ]
+        .map(str::to_owned)
+        .to_vec();
+        setup
+            .app_data
+            .lock()
+            .update_log_by_id(gen_stdout(logs), &setup.ids[0]);
+        setup.app_data.lock().log_wrap_toggle();
+        setup.app_data.lock().log_scroll(&ScrollDirection::Up);
+        setup.app_data.lock().log_scroll(&ScrollDirection::Up);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if (1..=3).contains(&row_index) && (1..=18).contains(&result_cell_index) {
+                    assert!(result_cell.modifier.contains(Modifier::BOLD));
+                } else {
+                    assert!(!result_cell.modifier.contains(Modifier::BOLD));
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// The selected lines, from the mark to the selected line, are drawn reversed
+    fn test_draw_blocks_logs_mark() {
+        let mut setup = test_setup(36, 6, true, true);
+        insert_logs(&setup);
+        setup.app_data.lock().log_mark_toggle();
+        setup.app_data.lock().log_scroll(&ScrollDirection::Up);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                // Only the text of the line is reversed, not the padding
+                if (2..=3).contains(&row_index) && (3..=8).contains(&result_cell_index) {
+                    assert!(result_cell.modifier.contains(Modifier::REVERSED));
+                } else {
+                    assert!(!result_cell.modifier.contains(Modifier::REVERSED));
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Once scrolled up, following is paused, and the number of new lines below the selected line is shown at the bottom of the panel
+    fn test_draw_blocks_logs_unread() {
+        let mut setup = test_setup(36, 6, true, true);
+        insert_logs(&setup);
+        setup.app_data.lock().log_scroll(&ScrollDirection::Up);
+        let logs = (4..=5).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
+        setup
+            .app_data
+            .lock()
+            .update_log_by_id(gen_stdout(logs), &setup.ids[0]);
+
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(
+                    &setup.app_data,
+                    setup.area,
+                    AppColors::new(),
+                    f,
+                    &fd,
+                    &setup.gui_state,
+                );
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
     #[test]
     /// Logs correct displayed, changing log state also draws correctly
     fn test_draw_blocks_logs_some_line_two() {
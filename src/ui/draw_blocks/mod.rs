@@ -3,7 +3,7 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use ratatui::{
     layout::{Constraint, Rect},
-    style::Style,
+    style::{Style, Stylize},
     widgets::{Block, BorderType, Borders},
 };
 
@@ -11,20 +11,35 @@ use crate::config::AppColors;
 
 use super::{FrameData, GuiState, SelectablePanel, Status, gui_state::Region};
 
+pub mod changes;
 pub mod chart_bandwidth;
 pub mod chart_cpu_mem;
+pub mod command_output;
 pub mod commands;
 pub mod containers;
 pub mod delete_confirm;
 pub mod error;
+pub mod exec;
+pub mod files;
 pub mod filter;
 pub mod headers;
 pub mod help;
 pub mod info;
 pub mod inspect;
+pub mod kill_signal;
+pub mod limits;
+pub mod log_detail;
 pub mod logs;
+pub mod networks;
+pub mod new_container;
 pub mod popup;
 pub mod ports;
+pub mod processes;
+pub mod prompt;
+pub mod prune;
+pub mod recreate;
+pub mod run_command;
+pub mod save_logs;
 pub mod search_logs;
 
 pub const NAME_TEXT: &str = r#"                         88                              
@@ -54,11 +69,13 @@ pub const VERSION: &str = "0.00.000";
 pub const CONSTRAINT_50_50: [Constraint; 2] =
     [Constraint::Percentage(50), Constraint::Percentage(50)];
 pub const CONSTRAINT_100: [Constraint; 1] = [Constraint::Percentage(100)];
-pub const CONSTRAINT_POPUP: [Constraint; 5] = [
+pub const CONSTRAINT_POPUP: [Constraint; 7] = [
     Constraint::Min(2),
     Constraint::Max(1),
     Constraint::Max(1),
     Constraint::Max(3),
+    Constraint::Max(1),
+    Constraint::Max(3),
     Constraint::Min(1),
 ];
 
@@ -98,6 +115,10 @@ fn generate_block<'a>(
             format!("{}{}", panel.title(), fd.log_title)
         }
         SelectablePanel::Commands => String::new(),
+        SelectablePanel::Exec => fd.embedded_exec.as_ref().map_or_else(
+            || panel.title().to_owned(),
+            |i| format!("{} - {}", panel.title(), i.name()),
+        ),
     };
     if !title.is_empty() {
         title = format!(" {title} ");
@@ -114,6 +135,14 @@ fn generate_block<'a>(
             .title_bottom(x.to_owned())
             .title_alignment(ratatui::layout::Alignment::Right);
     }
+    // The number of new lines below the selected line, since following the logs was paused
+    if panel == SelectablePanel::Logs && fd.log_unread > 0 {
+        block = block.title_bottom(
+            ratatui::text::Line::from(format!(" {} new lines ↓ ", fd.log_unread))
+                .bold()
+                .left_aligned(),
+        );
+    }
     if !fd.status.contains(&Status::Filter) {
         if fd.selected_panel == panel {
             block = block.border_style(Style::default().fg(colors.borders.selected));
@@ -140,7 +169,7 @@ pub mod tests {
     use crate::{
         app_data::{AppData, ContainerId, ContainerImage, ContainerName, ContainerPorts},
         app_error::AppError,
-        tests::{gen_appdata, gen_containers},
+        tests::{gen_appdata, gen_containers, gen_stdout},
         ui::{GuiState, Rerender, Status, draw_frame},
     };
 
@@ -169,9 +198,13 @@ pub mod tests {
             Self {
                 chart_data: app_data.get_chart_data(),
                 color_logs: app_data.config.color_logs,
+                command_input: gui_data.get_command_input(),
+                command_timeout: gui_data.get_command_timeout(),
                 columns: app_data.get_width(),
                 container_title: app_data.get_container_title(),
                 delete_confirm: gui_data.get_delete_container(),
+                kill_signal: gui_data.get_kill_container(),
+                embedded_exec: gui_data.get_embedded_exec(),
                 filter_by,
                 filter_term: filter_term.cloned(),
                 has_containers: app_data.get_container_len() > 0,
@@ -183,6 +216,7 @@ pub mod tests {
                 loading_icon: gui_data.get_loading().to_string(),
                 log_height: gui_data.get_log_height(),
                 log_title: app_data.get_log_title(),
+                log_unread: app_data.get_log_unread(),
                 scroll_title: app_data.get_scroll_title(gui_data.get_screen_width()),
                 port_max_lens: app_data.get_longest_port(),
                 ports: app_data.get_selected_ports(),
@@ -241,7 +275,10 @@ pub mod tests {
     /// Insert some logs into the first container
     pub fn insert_logs(setup: &TuiTestSetup) {
         let logs = (1..=3).map(|i| format!("{i} line {i}")).collect::<Vec<_>>();
-        setup.app_data.lock().update_log_by_id(logs, &setup.ids[0]);
+        setup
+            .app_data
+            .lock()
+            .update_log_by_id(gen_stdout(logs), &setup.ids[0]);
     }
 
     #[allow(clippy::cast_precision_loss)]
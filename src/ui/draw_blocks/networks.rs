@@ -0,0 +1,202 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::{
+    SELECT_ARROW,
+    new_container::{WIDTH, error_line, error_style, field_line},
+    popup,
+    prune::key_text,
+};
+use crate::{
+    app_data::{ConnectField, ContainerNetworks, NetworkItem},
+    config::{AppColors, Keymap},
+    ui::gui_state::BoxLocation,
+};
+
+/// Width of the driver column, long enough for "overlay"
+const DRIVER_WIDTH: usize = 8;
+
+/// Width of the ip column, long enough for any ipv4 address
+const IP_WIDTH: usize = 15;
+
+/// A single network, with a filled circle if the container is attached to it, and the container's ip & aliases on it
+fn network_line(
+    network: &NetworkItem,
+    selected: bool,
+    name_width: usize,
+    colors: AppColors,
+) -> Line<'static> {
+    let (prefix, style) = if selected {
+        (
+            SELECT_ARROW,
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ("  ", Style::default())
+    };
+    let marker = if network.connected { "●" } else { "○" };
+    Line::from(Span::styled(
+        format!(
+            "{prefix}{marker} {:<name_width$}  {:<DRIVER_WIDTH$}  {:<IP_WIDTH$}  {}",
+            network.name,
+            network.driver,
+            network.ip.as_deref().unwrap_or_default(),
+            network.aliases.join(", ")
+        ),
+        style,
+    ))
+}
+
+/// Draw every network in the centre of the screen, and the connect form below them, if it's open
+pub fn draw(colors: AppColors, f: &mut Frame, keymap: &Keymap, networks: &ContainerNetworks) {
+    let width = WIDTH.min(usize::from(f.area().width));
+    let name_width = networks
+        .items
+        .items
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let selected = networks.items.state.selected();
+
+    let mut lines = networks
+        .items
+        .items
+        .iter()
+        .enumerate()
+        .map(|(index, network)| network_line(network, selected == Some(index), name_width, colors))
+        .collect::<Vec<_>>();
+    lines.push(Line::from(""));
+
+    let lower_title = if let Some(form) = networks.connect.as_ref() {
+        lines.push(Line::from(format!(" connect to {}", form.network)).bold());
+        for field in ConnectField::ALL {
+            lines.push(field_line(
+                field.to_string(),
+                form.form.value(field),
+                form.form.selected == field,
+                false,
+                width,
+                colors,
+            ));
+            if let Some(error) = form.form.error(field) {
+                lines.push(error_line(error, colors));
+            }
+        }
+        lines.push(Line::from(format!(" {}", form.form.selected.hint())).italic());
+        " ↑ ↓ select - Enter connect - Esc back ".to_owned()
+    } else {
+        lines.push(
+            Line::from(" ● attached - Enter to disconnect, or to connect with an alias or ip")
+                .italic(),
+        );
+        format!(
+            " ↑ ↓ select - Enter connect/disconnect - {} close ",
+            key_text(keymap.clear)
+        )
+    };
+    if let Some(status) = networks.status.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!(" ✗ {status} "),
+            error_style(colors),
+        )));
+    }
+
+    let area = popup::draw(lines.len() + 2, width, f.area(), BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(format!(" networks {} ", networks.name).bold())
+        .title_alignment(Alignment::Center)
+        .title_bottom(Line::from(lower_title).bold().centered())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ContainerId, ContainerNetworks, NetworkItem, ScrollDirection},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_networks() -> ContainerNetworks {
+        ContainerNetworks::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            vec![
+                NetworkItem {
+                    aliases: vec!["container_1".to_owned(), "db".to_owned()],
+                    connected: true,
+                    driver: "bridge".to_owned(),
+                    ip: Some("172.20.0.2".to_owned()),
+                    name: "compose_default".to_owned(),
+                },
+                NetworkItem {
+                    aliases: vec![],
+                    connected: false,
+                    driver: "host".to_owned(),
+                    ip: None,
+                    name: "host".to_owned(),
+                },
+            ],
+        )
+    }
+
+    #[test]
+    /// Every network, with the attached network showing the ip & aliases of the container
+    fn test_draw_blocks_networks() {
+        let mut setup = test_setup(84, 9, true, true);
+        let networks = gen_networks();
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), &networks);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        assert_eq!(result[2].1[5].fg, Color::White);
+    }
+
+    #[test]
+    /// Connect form shown below the networks, with an error for an invalid ip, and the daemon error at the bottom
+    fn test_draw_blocks_networks_connect() {
+        let mut setup = test_setup(84, 14, true, true);
+        let mut networks = gen_networks();
+        networks.scroll(&ScrollDirection::Down);
+        networks.open_connect();
+        let form = networks.connect.as_mut().unwrap();
+        form.form.change_field(true);
+        for c in "10.0.0".chars() {
+            form.form.push(c);
+        }
+        form.validate();
+        networks.status = Some("container cannot be disconnected from host network".to_owned());
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), &networks);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
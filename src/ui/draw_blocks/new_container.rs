@@ -0,0 +1,192 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Modifier, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    app_data::{FormField, NewContainer},
+    config::AppColors,
+    ui::gui_state::BoxLocation,
+};
+
+/// Width of the popup, if the screen is wide enough
+pub(super) const WIDTH: usize = 80;
+
+/// Width of the field label column
+const LABEL_WIDTH: usize = 9;
+
+/// The end of a value, if it's too long to fit, so that the text being typed is always visible
+fn visible(value: &str, width: usize) -> String {
+    let count = value.chars().count();
+    if count <= width {
+        value.to_owned()
+    } else {
+        format!(
+            "…{}",
+            value.chars().skip(count + 1 - width).collect::<String>()
+        )
+    }
+}
+
+/// A single field, with the selected arrow, label, & value, choice fields show arrows either side of the value
+pub(super) fn field_line(
+    label: String,
+    value: &str,
+    selected: bool,
+    is_choice: bool,
+    width: usize,
+    colors: AppColors,
+) -> Line<'static> {
+    let (prefix, style) = if selected {
+        (
+            SELECT_ARROW,
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else {
+        ("  ", Style::default())
+    };
+    let value_width = width.saturating_sub(LABEL_WIDTH + 6);
+    let value = match (is_choice, selected) {
+        (true, true) => format!("◂ {} ▸", visible(value, value_width.saturating_sub(4))),
+        (false, true) => format!("{}▌", visible(value, value_width.saturating_sub(1))),
+        _ => visible(value, value_width),
+    };
+    Line::from(vec![
+        Span::styled(format!("{prefix}{label:<LABEL_WIDTH$}"), style),
+        Span::styled(value, style),
+    ])
+}
+
+/// A validation error, shown below the field it refers to
+pub(super) fn error_line(error: &str, colors: AppColors) -> Line<'static> {
+    Line::from(vec![
+        Span::from(" ".repeat(LABEL_WIDTH + 2)),
+        Span::styled(format!(" ✗ {error} "), error_style(colors)),
+    ])
+}
+
+pub(super) fn error_style(colors: AppColors) -> Style {
+    Style::default()
+        .bg(colors.popup_error.background)
+        .fg(colors.popup_error.text)
+}
+
+/// Draw the new container form in the centre of the screen, with any validation errors below the field they refer to
+pub fn draw(colors: AppColors, f: &mut Frame, form: &NewContainer) {
+    let width = WIDTH.min(usize::from(f.area().width));
+
+    let mut lines = vec![];
+    for field in FormField::ALL {
+        lines.push(field_line(
+            field.to_string(),
+            form.form.value(field),
+            form.form.selected == field,
+            field.is_choice(),
+            width,
+            colors,
+        ));
+        if let Some(error) = form.form.error(field) {
+            lines.push(error_line(error, colors));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(" {}", form.form.selected.hint())).italic());
+    if let Some(status) = form.form.status.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!(" ✗ {status} "),
+            error_style(colors),
+        )));
+    }
+
+    let area = popup::draw(lines.len() + 2, width, f.area(), BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(" new container ".bold())
+        .title_alignment(Alignment::Center)
+        .title_bottom(
+            Line::from(" ↑ ↓ select - ← → choose - Enter create - Esc cancel ")
+                .bold()
+                .centered(),
+        )
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{FormField, NewContainer},
+        config::AppColors,
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Empty form, with the image field selected, and the restart policy defaulting to no
+    fn test_draw_blocks_new_container() {
+        let mut setup = test_setup(84, 18, true, true);
+        let mut form = NewContainer::new();
+        form.set_lists(vec!["redis:7".to_owned()], vec![]);
+        form.choose(true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &form);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        assert_eq!(result[3].1[5].fg, Color::White);
+        assert_eq!(result[4].1[5].fg, Color::Black);
+    }
+
+    #[test]
+    /// Validation errors shown below each invalid field, and the daemon error shown at the bottom
+    fn test_draw_blocks_new_container_errors() {
+        let mut setup = test_setup(84, 22, true, true);
+        let mut form = NewContainer::new();
+        form.form.selected = FormField::Ports;
+        for c in "80:http".chars() {
+            form.form.push(c);
+        }
+        form.validate();
+        form.form.status = Some("Conflict. The container name is already in use".to_owned());
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &form);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        let error_row = result
+            .iter()
+            .find(|(_, row)| row.iter().any(|i| i.symbol() == "✗"))
+            .unwrap();
+        assert!(error_row.1.iter().any(|i| i.bg == Color::Red));
+    }
+
+    #[test]
+    /// Long values are trimmed from the start, so that the end is always visible
+    fn test_draw_blocks_new_container_visible() {
+        assert_eq!(super::visible("redis", 10), "redis");
+        assert_eq!(super::visible("0123456789", 5), "…6789");
+    }
+}
@@ -0,0 +1,225 @@
+use ratatui::{
+    Frame,
+    layout::Constraint,
+    style::{Modifier, Style, Stylize},
+    text::Text,
+    widgets::{Cell, Paragraph, Row, Table, TableState},
+};
+
+use super::SELECT_ARROW;
+use crate::{
+    app_data::{ContainerProcesses, ProcessHeader, SortedOrder},
+    config::{AppColors, Keymap},
+    ui::draw_blocks::inspect::title_block,
+};
+
+/// Create the lower title, with the keys used to sort, & send a signal, if signals are available
+fn generate_lower_title(keymap: &Keymap, signals: bool) -> String {
+    let mut clear_key = keymap.clear.0.to_string();
+    if let Some(x) = keymap.clear.1 {
+        clear_key.push_str(&format!(" or {x}"));
+    }
+    let signal_keys = if signals {
+        format!(
+            " Enter send signal - {} change signal -",
+            keymap.select_next_panel.0
+        )
+    } else {
+        String::new()
+    };
+    format!(
+        "{signal_keys} {} reset sort - {clear_key} to exit ",
+        keymap.sort_reset.0,
+    )
+}
+
+/// Convert rss, which is in KiB, into a human readable string
+fn format_rss(rss: u64) -> String {
+    let mut value = rss as f64;
+    for unit in ["KiB", "MiB", "GiB"] {
+        if value < 1024.0 {
+            return format!("{value:.1} {unit}");
+        }
+        value /= 1024.0;
+    }
+    format!("{value:.1} TiB")
+}
+
+/// Generate the header row, each column shows the key used to sort by it, and the sort order if currently sorted
+fn gen_header<'a>(data: &ContainerProcesses, keymap: &Keymap) -> Row<'a> {
+    let keys = [
+        keymap.sort_by_name.0,
+        keymap.sort_by_state.0,
+        keymap.sort_by_status.0,
+        keymap.sort_by_cpu.0,
+        keymap.sort_by_memory.0,
+    ];
+    Row::new(
+        ProcessHeader::ALL
+            .into_iter()
+            .zip(keys)
+            .map(|(header, key)| {
+                let suffix = match data.sorted_by {
+                    Some((h, SortedOrder::Asc)) if h == header => " ▲",
+                    Some((h, SortedOrder::Desc)) if h == header => " ▼",
+                    _ => "",
+                };
+                Cell::from(format!("{header} ({key}){suffix}"))
+            }),
+    )
+    .style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+/// Draw the processes of a container to the entire screen, as a table
+pub fn draw(f: &mut Frame, colors: AppColors, data: ContainerProcesses, keymap: &Keymap) {
+    let rect = f.area();
+    let signal = if data.signals {
+        format!("signal: SIG{}", data.signal)
+    } else {
+        "signals need oxker on the Docker host".to_owned()
+    };
+    let upper_title = format!(
+        " processes: {} - {} - {signal} ",
+        data.name,
+        data.processes.items.len(),
+    );
+    let lower_title = generate_lower_title(keymap, data.signals);
+    let block = title_block(&upper_title, &lower_title, &colors);
+
+    if data.processes.items.is_empty() {
+        let paragraph = Paragraph::new(" no processes").block(block).gray();
+        f.render_widget(paragraph, rect);
+        return;
+    }
+
+    let rows = data.processes.items.iter().map(|i| {
+        Row::new([
+            Cell::from(i.pid.to_string()),
+            Cell::from(i.user.clone()),
+            Cell::from(format!("{:.1}", i.cpu)),
+            Cell::from(format_rss(i.rss)),
+            Cell::from(Text::from(i.command.clone())),
+        ])
+        .gray()
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Length(11),
+            Constraint::Length(12),
+            Constraint::Min(12),
+        ],
+    )
+    .header(gen_header(&data, keymap))
+    .block(block)
+    .row_highlight_style(Style::default().add_modifier(Modifier::BOLD))
+    .highlight_symbol(SELECT_ARROW);
+
+    let mut state = TableState::default()
+        .with_selected(data.processes.state.selected())
+        .with_offset(data.processes.state.offset());
+    f.render_stateful_widget(table, rect, &mut state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        app_data::{ContainerId, ContainerProcesses, Process, ProcessHeader, Signal},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    fn gen_processes() -> ContainerProcesses {
+        let process = |pid: u64, cpu: f64, rss: u64, command: &str| Process {
+            command: command.to_owned(),
+            cpu,
+            pid,
+            rss,
+            user: "root".to_owned(),
+        };
+        ContainerProcesses::new(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            vec![
+                process(4321, 12.5, 2_202_009, "nginx: worker process"),
+                process(
+                    1234,
+                    0.1,
+                    4096,
+                    "nginx: master process nginx -g daemon off;",
+                ),
+                process(5000, 0.0, 512, "sh"),
+            ],
+            true,
+        )
+    }
+
+    #[test]
+    /// Processes drawn as a table, sorted by pid, the first process selected, and border colors are correct
+    fn test_draw_blocks_processes_default() {
+        let mut setup = test_setup(90, 7, true, true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), gen_processes(), &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        for (row_index, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                if matches!(row_index, 0 | 6) || matches!(result_cell_index, 0 | 89) {
+                    assert_eq!(result_cell.fg, Color::LightCyan);
+                } else if row_index == 1 || row_index == 2 {
+                    assert!(result_cell.modifier.contains(Modifier::BOLD));
+                }
+            }
+        }
+    }
+
+    #[test]
+    /// Sort order shown in the header, and the selected signal shown in the title
+    fn test_draw_blocks_processes_sorted_signal() {
+        let mut setup = test_setup(90, 7, true, true);
+        let mut processes = gen_processes();
+        processes.set_sort_by_header(ProcessHeader::Cpu);
+        processes.set_sort_by_header(ProcessHeader::Cpu);
+        processes.signal = Signal::Kill;
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), processes, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Without signals, the title explains why, and the signal keys aren't shown
+    fn test_draw_blocks_processes_signals_unavailable() {
+        let mut setup = test_setup(90, 7, true, true);
+        let mut processes = gen_processes();
+        processes.signals = false;
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(f, AppColors::new(), processes, &Keymap::new());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// rss converted to the largest sensible unit
+    fn test_draw_blocks_processes_format_rss() {
+        assert_eq!(super::format_rss(512), "512.0 KiB");
+        assert_eq!(super::format_rss(4096), "4.0 MiB");
+        assert_eq!(super::format_rss(2_202_009), "2.1 GiB");
+    }
+}
@@ -0,0 +1,139 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::{
+    new_container::{WIDTH, error_line, error_style, field_line},
+    popup,
+};
+use crate::{app_data::Prompt, config::AppColors, ui::gui_state::BoxLocation};
+
+/// Draw the rename, commit, or log time, prompt in the centre of the screen, in the same style as the new container form
+pub fn draw(colors: AppColors, f: &mut Frame, prompt: &Prompt) {
+    let width = WIDTH.min(usize::from(f.area().width));
+
+    let mut lines = vec![];
+    for field in prompt.form.fields() {
+        lines.push(field_line(
+            field.to_string(),
+            prompt.form.value(field),
+            prompt.form.selected == field,
+            false,
+            width,
+            colors,
+        ));
+        if let Some(error) = prompt.form.error(field) {
+            lines.push(error_line(error, colors));
+        }
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(format!(" {}", prompt.form.selected.hint())).italic());
+    if let Some(status) = prompt.form.status.as_ref() {
+        lines.push(Line::from(Span::styled(
+            format!(" ✗ {status} "),
+            error_style(colors),
+        )));
+    }
+
+    let area = popup::draw(lines.len() + 2, width, f.area(), BoxLocation::MiddleCentre);
+    let block = Block::default()
+        .title(format!(" {} {} ", prompt.kind, prompt.name).bold())
+        .title_alignment(Alignment::Center)
+        .title_bottom(
+            Line::from(format!(
+                " ↑ ↓ select - Enter {} - Esc cancel ",
+                prompt.kind.action()
+            ))
+            .bold()
+            .centered(),
+        )
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        );
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{ContainerId, Prompt},
+        config::AppColors,
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Rename prompt, prefilled with the current name
+    fn test_draw_blocks_prompt_rename() {
+        let mut setup = test_setup(84, 9, true, true);
+        let prompt = Prompt::rename(ContainerId::from("1"), "container_1".to_owned());
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &prompt);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+
+    #[test]
+    /// Commit prompt, with an invalid repo, and the daemon error shown at the bottom
+    fn test_draw_blocks_prompt_commit() {
+        let mut setup = test_setup(84, 12, true, true);
+        let mut prompt = Prompt::commit(ContainerId::from("1"), "container_1".to_owned());
+        for c in "Debug".chars() {
+            prompt.form.push(c);
+        }
+        prompt.validate();
+        prompt.form.status = Some("No such container: 1".to_owned());
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &prompt);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        let error_row = result
+            .iter()
+            .find(|(_, row)| row.iter().any(|i| i.symbol() == "✗"))
+            .unwrap();
+        assert!(error_row.1.iter().any(|i| i.bg == Color::Red));
+    }
+
+    #[test]
+    /// Log time prompt, with an invalid jump time
+    fn test_draw_blocks_prompt_log_time() {
+        let mut setup = test_setup(84, 11, true, true);
+        let mut prompt = Prompt::log_time(
+            ContainerId::from("1"),
+            "container_1".to_owned(),
+            (Some("2025-02-18T08:00:00Z".parse().unwrap()), None),
+            None,
+        );
+        for c in "yesterday".chars() {
+            prompt.form.push(c);
+        }
+        prompt.validate();
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &prompt);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
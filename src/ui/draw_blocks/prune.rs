@@ -0,0 +1,221 @@
+use ratatui::{
+    Frame,
+    layout::Constraint,
+    style::{Modifier, Style, Stylize},
+    widgets::{
+        Block, BorderType, Borders, Cell, Clear, List, ListItem, Paragraph, Row, Table, TableState,
+    },
+};
+
+use super::{SELECT_ARROW, popup};
+use crate::{
+    app_data::{ByteStats, Prune, PrunePreview},
+    config::{AppColors, Keymap},
+    ui::gui_state::BoxLocation,
+};
+
+/// Width of the size column of the preview table
+const SIZE_WIDTH: usize = 12;
+
+/// Format a key, and its optional secondary key, as "a or b"
+pub(super) fn key_text(
+    key: (crossterm::event::KeyCode, Option<crossterm::event::KeyCode>),
+) -> String {
+    let mut output = key.0.to_string();
+    if let Some(x) = key.1 {
+        output.push_str(&format!(" or {x}"));
+    }
+    output
+}
+
+/// Generate a block in the delete popup colors, as pruning can't be undone
+fn gen_block<'a>(upper_title: String, lower_title: String, colors: AppColors) -> Block<'a> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_delete.background)
+                .fg(colors.popup_delete.text),
+        )
+        .title(upper_title.bold().into_centered_line())
+        .title_bottom(lower_title.bold().into_centered_line())
+}
+
+/// Draw the list of prune targets
+fn draw_targets(colors: AppColors, f: &mut Frame, keymap: &Keymap, mut prune: Prune) {
+    let upper_title = " prune ".to_owned();
+    let lower_title = format!(" Enter preview - {} to exit ", key_text(keymap.clear));
+    let width = prune
+        .targets
+        .items
+        .iter()
+        .map(|i| i.to_string().chars().count() + SELECT_ARROW.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(lower_title.chars().count())
+        + 4;
+    let area = popup::draw(
+        prune.targets.items.len() + 2,
+        width,
+        f.area(),
+        BoxLocation::MiddleCentre,
+    );
+
+    let items = prune
+        .targets
+        .items
+        .iter()
+        .map(|i| ListItem::new(i.to_string()))
+        .collect::<Vec<_>>();
+    let list = List::new(items)
+        .block(gen_block(upper_title, lower_title, colors))
+        .highlight_style(
+            Style::default()
+                .fg(colors.popup_delete.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(SELECT_ARROW);
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut prune.targets.state);
+}
+
+/// Draw the objects that will be removed, with the total space to be reclaimed
+fn draw_preview(colors: AppColors, f: &mut Frame, keymap: &Keymap, preview: &PrunePreview) {
+    let upper_title = format!(
+        " prune {} - {} - reclaim {} ",
+        preview.target,
+        preview.items.items.len(),
+        ByteStats::new(preview.total())
+    );
+    let lower_title = format!(
+        " {} confirm - {} back ",
+        key_text(keymap.delete_confirm),
+        key_text(keymap.delete_deny)
+    );
+    let name_width = preview
+        .items
+        .items
+        .iter()
+        .map(|i| i.name.chars().count())
+        .max()
+        .unwrap_or_default();
+    let width = (name_width + SIZE_WIDTH + SELECT_ARROW.chars().count() + 1)
+        .max(upper_title.chars().count())
+        .max(lower_title.chars().count())
+        + 4;
+    let lines = preview.items.items.len().max(1) + 2;
+    let area = popup::draw(lines, width, f.area(), BoxLocation::MiddleCentre);
+    let block = gen_block(upper_title, lower_title, colors);
+
+    f.render_widget(Clear, area);
+    if preview.items.items.is_empty() {
+        f.render_widget(Paragraph::new(" nothing to remove").block(block), area);
+        return;
+    }
+
+    let rows = preview.items.items.iter().map(|i| {
+        Row::new([
+            Cell::from(i.name.clone()),
+            Cell::from(format!("{:>SIZE_WIDTH$}", i.size_text())),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(1),
+            Constraint::Length(u16::try_from(SIZE_WIDTH).unwrap_or_default()),
+        ],
+    )
+    .block(block)
+    .row_highlight_style(
+        Style::default()
+            .fg(colors.popup_delete.text_highlight)
+            .add_modifier(Modifier::BOLD),
+    )
+    .highlight_symbol(SELECT_ARROW);
+    let mut state = TableState::default()
+        .with_selected(preview.items.state.selected())
+        .with_offset(preview.items.state.offset());
+    f.render_stateful_widget(table, area, &mut state);
+}
+
+/// Draw the prune popup in the centre of the screen, either the list of targets, or the preview of the chosen target
+pub fn draw(colors: AppColors, f: &mut Frame, keymap: &Keymap, prune: Prune) {
+    if let Some(preview) = prune.preview.as_ref() {
+        draw_preview(colors, f, keymap, preview);
+    } else {
+        draw_targets(colors, f, keymap, prune);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{Prune, PruneItem, PrunePreview, PruneTarget, ScrollDirection},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// The list of prune targets drawn in the delete popup colors, with the selected target highlighted
+    fn test_draw_blocks_prune_targets() {
+        let mut setup = test_setup(50, 9, true, true);
+        let mut prune = Prune::new();
+        prune.scroll(&ScrollDirection::Down);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), prune);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        assert_eq!(result[1].1[10].bg, Color::White);
+        assert_eq!(result[3].1[14].fg, Color::Red);
+    }
+
+    #[test]
+    /// The preview shows every item, with its size, and the total to be reclaimed in the title
+    fn test_draw_blocks_prune_preview() {
+        let mut setup = test_setup(60, 8, true, true);
+        let mut prune = Prune::new();
+        prune.preview = Some(PrunePreview::new(
+            PruneTarget::Volumes,
+            vec![
+                PruneItem {
+                    name: "volume_1".to_owned(),
+                    size: Some(2_000_000),
+                },
+                PruneItem {
+                    name: "volume_2".to_owned(),
+                    size: None,
+                },
+            ],
+        ));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), prune);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let mut setup = test_setup(60, 5, true, true);
+        let mut prune = Prune::new();
+        prune.preview = Some(PrunePreview::new(PruneTarget::Networks, vec![]));
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), prune);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -0,0 +1,109 @@
+use ratatui::{
+    Frame,
+    layout::Alignment,
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+};
+
+use super::popup;
+use crate::{app_data::Recreate, config::AppColors, ui::gui_state::BoxLocation};
+
+/// Width of the layer status column, long enough for "Verifying Checksum"
+const STATUS_WIDTH: usize = 18;
+
+/// A single line for each layer, with the short id, status, and percentage, if known
+fn layer_lines(recreate: &Recreate) -> Vec<Line<'static>> {
+    recreate
+        .layers
+        .iter()
+        .map(|i| {
+            let id = i.id.chars().take(12).collect::<String>();
+            let percent = i.percent().map_or_else(String::new, |p| format!("{p:>3}%"));
+            Line::from(format!(
+                " {id:<12} {:<STATUS_WIDTH$} {percent:>4} ",
+                i.status
+            ))
+        })
+        .collect()
+}
+
+/// Draw the progress of a pull & recreate in the bottom centre of the screen, it doesn't take focus, and is removed once complete
+pub fn draw(colors: AppColors, f: &mut Frame, recreate: &Recreate) {
+    let title = format!(" pull & recreate {} ", recreate.name);
+    let mut lines = vec![Line::from(format!(" {} - {} ", recreate.image, recreate.stage)).bold()];
+    lines.extend(layer_lines(recreate));
+    if let Some(status) = recreate.status.as_ref() {
+        lines.push(Line::from(format!(" {status} ")));
+    }
+
+    let width = lines
+        .iter()
+        .map(Line::width)
+        .chain([title.chars().count()])
+        .max()
+        .unwrap_or_default()
+        + 2;
+    let area = popup::draw(lines.len() + 2, width, f.area(), BoxLocation::BottomCentre);
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_info.background)
+                .fg(colors.popup_info.text),
+        );
+    f.render_widget(Clear, area);
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+    use ratatui::style::Color;
+
+    use crate::{
+        app_data::{Recreate, RecreateStage},
+        config::AppColors,
+        ui::draw_blocks::tests::{get_result, test_setup},
+    };
+
+    #[test]
+    /// Progress drawn at the bottom of the screen, with each layer, and the current stage
+    fn test_draw_blocks_recreate() {
+        let mut setup = test_setup(60, 10, true, true);
+        let mut recreate = Recreate::new("container_1".to_owned(), "redis:latest".to_owned());
+        let status = |i: &str| Some(i.to_owned());
+        recreate.pull_progress(
+            status("0123456789abcdef"),
+            status("Downloading"),
+            Some(50),
+            Some(200),
+        );
+        recreate.pull_progress(status("bbb"), status("Already exists"), None, None);
+        recreate.pull_progress(None, status("Digest: sha256:123"), None, None);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &recreate);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        recreate.stage = RecreateStage::Starting;
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &recreate);
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+
+        let result = get_result(&setup).collect::<Vec<_>>();
+        assert_eq!(result[9].1[30].bg, Color::Blue);
+    }
+}
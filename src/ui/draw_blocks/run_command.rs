@@ -0,0 +1,103 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use crate::{config::AppColors, ui::FrameData};
+
+/// Draw the one-off command prompt bar, uses the log_search colors
+pub fn draw(area: Rect, colors: AppColors, frame: &mut Frame, fd: &FrameData) {
+    let style_but = Style::default()
+        .fg(colors.log_search.button_text)
+        .bg(colors.log_search.highlight);
+    let style_desc = Style::default()
+        .fg(colors.log_search.text)
+        .bg(colors.log_search.background);
+    let space = || Span::from(" ");
+
+    let line = vec![
+        Span::styled(" Esc ", style_but),
+        Span::styled(" cancel ", style_desc),
+        space(),
+        Span::styled(" Enter ", style_but),
+        Span::styled(" run ", style_desc),
+        space(),
+        Span::styled(
+            " command: ",
+            Style::default()
+                .fg(colors.log_search.highlight)
+                .bg(colors.log_search.background)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(fd.command_input.clone(), style_desc),
+    ];
+
+    let paragraph = Paragraph::new(Line::from(line))
+        .alignment(ratatui::layout::Alignment::Left)
+        .style(Style::default().bg(colors.log_search.background));
+    frame.render_widget(paragraph, area);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+
+    use insta::assert_snapshot;
+    use ratatui::style::{Color, Modifier};
+
+    use crate::{
+        config::AppColors,
+        ui::{
+            FrameData, Status,
+            draw_blocks::tests::{get_result, test_setup},
+        },
+    };
+
+    #[test]
+    /// Command prompt is drawn with the current input, & colors are correct
+    fn test_draw_blocks_run_command_row() {
+        let mut setup = test_setup(60, 1, true, true);
+
+        setup.gui_state.lock().status_push(Status::RunCommand);
+        for c in "ps aux".chars() {
+            setup.gui_state.lock().command_input_push(c);
+        }
+        let fd = FrameData::from((&setup.app_data, &setup.gui_state));
+
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(setup.area, AppColors::new(), f, &fd);
+            })
+            .unwrap();
+
+        assert_snapshot!(setup.terminal.backend());
+
+        for (_, result_row) in get_result(&setup) {
+            for (result_cell_index, result_cell) in result_row.iter().enumerate() {
+                match result_cell_index {
+                    0..=4 | 14..=20 => {
+                        assert_eq!(result_cell.bg, Color::Magenta);
+                        assert_eq!(result_cell.fg, Color::Black);
+                    }
+                    5..=12 | 21..=25 | 37..=42 => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Gray);
+                    }
+                    27..=36 => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Magenta);
+                        assert_eq!(result_cell.modifier, Modifier::BOLD);
+                    }
+                    _ => {
+                        assert_eq!(result_cell.bg, Color::Reset);
+                        assert_eq!(result_cell.fg, Color::Reset);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use ratatui::{
+    Frame,
+    style::{Modifier, Style, Stylize},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem},
+};
+
+use super::{SELECT_ARROW, popup, prune::key_text};
+use crate::{
+    app_data::{SaveField, SaveLogs},
+    config::{AppColors, Keymap},
+    ui::gui_state::BoxLocation,
+};
+
+/// Draw the save logs popup in the centre of the screen, a row per option, with the value of each option changed with left & right
+pub fn draw(colors: AppColors, f: &mut Frame, keymap: &Keymap, mut save_logs: SaveLogs) {
+    let upper_title = " save logs ".to_owned();
+    let lower_title = format!(
+        " ← → change - Enter save - {} cancel ",
+        key_text(keymap.clear)
+    );
+    let field_width = SaveField::ALL
+        .iter()
+        .map(|i| i.to_string().chars().count())
+        .max()
+        .unwrap_or_default();
+    let rows = SaveField::ALL
+        .into_iter()
+        .map(|i| {
+            format!(
+                "{:<field_width$}  ‹ {} ›",
+                i.to_string(),
+                save_logs.value(i)
+            )
+        })
+        .collect::<Vec<_>>();
+    let width = rows
+        .iter()
+        .map(|i| i.chars().count() + SELECT_ARROW.chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(lower_title.chars().count())
+        + 4;
+    let area = popup::draw(rows.len() + 2, width, f.area(), BoxLocation::MiddleCentre);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .style(
+            Style::default()
+                .bg(colors.popup_help.background)
+                .fg(colors.popup_help.text),
+        )
+        .title(upper_title.bold().into_centered_line())
+        .title_bottom(lower_title.bold().into_centered_line());
+    let list = List::new(rows.into_iter().map(ListItem::new))
+        .block(block)
+        .highlight_style(
+            Style::default()
+                .fg(colors.popup_help.text_highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol(SELECT_ARROW);
+
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(list, area, &mut save_logs.fields.state);
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use insta::assert_snapshot;
+
+    use crate::{
+        app_data::{SaveLogs, ScrollDirection},
+        config::{AppColors, Keymap},
+        ui::draw_blocks::tests::test_setup,
+    };
+
+    #[test]
+    /// Save logs popup, with the format changed to NDJSON, and gzip enabled
+    fn test_draw_blocks_save_logs() {
+        let mut setup = test_setup(64, 8, true, true);
+        let mut save_logs = SaveLogs::new("container_1".to_owned(), 3);
+        save_logs.scroll(&ScrollDirection::Down);
+        save_logs.cycle(false);
+        save_logs.scroll(&ScrollDirection::Down);
+        save_logs.cycle(true);
+        setup
+            .terminal
+            .draw(|f| {
+                super::draw(AppColors::new(), f, &Keymap::new(), save_logs.clone());
+            })
+            .unwrap();
+        assert_snapshot!(setup.terminal.backend());
+    }
+}
@@ -9,8 +9,10 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use crate::{
-    app_data::{AppData, ContainerId, Header, ScrollDirection},
-    exec::ExecMode,
+    app_data::{
+        AppData, ContainerId, DeleteOption, DeleteOptions, Header, ScrollDirection, Signal,
+    },
+    exec::{EmbeddedExec, ExecMode},
 };
 
 use super::Rerender;
@@ -21,6 +23,7 @@ pub enum SelectablePanel {
     Containers,
     Commands,
     Logs,
+    Exec,
 }
 
 impl SelectablePanel {
@@ -29,20 +32,23 @@ impl SelectablePanel {
             Self::Containers => "Containers",
             Self::Logs => "Logs",
             Self::Commands => "",
+            Self::Exec => "Exec",
         }
     }
     pub const fn next(self) -> Self {
         match self {
             Self::Containers => Self::Commands,
             Self::Commands => Self::Logs,
-            Self::Logs => Self::Containers,
+            Self::Logs => Self::Exec,
+            Self::Exec => Self::Containers,
         }
     }
     pub const fn prev(self) -> Self {
         match self {
-            Self::Containers => Self::Logs,
+            Self::Containers => Self::Exec,
             Self::Commands => Self::Containers,
             Self::Logs => Self::Commands,
+            Self::Exec => Self::Logs,
         }
     }
 }
@@ -59,6 +65,7 @@ pub enum Region {
 pub enum DeleteButton {
     Confirm,
     Cancel,
+    Toggle(DeleteOption),
 }
 
 #[allow(unused)]
@@ -162,15 +169,28 @@ const FRAMES_LEN: u8 = 9;
 /// Various functions (e.g input handler), operate differently depending upon current Status
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Status {
+    Changes,
+    CommandOutput,
     DeleteConfirm,
     DockerConnect(Option<String>),
     Error,
     Exec,
+    Files,
+    FilesUpload,
     Filter,
     Help,
     Init,
     Inspect,
-    Logs,
+    KillSignal,
+    Limits,
+    LogDetail,
+    Networks,
+    NewContainer,
+    Processes,
+    Prompt,
+    Prune,
+    RunCommand,
+    SaveLogs,
     SearchLogs,
 }
 
@@ -183,12 +203,17 @@ pub struct ScrollOffset {
 /// Global gui_state, stored in an Arc<Mutex>
 #[derive(Debug)]
 pub struct GuiState {
+    command_input: String,
+    command_timeout: Option<u16>,
     delete_container_id: Option<ContainerId>,
+    delete_options: DeleteOptions,
+    embedded_exec: Option<EmbeddedExec>,
     exec_mode: Option<ExecMode>,
     intersect_delete: HashMap<DeleteButton, Rect>,
     intersect_heading: HashMap<Header, Rect>,
     intersect_help: Option<Rect>,
     intersect_panel: HashMap<SelectablePanel, Rect>,
+    kill_container: Option<(ContainerId, Signal)>,
     loading_handle: Option<JoinHandle<()>>,
     loading_index: u8,
     loading_set: HashSet<Uuid>,
@@ -205,7 +230,11 @@ pub struct GuiState {
 impl GuiState {
     pub fn new(redraw: &Arc<Rerender>, show_logs: bool) -> Self {
         Self {
+            command_input: String::new(),
+            command_timeout: None,
             delete_container_id: None,
+            delete_options: DeleteOptions::default(),
+            embedded_exec: None,
             exec_mode: None,
             info_box_text: None,
             intersect_delete: HashMap::new(),
@@ -214,6 +243,7 @@ impl GuiState {
             intersect_panel: HashMap::new(),
             inspect_offset: ScrollOffset::default(),
             inspect_offset_max: ScrollOffset::default(),
+            kill_container: None,
             loading_handle: None,
             loading_index: 0,
             loading_set: HashSet::new(),
@@ -424,6 +454,93 @@ impl GuiState {
         self.rerender.update_draw();
     }
 
+    /// Get the options that will be used to delete the container in the delete confirm dialog
+    pub const fn get_delete_options(&self) -> DeleteOptions {
+        self.delete_options
+    }
+
+    /// Set the options of the delete confirm dialog, used to reset them to the config defaults when the dialog is opened
+    pub fn set_delete_options(&mut self, options: DeleteOptions) {
+        self.delete_options = options;
+        self.rerender.update_draw();
+    }
+
+    /// Toggle a single option in the delete confirm dialog
+    pub fn delete_option_toggle(&mut self, option: DeleteOption) {
+        self.delete_options.toggle(option);
+        self.rerender.update_draw();
+    }
+
+    /// Get the container, and the currently selected signal, of the kill signal picker
+    pub fn get_kill_container(&self) -> Option<(ContainerId, Signal)> {
+        self.kill_container.clone()
+    }
+
+    /// Open the kill signal picker for a given container, with SIGTERM selected, or close it with None
+    pub fn set_kill_container(&mut self, id: Option<ContainerId>) {
+        if id.is_some() {
+            self.status.insert(Status::KillSignal);
+        } else {
+            self.status_del(Status::KillSignal);
+        }
+        self.kill_container = id.map(|i| (i, Signal::Term));
+        self.rerender.update_draw();
+    }
+
+    /// Select the next, or previous, signal in the kill signal picker
+    pub fn kill_signal_cycle(&mut self, forward: bool) {
+        if let Some((_, signal)) = self.kill_container.as_mut() {
+            *signal = signal.cycle(forward);
+            self.rerender.update_draw();
+        }
+    }
+
+    /// Get the stop/restart timeout override, only set if the user has changed it for the next command
+    pub const fn get_command_timeout(&self) -> Option<u16> {
+        self.command_timeout
+    }
+
+    /// Increase, or decrease, the stop/restart timeout override by 5 seconds, starting from the given base value
+    pub fn command_timeout_change(&mut self, increase: bool, base: u16) {
+        let current = self.command_timeout.unwrap_or(base);
+        self.command_timeout = Some(if increase {
+            current.saturating_add(5)
+        } else {
+            current.saturating_sub(5)
+        });
+        self.rerender.update_draw();
+    }
+
+    /// Remove the stop/restart timeout override, returning the previous value
+    pub fn command_timeout_take(&mut self) -> Option<u16> {
+        let timeout = self.command_timeout.take();
+        if timeout.is_some() {
+            self.rerender.update_draw();
+        }
+        timeout
+    }
+
+    /// Add a char to the one-off command input
+    pub fn command_input_push(&mut self, c: char) {
+        self.command_input.push(c);
+        self.rerender.update_draw();
+    }
+
+    /// Remove the final char from the one-off command input
+    pub fn command_input_pop(&mut self) {
+        self.command_input.pop();
+        self.rerender.update_draw();
+    }
+
+    pub fn get_command_input(&self) -> String {
+        self.command_input.clone()
+    }
+
+    pub fn command_input_clear(&mut self) {
+        self.command_input.clear();
+        self.rerender.update_draw();
+    }
+
     /// Return a copy of the Status HashSet
     pub fn get_status(&self) -> HashSet<Status> {
         self.status.clone()
@@ -467,12 +584,20 @@ impl GuiState {
         }
     }
 
+    /// Check if a panel isn't currently visible, and so shouldn't be selectable
+    /// The Commands panel is hidden when there are no containers, the Logs panel is hidden when it has no height, or is replaced by an embedded exec session
+    fn panel_hidden(&self, panel: SelectablePanel, app_data: &Arc<Mutex<AppData>>) -> bool {
+        match panel {
+            SelectablePanel::Containers => false,
+            SelectablePanel::Commands => app_data.lock().get_container_len() == 0,
+            SelectablePanel::Logs => self.log_height == 0 || self.embedded_exec.is_some(),
+            SelectablePanel::Exec => self.embedded_exec.is_none(),
+        }
+    }
+
     pub fn set_logs_panel_selected(&mut self, app_data: &Arc<Mutex<AppData>>) {
         self.selected_panel = SelectablePanel::Logs;
-        if (app_data.lock().get_container_len() == 0
-            && self.get_selected_panel() == SelectablePanel::Commands)
-            || (self.log_height == 0 && self.get_selected_panel() == SelectablePanel::Logs)
-        {
+        while self.panel_hidden(self.selected_panel, app_data) {
             self.selected_panel = self.selected_panel.next();
         }
         self.rerender.update_draw();
@@ -480,10 +605,7 @@ impl GuiState {
     /// Change to next selectable panel
     pub fn selectable_panel_next(&mut self, app_data: &Arc<Mutex<AppData>>) {
         self.selected_panel = self.selected_panel.next();
-        if (app_data.lock().get_container_len() == 0
-            && self.get_selected_panel() == SelectablePanel::Commands)
-            || (self.log_height == 0 && self.get_selected_panel() == SelectablePanel::Logs)
-        {
+        while self.panel_hidden(self.selected_panel, app_data) {
             self.selected_panel = self.selected_panel.next();
         }
         self.rerender.update_draw();
@@ -492,15 +614,51 @@ impl GuiState {
     /// Change to previous selectable panel
     pub fn selectable_panel_previous(&mut self, app_data: &Arc<Mutex<AppData>>) {
         self.selected_panel = self.selected_panel.prev();
-        if (app_data.lock().get_container_len() == 0
-            && self.get_selected_panel() == SelectablePanel::Commands)
-            || (self.log_height == 0 && self.get_selected_panel() == SelectablePanel::Logs)
-        {
+        while self.panel_hidden(self.selected_panel, app_data) {
             self.selected_panel = self.selected_panel.prev();
         }
         self.rerender.update_draw();
     }
 
+    /// Insert an embedded exec session, and select its panel
+    /// The logs panel is replaced by the exec panel, so remove it from the intersect map
+    pub fn set_embedded_exec(&mut self, embedded_exec: EmbeddedExec) {
+        self.embedded_exec = Some(embedded_exec);
+        self.intersect_panel.remove(&SelectablePanel::Logs);
+        self.selected_panel = SelectablePanel::Exec;
+        self.rerender.update_draw();
+    }
+
+    pub fn get_embedded_exec(&self) -> Option<EmbeddedExec> {
+        self.embedded_exec.clone()
+    }
+
+    /// Select the embedded exec panel, returns false if there isn't an active embedded exec session
+    pub fn select_embedded_exec(&mut self) -> bool {
+        if self.embedded_exec.is_some() {
+            self.selected_panel = SelectablePanel::Exec;
+            self.rerender.update_draw();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Remove the embedded exec session, executed once the exec process has ended, if the exec panel was selected, the containers panel is selected instead
+    pub fn clear_embedded_exec(&mut self) {
+        self.embedded_exec = None;
+        self.intersect_panel.remove(&SelectablePanel::Exec);
+        if self.selected_panel == SelectablePanel::Exec {
+            self.selected_panel = SelectablePanel::Containers;
+        }
+        self.rerender.update_draw();
+    }
+
+    /// Redraw the ui after new output from the embedded exec session
+    pub fn redraw_embedded_exec(&self) {
+        self.rerender.update_draw();
+    }
+
     /// Insert a new loading_uuid into HashSet, and advance the loading_index by one frame, or reset to 0 if at end of array
     pub fn next_loading(&mut self, uuid: Uuid) {
         if self.loading_index == FRAMES_LEN {
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use base64::{Engine, engine::general_purpose::STANDARD};
 use crossterm::{
     event::{self, DisableMouseCapture, Event},
     execute,
@@ -31,15 +32,25 @@ pub use self::gui_state::{DeleteButton, GuiState, SelectablePanel, Status};
 use crate::{
     app_data::{
         AppData, ChartsData, Columns, ContainerId, ContainerPorts, FilterBy, Header, LogSearch,
-        SortedOrder, State,
+        Signal, SortedOrder, State,
     },
     app_error::AppError,
     config::{AppColors, Keymap},
+    exec::EmbeddedExec,
     input_handler::InputMessages,
 };
 
 const POLL_RATE: Duration = std::time::Duration::from_millis(50);
 
+/// The max length of the base64 encoded OSC 52 clipboard payload, many terminals ignore, or truncate, anything longer
+pub const CLIPBOARD_MAX: usize = 100_000;
+
+/// The OSC 52 escape sequence to set the clipboard, None if the encoded text is longer than the max payload length
+fn osc52(text: &str) -> Option<String> {
+    let payload = STANDARD.encode(text);
+    (payload.len() <= CLIPBOARD_MAX).then(|| format!("\x1b]52;c;{payload}\x07"))
+}
+
 // could have a render struct, which takes in poll rate, and docker
 
 pub struct Ui {
@@ -66,6 +77,17 @@ impl Ui {
         )?)
     }
 
+    /// Copy text to the system clipboard with the OSC 52 escape sequence, which the terminal, rather than oxker, acts upon, so it also works over SSH
+    /// Returns an error, without writing anything, if the text is too large to be copied
+    pub fn copy_to_clipboard(text: &str) -> Result<(), AppError> {
+        let sequence = osc52(text).ok_or(AppError::ClipboardSize(CLIPBOARD_MAX))?;
+        let mut stdout = io::stdout().lock();
+        stdout
+            .write_all(sequence.as_bytes())
+            .and_then(|()| stdout.flush())
+            .map_err(|_| AppError::Clipboard)
+    }
+
     /// Create a new Ui struct, and execute the drawing loop
     pub async fn start(
         app_data: Arc<Mutex<AppData>>,
@@ -90,6 +112,11 @@ impl Ui {
                 if let Err(e) = ui.draw_ui().await {
                     error!("{e}");
                 }
+                // Close any embedded exec session, so that its threads are cancelled, and a debug container is removed, before exiting
+                let embedded_exec = ui.gui_state.lock().get_embedded_exec();
+                if let Some(embedded_exec) = embedded_exec {
+                    embedded_exec.close().await;
+                }
                 if let Err(e) = ui.reset_terminal() {
                     error!("{e}");
                 }
@@ -304,9 +331,13 @@ pub struct FrameData {
     chart_data: Option<ChartsData>,
     color_logs: bool,
     columns: Columns,
+    command_input: String,
+    command_timeout: Option<u16>,
     container_title: String,
     log_search: Option<LogSearch>,
     delete_confirm: Option<ContainerId>,
+    kill_signal: Option<(ContainerId, Signal)>,
+    embedded_exec: Option<EmbeddedExec>,
     filter_by: FilterBy,
     filter_term: Option<String>,
     has_containers: bool,
@@ -317,6 +348,7 @@ pub struct FrameData {
     is_loading: bool,
     loading_icon: String,
     log_title: String,
+    log_unread: usize,
     port_max_lens: (usize, usize, usize),
     ports: Option<(Vec<ContainerPorts>, State)>,
     selected_panel: SelectablePanel,
@@ -333,9 +365,13 @@ impl From<&Ui> for FrameData {
         Self {
             chart_data: app_data.get_chart_data(),
             color_logs: app_data.config.color_logs,
+            command_input: gui_data.get_command_input(),
+            command_timeout: gui_data.get_command_timeout(),
             columns: app_data.get_width(),
             container_title: app_data.get_container_title(),
             delete_confirm: gui_data.get_delete_container(),
+            kill_signal: gui_data.get_kill_container(),
+            embedded_exec: gui_data.get_embedded_exec(),
             filter_by,
             filter_term: filter_term.cloned(),
             has_containers: app_data.get_container_len() > 0,
@@ -347,6 +383,7 @@ impl From<&Ui> for FrameData {
             loading_icon: gui_data.get_loading().to_string(),
             log_height: gui_data.get_log_height(),
             log_title: app_data.get_log_title(),
+            log_unread: app_data.get_log_unread(),
             port_max_lens: app_data.get_longest_port(),
             ports: app_data.get_selected_ports(),
             scroll_title: app_data.get_scroll_title(gui_data.get_screen_width()),
@@ -368,20 +405,45 @@ fn draw_frame(
 ) {
     let contains_filter = fd.status.contains(&Status::Filter);
     let contains_search_logs = fd.status.contains(&Status::SearchLogs);
+    let contains_run_command = fd.status.contains(&Status::RunCommand);
 
     let contains_inspect = fd.status.contains(&Status::Inspect);
+    let contains_command_output = fd.status.contains(&Status::CommandOutput);
+    let contains_files = fd.status.contains(&Status::Files);
+    let contains_changes = fd.status.contains(&Status::Changes);
+    let contains_processes = fd.status.contains(&Status::Processes);
 
     let inspect_data = app_data.lock().get_inspect_data();
+    let command_output = app_data.lock().get_command_output();
+    let file_browser = app_data.lock().get_file_browser();
+    let container_changes = app_data.lock().get_container_changes();
+    let processes = app_data.lock().get_processes();
     if contains_inspect && let Some(inspect_data) = inspect_data {
         draw_blocks::inspect::draw(f, colors, inspect_data, gui_state, keymap);
+    } else if contains_command_output && let Some(command_output) = command_output {
+        draw_blocks::command_output::draw(f, colors, command_output, gui_state, keymap);
+    } else if contains_files && let Some(file_browser) = file_browser {
+        draw_blocks::files::draw(
+            f,
+            colors,
+            file_browser,
+            fd.status.contains(&Status::FilesUpload),
+            keymap,
+        );
+    } else if contains_changes && let Some(container_changes) = container_changes {
+        draw_blocks::changes::draw(f, colors, container_changes, keymap);
+    } else if contains_processes && let Some(processes) = processes {
+        draw_blocks::processes::draw(f, colors, processes, keymap);
     } else {
         let whole_layout = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(if contains_filter || contains_search_logs {
-                vec![Constraint::Max(1), Constraint::Min(1), Constraint::Max(1)]
-            } else {
-                vec![Constraint::Max(1), Constraint::Min(1)]
-            })
+            .constraints(
+                if contains_filter || contains_search_logs || contains_run_command {
+                    vec![Constraint::Max(1), Constraint::Min(1), Constraint::Max(1)]
+                } else {
+                    vec![Constraint::Max(1), Constraint::Min(1)]
+                },
+            )
             .split(f.area());
 
         draw_blocks::headers::draw(whole_layout[0], colors, f, fd, gui_state, keymap);
@@ -389,6 +451,8 @@ fn draw_frame(
         if let Some(rect) = whole_layout.get(2) {
             if contains_filter {
                 draw_blocks::filter::draw(*rect, colors, f, fd);
+            } else if contains_run_command {
+                draw_blocks::run_command::draw(*rect, colors, f, fd);
             } else {
                 draw_blocks::search_logs::draw(*rect, colors, f, fd, keymap);
             }
@@ -403,9 +467,15 @@ fn draw_frame(
             })
             .split(whole_layout[1]);
 
+        // An embedded exec session replaces the logs panel, and is always shown, with at least half of the height
         let containers_logs_section = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(if fd.show_logs {
+            .constraints(if fd.embedded_exec.is_some() {
+                vec![
+                    Constraint::Min(6),
+                    Constraint::Percentage(fd.log_height.max(50)),
+                ]
+            } else if fd.show_logs {
                 vec![Constraint::Min(6), Constraint::Percentage(fd.log_height)]
             } else {
                 vec![Constraint::Percentage(100)]
@@ -424,7 +494,9 @@ fn draw_frame(
 
         draw_blocks::containers::draw(app_data, containers_commands[0], colors, f, fd, gui_state);
 
-        if fd.show_logs {
+        if fd.embedded_exec.is_some() {
+            draw_blocks::exec::draw(containers_logs_section[1], colors, f, fd, gui_state);
+        } else if fd.show_logs {
             draw_blocks::logs::draw(
                 app_data,
                 containers_logs_section[1],
@@ -448,6 +520,17 @@ fn draw_frame(
             );
         }
 
+        if let Some((id, signal)) = fd.kill_signal.as_ref() {
+            app_data.lock().get_container_name_by_id(id).map_or_else(
+                || {
+                    gui_state.lock().set_kill_container(None);
+                },
+                |name| {
+                    draw_blocks::kill_signal::draw(colors, f, keymap, name, *signal);
+                },
+            );
+        }
+
         // only draw commands + charts if there are containers
         if let Some(rect) = containers_commands.get(1) {
             draw_blocks::commands::draw(app_data, *rect, colors, f, fd, gui_state);
@@ -473,6 +556,52 @@ fn draw_frame(
             draw_blocks::ports::draw(lower[1], colors, f, fd);
         }
 
+        if fd.status.contains(&Status::NewContainer)
+            && let Some(form) = app_data.lock().get_new_container()
+        {
+            draw_blocks::new_container::draw(colors, f, &form);
+        }
+
+        if fd.status.contains(&Status::Limits)
+            && let Some(form) = app_data.lock().get_limits()
+        {
+            draw_blocks::limits::draw(colors, f, &form);
+        }
+
+        if fd.status.contains(&Status::Prompt)
+            && let Some(prompt) = app_data.lock().get_prompt()
+        {
+            draw_blocks::prompt::draw(colors, f, &prompt);
+        }
+
+        if fd.status.contains(&Status::LogDetail)
+            && let Some(detail) = app_data.lock().get_log_detail()
+        {
+            draw_blocks::log_detail::draw(colors, f, keymap, detail);
+        }
+
+        if fd.status.contains(&Status::Networks)
+            && let Some(networks) = app_data.lock().get_networks()
+        {
+            draw_blocks::networks::draw(colors, f, keymap, &networks);
+        }
+
+        if let Some(recreate) = app_data.lock().get_recreate() {
+            draw_blocks::recreate::draw(colors, f, &recreate);
+        }
+
+        if fd.status.contains(&Status::Prune)
+            && let Some(prune) = app_data.lock().get_prune()
+        {
+            draw_blocks::prune::draw(colors, f, keymap, prune);
+        }
+
+        if fd.status.contains(&Status::SaveLogs)
+            && let Some(save_logs) = app_data.lock().get_save_logs()
+        {
+            draw_blocks::save_logs::draw(colors, f, keymap, save_logs);
+        }
+
         // Check if error, and show popup if so
         if fd.status.contains(&Status::Help) {
             let config = app_data.lock().config.clone();
@@ -488,3 +617,18 @@ fn draw_frame(
         draw_blocks::error::draw(colors, error, f, None, keymap, None);
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{CLIPBOARD_MAX, osc52};
+
+    #[test]
+    /// The text is base64 encoded into the OSC 52 sequence, unless the payload is too large
+    fn test_ui_osc52() {
+        assert_eq!(osc52("↪ oxker").unwrap(), "\x1b]52;c;4oaqIG94a2Vy\x07");
+        assert_eq!(osc52("").unwrap(), "\x1b]52;c;\x07");
+        assert!(osc52(&"a".repeat(CLIPBOARD_MAX / 4 * 3)).is_some());
+        assert!(osc52(&"a".repeat(CLIPBOARD_MAX / 4 * 3 + 1)).is_none());
+    }
+}